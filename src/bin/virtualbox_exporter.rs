@@ -0,0 +1,92 @@
+//! Prometheus exporter for VirtualBox.
+//!
+//! Serves the state and resource usage of the host and all registered virtual machines at
+//! `/metrics`.
+//!
+//! ```text
+//! virtualbox_exporter [--listen ADDRESS] [--period SECONDS] [--count SAMPLES] [--guest-statistics]
+//! ```
+
+use std::process::exit;
+use virtualbox_rs::{MetricsExporter, VirtualBox, VirtualBoxClient};
+
+const USAGE: &str = "Usage: virtualbox_exporter [--listen ADDRESS] [--period SECONDS] [--count SAMPLES] [--guest-statistics]
+
+Options:
+    --listen ADDRESS      Address to serve the metrics on (default: 127.0.0.1:9183)
+    --period SECONDS      Performance collector sampling period, 0 disables it (default: 10)
+    --count SAMPLES       Number of performance samples to retain (default: 1)
+    --guest-statistics    Export the guest additions statistics of running machines
+    --help                Print this help";
+
+struct Options {
+    listen: String,
+    period: u32,
+    count: u32,
+    guest_statistics: bool,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        listen: "127.0.0.1:9183".to_string(),
+        period: 10,
+        count: 1,
+        guest_statistics: false,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--listen" => options.listen = args.next().ok_or("--listen requires a value")?,
+            "--period" => options.period = parse_number(&arg, args.next())?,
+            "--count" => options.count = parse_number(&arg, args.next())?,
+            "--guest-statistics" => options.guest_statistics = true,
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                exit(0);
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn parse_number(arg: &str, value: Option<String>) -> Result<u32, String> {
+    let value = value.ok_or(format!("{} requires a value", arg))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", arg, value))
+}
+
+fn main() {
+    let options = match parse_options() {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            exit(2);
+        }
+    };
+    if let Err(err) = VirtualBoxClient::check_version() {
+        eprintln!("{}", err);
+        exit(1);
+    }
+    let vbox = match VirtualBox::init() {
+        Ok(vbox) => vbox,
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1);
+        }
+    };
+    let mut exporter = MetricsExporter::new(vbox);
+    exporter.set_guest_statistics(options.guest_statistics);
+    if options.period > 0 {
+        if let Err(err) = exporter.setup_performance_metrics(options.period, options.count) {
+            eprintln!("{}", err);
+            exit(1);
+        }
+    }
+    println!("Serving metrics on http://{}/metrics", options.listen);
+    if let Err(err) = exporter.serve(&options.listen) {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
@@ -0,0 +1,508 @@
+use crate::ovf_package::digest_stream;
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_pointer_vec,
+    get_function_result_str, get_function_result_str_vec,
+};
+use crate::{Certificate, DigestAlgorithm, VboxError};
+
+impl Certificate {
+    /// Certificate version number.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let version_number = certificate.get_version_number().unwrap();
+    pub fn get_version_number(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetVersionNumber, u32)
+    }
+
+    /// Certificate serial number.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let serial_number = certificate.get_serial_number().unwrap();
+    pub fn get_serial_number(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetSerialNumber)
+    }
+
+    /// The dotted OID of the signature algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let signature_algorithm_oid = certificate.get_signature_algorithm_oid().unwrap();
+    pub fn get_signature_algorithm_oid(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetSignatureAlgorithmOID)
+    }
+
+    /// The signature algorithm name if known (if known).
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let signature_algorithm_name = certificate.get_signature_algorithm_name().unwrap();
+    pub fn get_signature_algorithm_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetSignatureAlgorithmName)
+    }
+
+    /// Issuer name.
+    ///
+    /// Each member of the array is on the format `COMPONENT=NAME`, e.g. `C=DE`, `ST=Example`, `L=For Instance`, `O=Beispiel GmbH`, `CN=beispiel.example.org`.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let issuer_name = certificate.get_issuer_name().unwrap();
+    pub fn get_issuer_name(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetIssuerName)
+    }
+
+    /// Subject name.
+    ///
+    /// Same format as [`Certificate::get_issuer_name`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let subject_name = certificate.get_subject_name().unwrap();
+    pub fn get_subject_name(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetSubjectName)
+    }
+
+    /// Friendly name if present in certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let friendly_name = certificate.get_friendly_name().unwrap();
+    pub fn get_friendly_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetFriendlyName)
+    }
+
+    /// Certificate not valid before ISO time stamp.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let validity_period_not_before = certificate.get_validity_period_not_before().unwrap();
+    pub fn get_validity_period_not_before(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetValidityPeriodNotBefore)
+    }
+
+    /// Certificate not valid after ISO time stamp.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let validity_period_not_after = certificate.get_validity_period_not_after().unwrap();
+    pub fn get_validity_period_not_after(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetValidityPeriodNotAfter)
+    }
+
+    /// The dotted OID of the public key algorithm.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let public_key_algorithm_oid = certificate.get_public_key_algorithm_oid().unwrap();
+    pub fn get_public_key_algorithm_oid(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPublicKeyAlgorithmOID)
+    }
+
+    /// The public key algorithm name (if known).
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let public_key_algorithm = certificate.get_public_key_algorithm().unwrap();
+    pub fn get_public_key_algorithm(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPublicKeyAlgorithm)
+    }
+
+    /// The raw public key bytes.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<u8>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let subject_public_key = certificate.get_subject_public_key().unwrap();
+    pub fn get_subject_public_key(&self) -> Result<Vec<u8>, VboxError> {
+        get_function_result_pointer_vec!(self.object, GetSubjectPublicKey, u8)
+    }
+
+    /// Unique identifier of the issuer (empty string if not present).
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let issuer_unique_identifier = certificate.get_issuer_unique_identifier().unwrap();
+    pub fn get_issuer_unique_identifier(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetIssuerUniqueIdentifier)
+    }
+
+    /// Unique identifier of this certificate (empty string if not present).
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let subject_unique_identifier = certificate.get_subject_unique_identifier().unwrap();
+    pub fn get_subject_unique_identifier(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetSubjectUniqueIdentifier)
+    }
+
+    /// Whether this certificate is a certificate authority.
+    ///
+    /// Returns an error if this attribute is not present.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let certificate_authority = certificate.get_certificate_authority().unwrap();
+    pub fn get_certificate_authority(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetCertificateAuthority)
+    }
+
+    /// Key usage mask.
+    ///
+    /// Will return 0 if not present.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let key_usage = certificate.get_key_usage().unwrap();
+    pub fn get_key_usage(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetKeyUsage, u32)
+    }
+
+    /// Array of dotted extended key usage OIDs.
+    ///
+    /// Empty array if not present.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let extended_key_usage = certificate.get_extended_key_usage().unwrap();
+    pub fn get_extended_key_usage(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetExtendedKeyUsage)
+    }
+
+    /// The raw certificate bytes (DER).
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<u8>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let raw_cert_data = certificate.get_raw_cert_data().unwrap();
+    pub fn get_raw_cert_data(&self) -> Result<Vec<u8>, VboxError> {
+        get_function_result_pointer_vec!(self.object, GetRawCertData, u8)
+    }
+
+    /// Set if self signed certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let self_signed = certificate.get_self_signed().unwrap();
+    pub fn get_self_signed(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetSelfSigned)
+    }
+
+    /// Set if the certificate is trusted (by the parent object).
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let trusted = certificate.get_trusted().unwrap();
+    pub fn get_trusted(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetTrusted)
+    }
+
+    /// Set if the certificate has expired (relevant to the parent object).
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let expired = certificate.get_expired().unwrap();
+    pub fn get_expired(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetExpired)
+    }
+
+    /// Tests if the certificate has expired at the present time according to the X.509 validity of the certificate.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let expired = certificate.is_currently_expired().unwrap();
+    pub fn is_currently_expired(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, IsCurrentlyExpired)
+    }
+
+    /// Way to extend the interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `what` - i32. Index of the requested piece of information.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let info = certificate.query_info(0).unwrap();
+    pub fn query_info(&self, what: i32) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, QueryInfo, what)
+    }
+
+    /// Fingerprint of the certificate, the digest of [`Certificate::get_raw_cert_data`].
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - [`DigestAlgorithm`]. Digest algorithm of the fingerprint.
+    ///
+    /// # Returns
+    ///
+    /// Returns the lowercase hex fingerprint on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Appliance, DigestAlgorithm};
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let certificate = appliance.get_certificate().unwrap();
+    /// let fingerprint = certificate.get_fingerprint(DigestAlgorithm::Sha256).unwrap();
+    pub fn get_fingerprint(&self, algorithm: DigestAlgorithm) -> Result<String, VboxError> {
+        let raw_cert_data = self.get_raw_cert_data()?;
+        let (_, mut digests) = digest_stream(&mut raw_cert_data.as_slice(), &[algorithm])?;
+        Ok(digests.remove(0))
+    }
+}
@@ -0,0 +1,352 @@
+/// Content of the NoCloud `meta-data` file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetaData {
+    /// Changing the instance ID makes cloud-init run the first boot modules again.
+    pub instance_id: String,
+    pub local_hostname: Option<String>,
+}
+
+/// Content of the NoCloud `user-data` file, rendered as a `#cloud-config` document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserData {
+    pub hostname: Option<String>,
+    pub fqdn: Option<String>,
+    /// Users to create. When empty, only the distribution default user is created.
+    pub users: Vec<CloudUser>,
+    /// Plain text password of the default user.
+    pub password: Option<String>,
+    /// Whether the password of the default user has to be changed on the first login.
+    pub expire_password: Option<bool>,
+    /// Whether sshd accepts password authentication.
+    pub ssh_pwauth: Option<bool>,
+    /// Public keys authorized for the default user.
+    pub ssh_authorized_keys: Vec<String>,
+    pub package_update: bool,
+    pub package_upgrade: bool,
+    pub packages: Vec<String>,
+    /// Commands run by `sh -c` on the first boot.
+    pub runcmd: Vec<String>,
+    /// YAML appended verbatim for modules not covered by this struct.
+    pub extra: Option<String>,
+}
+
+/// A user entry of [`UserData`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CloudUser {
+    pub name: String,
+    pub gecos: Option<String>,
+    pub groups: Vec<String>,
+    pub shell: Option<String>,
+    /// Sudo rule, for example `ALL=(ALL) NOPASSWD:ALL`.
+    pub sudo: Option<String>,
+    /// Password hash as produced by `mkpasswd`.
+    pub hashed_passwd: Option<String>,
+    pub lock_passwd: Option<bool>,
+    pub ssh_authorized_keys: Vec<String>,
+}
+
+/// Content of the NoCloud `network-config` file, in the version 2 (netplan) format.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkConfig {
+    pub ethernets: Vec<EthernetConfig>,
+}
+
+/// An ethernet interface of [`NetworkConfig`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EthernetConfig {
+    /// Interface name, or an arbitrary ID when `mac_address` is set.
+    pub id: String,
+    /// Matches the interface by MAC address, for example the one of
+    /// [`crate::NetworkAdapter::get_mac_address`], and renames it to `id`.
+    pub mac_address: Option<String>,
+    pub dhcp4: bool,
+    pub dhcp6: bool,
+    /// Static addresses in CIDR notation, for example `192.168.56.10/24`.
+    pub addresses: Vec<String>,
+    /// Default IPv4 gateway.
+    pub gateway4: Option<String>,
+    pub nameservers: Vec<String>,
+    pub search_domains: Vec<String>,
+}
+
+impl MetaData {
+    pub fn new(instance_id: &str) -> Self {
+        Self {
+            instance_id: instance_id.to_string(),
+            local_hostname: None,
+        }
+    }
+
+    pub(crate) fn to_yaml(&self) -> String {
+        let mut yaml = format!("instance-id: {}\n", quote(&self.instance_id));
+        if let Some(local_hostname) = &self.local_hostname {
+            yaml.push_str(&format!("local-hostname: {}\n", quote(local_hostname)));
+        }
+        yaml
+    }
+}
+
+impl UserData {
+    pub(crate) fn to_yaml(&self) -> String {
+        let mut yaml = String::from("#cloud-config\n");
+        if let Some(hostname) = &self.hostname {
+            yaml.push_str(&format!("hostname: {}\n", quote(hostname)));
+        }
+        if let Some(fqdn) = &self.fqdn {
+            yaml.push_str(&format!("fqdn: {}\n", quote(fqdn)));
+        }
+        if !self.users.is_empty() {
+            yaml.push_str("users:\n  - default\n");
+            for user in &self.users {
+                user.push_yaml(&mut yaml);
+            }
+        }
+        if let Some(password) = &self.password {
+            yaml.push_str(&format!("password: {}\n", quote(password)));
+        }
+        if let Some(expire) = self.expire_password {
+            yaml.push_str(&format!("chpasswd:\n  expire: {}\n", expire));
+        }
+        if let Some(ssh_pwauth) = self.ssh_pwauth {
+            yaml.push_str(&format!("ssh_pwauth: {}\n", ssh_pwauth));
+        }
+        push_list(
+            &mut yaml,
+            "",
+            "ssh_authorized_keys",
+            &self.ssh_authorized_keys,
+        );
+        if self.package_update {
+            yaml.push_str("package_update: true\n");
+        }
+        if self.package_upgrade {
+            yaml.push_str("package_upgrade: true\n");
+        }
+        push_list(&mut yaml, "", "packages", &self.packages);
+        push_list(&mut yaml, "", "runcmd", &self.runcmd);
+        if let Some(extra) = &self.extra {
+            yaml.push_str(extra);
+            if !extra.ends_with('\n') {
+                yaml.push('\n');
+            }
+        }
+        yaml
+    }
+}
+
+impl CloudUser {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn push_yaml(&self, yaml: &mut String) {
+        yaml.push_str(&format!("  - name: {}\n", quote(&self.name)));
+        if let Some(gecos) = &self.gecos {
+            yaml.push_str(&format!("    gecos: {}\n", quote(gecos)));
+        }
+        if !self.groups.is_empty() {
+            yaml.push_str(&format!("    groups: {}\n", quote(&self.groups.join(", "))));
+        }
+        if let Some(shell) = &self.shell {
+            yaml.push_str(&format!("    shell: {}\n", quote(shell)));
+        }
+        if let Some(sudo) = &self.sudo {
+            yaml.push_str(&format!("    sudo: {}\n", quote(sudo)));
+        }
+        if let Some(hashed_passwd) = &self.hashed_passwd {
+            yaml.push_str(&format!("    passwd: {}\n", quote(hashed_passwd)));
+        }
+        if let Some(lock_passwd) = self.lock_passwd {
+            yaml.push_str(&format!("    lock_passwd: {}\n", lock_passwd));
+        }
+        push_list(
+            yaml,
+            "    ",
+            "ssh_authorized_keys",
+            &self.ssh_authorized_keys,
+        );
+    }
+}
+
+impl NetworkConfig {
+    pub(crate) fn to_yaml(&self) -> String {
+        let mut yaml = String::from("version: 2\n");
+        if self.ethernets.is_empty() {
+            return yaml;
+        }
+        yaml.push_str("ethernets:\n");
+        for ethernet in &self.ethernets {
+            ethernet.push_yaml(&mut yaml);
+        }
+        yaml
+    }
+}
+
+impl EthernetConfig {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn push_yaml(&self, yaml: &mut String) {
+        yaml.push_str(&format!("  {}:\n", quote(&self.id)));
+        if let Some(mac_address) = &self.mac_address {
+            yaml.push_str(&format!(
+                "    match:\n      macaddress: {}\n    set-name: {}\n",
+                quote(&format_mac_address(mac_address)),
+                quote(&self.id)
+            ));
+        }
+        yaml.push_str(&format!("    dhcp4: {}\n", self.dhcp4));
+        if self.dhcp6 {
+            yaml.push_str("    dhcp6: true\n");
+        }
+        push_list(yaml, "    ", "addresses", &self.addresses);
+        if let Some(gateway4) = &self.gateway4 {
+            yaml.push_str(&format!(
+                "    routes:\n      - to: default\n        via: {}\n",
+                quote(gateway4)
+            ));
+        }
+        if !self.nameservers.is_empty() || !self.search_domains.is_empty() {
+            yaml.push_str("    nameservers:\n");
+            push_list(yaml, "      ", "addresses", &self.nameservers);
+            push_list(yaml, "      ", "search", &self.search_domains);
+        }
+    }
+}
+
+fn push_list(yaml: &mut String, indent: &str, key: &str, values: &[String]) {
+    if values.is_empty() {
+        return;
+    }
+    yaml.push_str(&format!("{}{}:\n", indent, key));
+    for value in values {
+        yaml.push_str(&format!("{}  - {}\n", indent, quote(value)));
+    }
+}
+
+/// VirtualBox reports MAC addresses without separators, netplan expects them colon separated.
+fn format_mac_address(mac_address: &str) -> String {
+    let is_bare_hex = mac_address.len() == 12 && mac_address.chars().all(|c| c.is_ascii_hexdigit());
+    if !is_bare_hex {
+        return mac_address.to_lowercase();
+    }
+    mac_address
+        .to_lowercase()
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| String::from_utf8_lossy(pair).to_string())
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+/// Renders a YAML double-quoted scalar, so values never change their type or break the document.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_data_yaml() {
+        let mut meta_data = MetaData::new("iid-freebsd-14");
+        meta_data.local_hostname = Some("freebsd".to_string());
+        assert_eq!(
+            meta_data.to_yaml(),
+            "instance-id: \"iid-freebsd-14\"\nlocal-hostname: \"freebsd\"\n"
+        );
+    }
+
+    #[test]
+    fn user_data_yaml() {
+        let mut user = CloudUser::new("admin");
+        user.groups = vec!["wheel".to_string(), "operator".to_string()];
+        user.sudo = Some("ALL=(ALL) NOPASSWD:ALL".to_string());
+        user.lock_passwd = Some(false);
+        user.ssh_authorized_keys = vec!["ssh-ed25519 AAAA admin@host".to_string()];
+        let user_data = UserData {
+            hostname: Some("freebsd".to_string()),
+            users: vec![user],
+            ssh_pwauth: Some(false),
+            packages: vec!["curl".to_string()],
+            runcmd: vec!["echo \"done\" > /tmp/done".to_string()],
+            extra: Some("timezone: Etc/UTC".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            user_data.to_yaml(),
+            concat!(
+                "#cloud-config\n",
+                "hostname: \"freebsd\"\n",
+                "users:\n",
+                "  - default\n",
+                "  - name: \"admin\"\n",
+                "    groups: \"wheel, operator\"\n",
+                "    sudo: \"ALL=(ALL) NOPASSWD:ALL\"\n",
+                "    lock_passwd: false\n",
+                "    ssh_authorized_keys:\n",
+                "      - \"ssh-ed25519 AAAA admin@host\"\n",
+                "ssh_pwauth: false\n",
+                "packages:\n",
+                "  - \"curl\"\n",
+                "runcmd:\n",
+                "  - \"echo \\\"done\\\" > /tmp/done\"\n",
+                "timezone: Etc/UTC\n",
+            )
+        );
+    }
+
+    #[test]
+    fn network_config_yaml() {
+        let mut ethernet = EthernetConfig::new("eth0");
+        ethernet.mac_address = Some("080027A1B2C3".to_string());
+        ethernet.addresses = vec!["192.168.56.10/24".to_string()];
+        ethernet.gateway4 = Some("192.168.56.1".to_string());
+        ethernet.nameservers = vec!["1.1.1.1".to_string()];
+        let network_config = NetworkConfig {
+            ethernets: vec![ethernet],
+        };
+        assert_eq!(
+            network_config.to_yaml(),
+            concat!(
+                "version: 2\n",
+                "ethernets:\n",
+                "  \"eth0\":\n",
+                "    match:\n",
+                "      macaddress: \"08:00:27:a1:b2:c3\"\n",
+                "    set-name: \"eth0\"\n",
+                "    dhcp4: false\n",
+                "    addresses:\n",
+                "      - \"192.168.56.10/24\"\n",
+                "    routes:\n",
+                "      - to: default\n",
+                "        via: \"192.168.56.1\"\n",
+                "    nameservers:\n",
+                "      addresses:\n",
+                "        - \"1.1.1.1\"\n",
+            )
+        );
+    }
+}
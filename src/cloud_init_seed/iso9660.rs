@@ -0,0 +1,764 @@
+//! Minimal ISO 9660 writer for images with files in the root directory only.
+//!
+//! The image carries a primary volume descriptor with Rock Ridge entries (POSIX names and
+//! modes) and a Joliet supplementary volume descriptor (UCS-2 names), the same layout as
+//! `genisoimage -J -r` produces, so Linux, the BSDs and Windows all see the original file names.
+use crate::VboxError;
+
+pub(crate) const SECTOR_SIZE: usize = 2048;
+
+const PRIMARY_DESCRIPTOR_LBA: u32 = 16;
+const JOLIET_DESCRIPTOR_LBA: u32 = 17;
+const TERMINATOR_LBA: u32 = 18;
+const PRIMARY_L_PATH_TABLE_LBA: u32 = 19;
+const PRIMARY_M_PATH_TABLE_LBA: u32 = 20;
+const JOLIET_L_PATH_TABLE_LBA: u32 = 21;
+const JOLIET_M_PATH_TABLE_LBA: u32 = 22;
+const PRIMARY_ROOT_LBA: u32 = 23;
+
+const PATH_TABLE_SIZE: u32 = 10;
+const FLAG_DIRECTORY: u8 = 0x02;
+const DIRECTORY_MODE: u32 = 0o040555;
+const FILE_MODE: u32 = 0o100444;
+/// Joliet allows 64 UCS-2 characters. Limiting the UTF-8 length as well keeps the Rock Ridge
+/// `NM` entry within the 255 bytes of a directory record.
+const MAX_NAME_LEN: usize = 64;
+
+const RRIP_ID: &str = "RRIP_1991A";
+const RRIP_DESCRIPTOR: &str =
+    "THE ROCK RIDGE INTERCHANGE PROTOCOL PROVIDES SUPPORT FOR POSIX FILE SYSTEM SEMANTICS";
+const RRIP_SOURCE: &str = "PLEASE CONTACT DISC PUBLISHER FOR SPECIFICATION SOURCE.  SEE PUBLISHER IDENTIFIER IN PRIMARY VOLUME DESCRIPTOR FOR CONTACT INFORMATION.";
+
+/// A file of the root directory.
+#[derive(Debug)]
+pub(crate) struct IsoFile<'a> {
+    pub name: &'a str,
+    pub data: &'a [u8],
+}
+
+/// Builds the image.
+///
+/// # Arguments
+///
+/// * `volume_id` - Volume label, at most 16 characters.
+/// * `files` - Files of the root directory.
+/// * `timestamp` - Recording time in seconds since the Unix epoch.
+pub(crate) fn write_iso(
+    volume_id: &str,
+    files: &[IsoFile],
+    timestamp: u64,
+) -> Result<Vec<u8>, VboxError> {
+    if volume_id.is_empty() || volume_id.chars().count() > 16 || !volume_id.is_ascii() {
+        return Err(VboxError::invalid_data(
+            "write_iso",
+            format!("Invalid volume ID: {}", volume_id),
+        ));
+    }
+    for (i, file) in files.iter().enumerate() {
+        validate_name(file.name)?;
+        if files[..i].iter().any(|other| other.name == file.name) {
+            return Err(VboxError::invalid_data(
+                "write_iso",
+                format!("Duplicate file name: {}", file.name),
+            ));
+        }
+    }
+    let date = RecordingDate::from_unix(timestamp);
+    let primary_names = primary_names(files);
+
+    // Record sizes do not depend on the extents, so the directory sizes are known up front.
+    let primary_records = |extents: &[u32], root: u32, root_size: u32, continuation: u32| {
+        let mut records = vec![
+            DirectoryEntry::new(b"\0".to_vec(), root, root_size, FLAG_DIRECTORY)
+                .system_use(root_system_use(continuation)),
+            DirectoryEntry::new(b"\x01".to_vec(), root, root_size, FLAG_DIRECTORY)
+                .system_use(posix_attributes(DIRECTORY_MODE, 2)),
+        ];
+        let mut entries: Vec<DirectoryEntry> = files
+            .iter()
+            .zip(&primary_names)
+            .zip(extents)
+            .map(|((file, name), extent)| {
+                let mut system_use = posix_attributes(FILE_MODE, 1);
+                system_use.extend(alternate_name(file.name));
+                DirectoryEntry::new(name.as_bytes().to_vec(), *extent, file.data.len() as u32, 0)
+                    .system_use(system_use)
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        records.extend(entries);
+        records
+    };
+    let joliet_records = |extents: &[u32], root: u32, root_size: u32| {
+        let mut records = vec![
+            DirectoryEntry::new(b"\0".to_vec(), root, root_size, FLAG_DIRECTORY),
+            DirectoryEntry::new(b"\x01".to_vec(), root, root_size, FLAG_DIRECTORY),
+        ];
+        let mut entries: Vec<DirectoryEntry> = files
+            .iter()
+            .zip(extents)
+            .map(|(file, extent)| {
+                DirectoryEntry::new(ucs2(file.name), *extent, file.data.len() as u32, 0)
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        records.extend(entries);
+        records
+    };
+
+    let placeholder = vec![0; files.len()];
+    let primary_sectors = directory_sectors(&primary_records(&placeholder, 0, 0, 0));
+    let joliet_sectors = directory_sectors(&joliet_records(&placeholder, 0, 0));
+    let primary_root = PRIMARY_ROOT_LBA;
+    // Readers like libarchive go through the image sequentially and reject continuation areas
+    // in front of the directory referring to them.
+    let continuation = primary_root + primary_sectors;
+    let joliet_root = continuation + 1;
+    let mut extents = Vec::with_capacity(files.len());
+    let mut next_lba = joliet_root + joliet_sectors;
+    for file in files {
+        extents.push(next_lba);
+        next_lba += sectors(file.data.len()) as u32;
+    }
+    let total_sectors = next_lba;
+
+    let mut image = vec![0u8; total_sectors as usize * SECTOR_SIZE];
+    let primary_root_size = primary_sectors * SECTOR_SIZE as u32;
+    let joliet_root_size = joliet_sectors * SECTOR_SIZE as u32;
+    let primary_root_record = DirectoryEntry::new(
+        b"\0".to_vec(),
+        primary_root,
+        primary_root_size,
+        FLAG_DIRECTORY,
+    );
+    let joliet_root_record = DirectoryEntry::new(
+        b"\0".to_vec(),
+        joliet_root,
+        joliet_root_size,
+        FLAG_DIRECTORY,
+    );
+
+    let descriptor = volume_descriptor(
+        &VolumeDescriptor {
+            joliet: false,
+            volume_id,
+            total_sectors,
+            l_path_table: PRIMARY_L_PATH_TABLE_LBA,
+            m_path_table: PRIMARY_M_PATH_TABLE_LBA,
+            root: primary_root_record.encode(&date),
+        },
+        &date,
+    );
+    write_at(&mut image, PRIMARY_DESCRIPTOR_LBA, &descriptor);
+    let descriptor = volume_descriptor(
+        &VolumeDescriptor {
+            joliet: true,
+            volume_id,
+            total_sectors,
+            l_path_table: JOLIET_L_PATH_TABLE_LBA,
+            m_path_table: JOLIET_M_PATH_TABLE_LBA,
+            root: joliet_root_record.encode(&date),
+        },
+        &date,
+    );
+    write_at(&mut image, JOLIET_DESCRIPTOR_LBA, &descriptor);
+    let mut terminator = vec![255u8];
+    terminator.extend_from_slice(b"CD001\x01");
+    write_at(&mut image, TERMINATOR_LBA, &terminator);
+
+    write_at(
+        &mut image,
+        PRIMARY_L_PATH_TABLE_LBA,
+        &path_table(primary_root, false),
+    );
+    write_at(
+        &mut image,
+        PRIMARY_M_PATH_TABLE_LBA,
+        &path_table(primary_root, true),
+    );
+    write_at(
+        &mut image,
+        JOLIET_L_PATH_TABLE_LBA,
+        &path_table(joliet_root, false),
+    );
+    write_at(
+        &mut image,
+        JOLIET_M_PATH_TABLE_LBA,
+        &path_table(joliet_root, true),
+    );
+    write_at(&mut image, continuation, &extension_reference());
+
+    let records = primary_records(&extents, primary_root, primary_root_size, continuation);
+    write_at(&mut image, primary_root, &pack_directory(&records, &date));
+    let records = joliet_records(&extents, joliet_root, joliet_root_size);
+    write_at(&mut image, joliet_root, &pack_directory(&records, &date));
+    for (file, extent) in files.iter().zip(&extents) {
+        write_at(&mut image, *extent, file.data);
+    }
+    Ok(image)
+}
+
+fn validate_name(name: &str) -> Result<(), VboxError> {
+    let is_valid = !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name.len() <= MAX_NAME_LEN
+        && !name
+            .chars()
+            .any(|c| c.is_control() || matches!(c, '/' | '\\' | '*' | ':' | ';' | '?'));
+    if is_valid {
+        Ok(())
+    } else {
+        Err(VboxError::invalid_data(
+            "write_iso",
+            format!("Invalid file name: {}", name),
+        ))
+    }
+}
+
+fn sectors(size: usize) -> usize {
+    size.div_ceil(SECTOR_SIZE)
+}
+
+fn write_at(image: &mut [u8], lba: u32, data: &[u8]) {
+    let offset = lba as usize * SECTOR_SIZE;
+    image[offset..offset + data.len()].copy_from_slice(data);
+}
+
+fn both_endian_u16(value: u16) -> [u8; 4] {
+    let mut bytes = [0u8; 4];
+    bytes[..2].copy_from_slice(&value.to_le_bytes());
+    bytes[2..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+fn both_endian_u32(value: u32) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+    bytes[..4].copy_from_slice(&value.to_le_bytes());
+    bytes[4..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+fn ucs2(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(|c| c.to_be_bytes()).collect()
+}
+
+/// ISO 9660 level 1 names (`NAME.EXT;1` with uppercase d-characters), unique within the directory.
+fn primary_names(files: &[IsoFile]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(files.len());
+    for file in files {
+        let to_d_chars = |part: &str, len: usize| -> String {
+            part.chars()
+                .map(|c| match c.to_ascii_uppercase() {
+                    c @ ('A'..='Z' | '0'..='9') => c,
+                    _ => '_',
+                })
+                .take(len)
+                .collect()
+        };
+        let (base, extension) = match file.name.rsplit_once('.') {
+            Some((base, extension)) if !base.is_empty() => (base, extension),
+            _ => (file.name, ""),
+        };
+        let base = to_d_chars(base, 8);
+        let extension = to_d_chars(extension, 3);
+        let mut name = format!("{}.{};1", base, extension);
+        let mut counter = 1;
+        while names.contains(&name) {
+            let suffix = counter.to_string();
+            let prefix: String = base.chars().take(8 - suffix.len()).collect();
+            name = format!("{}{}.{};1", prefix, suffix, extension);
+            counter += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// Dates of directory records and volume descriptors, always in UTC.
+struct RecordingDate {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl RecordingDate {
+    fn from_unix(timestamp: u64) -> Self {
+        let days = (timestamp / 86400) as i64;
+        let seconds = (timestamp % 86400) as u32;
+        // Days to civil date, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds / 3600,
+            minute: seconds / 60 % 60,
+            second: seconds % 60,
+        }
+    }
+
+    /// The 7 byte binary form used in directory records.
+    fn record(&self) -> [u8; 7] {
+        [
+            (self.year - 1900).clamp(0, 255) as u8,
+            self.month as u8,
+            self.day as u8,
+            self.hour as u8,
+            self.minute as u8,
+            self.second as u8,
+            0,
+        ]
+    }
+
+    /// The 17 byte text form used in volume descriptors.
+    fn descriptor(&self) -> [u8; 17] {
+        let text = format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}00",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+        let mut bytes = [0u8; 17];
+        bytes[..16].copy_from_slice(text.as_bytes());
+        bytes
+    }
+}
+
+struct DirectoryEntry {
+    name: Vec<u8>,
+    extent: u32,
+    size: u32,
+    flags: u8,
+    system_use: Vec<u8>,
+}
+
+impl DirectoryEntry {
+    fn new(name: Vec<u8>, extent: u32, size: u32, flags: u8) -> Self {
+        Self {
+            name,
+            extent,
+            size,
+            flags,
+            system_use: Vec::new(),
+        }
+    }
+
+    fn system_use(mut self, system_use: Vec<u8>) -> Self {
+        self.system_use = system_use;
+        self
+    }
+
+    fn len(&self) -> usize {
+        let padding = (self.name.len() + 1) % 2;
+        let len = 33 + self.name.len() + padding + self.system_use.len();
+        // Records have an even length.
+        len + len % 2
+    }
+
+    fn encode(&self, date: &RecordingDate) -> Vec<u8> {
+        let mut record = Vec::with_capacity(self.len());
+        record.push(self.len() as u8);
+        record.push(0);
+        record.extend_from_slice(&both_endian_u32(self.extent));
+        record.extend_from_slice(&both_endian_u32(self.size));
+        record.extend_from_slice(&date.record());
+        record.push(self.flags);
+        record.push(0);
+        record.push(0);
+        record.extend_from_slice(&both_endian_u16(1));
+        record.push(self.name.len() as u8);
+        record.extend_from_slice(&self.name);
+        if self.name.len().is_multiple_of(2) {
+            record.push(0);
+        }
+        record.extend_from_slice(&self.system_use);
+        record.resize(self.len(), 0);
+        record
+    }
+}
+
+/// Number of sectors the records take. A record never crosses a sector boundary.
+fn directory_sectors(records: &[DirectoryEntry]) -> u32 {
+    let mut sectors = 1;
+    let mut used = 0;
+    for record in records {
+        if used + record.len() > SECTOR_SIZE {
+            sectors += 1;
+            used = 0;
+        }
+        used += record.len();
+    }
+    sectors
+}
+
+fn pack_directory(records: &[DirectoryEntry], date: &RecordingDate) -> Vec<u8> {
+    let mut directory = Vec::new();
+    for record in records {
+        let used = directory.len() % SECTOR_SIZE;
+        if used + record.len() > SECTOR_SIZE {
+            directory.resize(directory.len() + SECTOR_SIZE - used, 0);
+        }
+        directory.extend(record.encode(date));
+    }
+    directory
+}
+
+fn path_table(root: u32, big_endian: bool) -> Vec<u8> {
+    let mut table = vec![1, 0];
+    if big_endian {
+        table.extend_from_slice(&root.to_be_bytes());
+        table.extend_from_slice(&1u16.to_be_bytes());
+    } else {
+        table.extend_from_slice(&root.to_le_bytes());
+        table.extend_from_slice(&1u16.to_le_bytes());
+    }
+    table.extend_from_slice(&[0, 0]);
+    table
+}
+
+struct VolumeDescriptor<'a> {
+    joliet: bool,
+    volume_id: &'a str,
+    total_sectors: u32,
+    l_path_table: u32,
+    m_path_table: u32,
+    root: Vec<u8>,
+}
+
+fn volume_descriptor(volume: &VolumeDescriptor, date: &RecordingDate) -> Vec<u8> {
+    let mut descriptor = vec![0u8; SECTOR_SIZE];
+    descriptor[0] = if volume.joliet { 2 } else { 1 };
+    descriptor[1..6].copy_from_slice(b"CD001");
+    descriptor[6] = 1;
+    let text = |field: &mut [u8], value: &str| {
+        if volume.joliet {
+            for (i, pair) in field.chunks_mut(2).enumerate() {
+                let c = value.encode_utf16().nth(i).unwrap_or(b' ' as u16);
+                pair.copy_from_slice(&c.to_be_bytes()[..pair.len()]);
+            }
+        } else {
+            field.fill(b' ');
+            field[..value.len()].copy_from_slice(value.as_bytes());
+        }
+    };
+    text(&mut descriptor[8..40], "");
+    text(&mut descriptor[40..72], volume.volume_id);
+    descriptor[80..88].copy_from_slice(&both_endian_u32(volume.total_sectors));
+    if volume.joliet {
+        // UCS-2 level 3.
+        descriptor[88..91].copy_from_slice(b"%/E");
+    }
+    descriptor[120..124].copy_from_slice(&both_endian_u16(1));
+    descriptor[124..128].copy_from_slice(&both_endian_u16(1));
+    descriptor[128..132].copy_from_slice(&both_endian_u16(SECTOR_SIZE as u16));
+    descriptor[132..140].copy_from_slice(&both_endian_u32(PATH_TABLE_SIZE));
+    descriptor[140..144].copy_from_slice(&volume.l_path_table.to_le_bytes());
+    descriptor[148..152].copy_from_slice(&volume.m_path_table.to_be_bytes());
+    descriptor[156..156 + volume.root.len()].copy_from_slice(&volume.root);
+    text(&mut descriptor[190..318], "");
+    text(&mut descriptor[318..446], "");
+    text(&mut descriptor[446..574], "");
+    text(&mut descriptor[574..702], "VIRTUALBOX_RS");
+    text(&mut descriptor[702..739], "");
+    text(&mut descriptor[739..776], "");
+    text(&mut descriptor[776..813], "");
+    descriptor[813..830].copy_from_slice(&date.descriptor());
+    descriptor[830..847].copy_from_slice(&date.descriptor());
+    descriptor[847..863].copy_from_slice(b"0000000000000000");
+    descriptor[864..880].copy_from_slice(b"0000000000000000");
+    descriptor[881] = 1;
+    descriptor
+}
+
+/// The SUSP `SP` indicator, the Rock Ridge attributes of the root and a continuation entry
+/// pointing at the `ER` entry, which does not fit into a directory record.
+fn root_system_use(continuation: u32) -> Vec<u8> {
+    let mut system_use = vec![b'S', b'P', 7, 1, 0xBE, 0xEF, 0];
+    system_use.extend(posix_attributes(DIRECTORY_MODE, 2));
+    system_use.extend_from_slice(&[b'C', b'E', 28, 1]);
+    system_use.extend_from_slice(&both_endian_u32(continuation));
+    system_use.extend_from_slice(&both_endian_u32(0));
+    system_use.extend_from_slice(&both_endian_u32(extension_reference().len() as u32));
+    system_use
+}
+
+fn extension_reference() -> Vec<u8> {
+    let len = 8 + RRIP_ID.len() + RRIP_DESCRIPTOR.len() + RRIP_SOURCE.len();
+    let mut entry = vec![
+        b'E',
+        b'R',
+        len as u8,
+        1,
+        RRIP_ID.len() as u8,
+        RRIP_DESCRIPTOR.len() as u8,
+        RRIP_SOURCE.len() as u8,
+        1,
+    ];
+    entry.extend_from_slice(RRIP_ID.as_bytes());
+    entry.extend_from_slice(RRIP_DESCRIPTOR.as_bytes());
+    entry.extend_from_slice(RRIP_SOURCE.as_bytes());
+    entry
+}
+
+/// Rock Ridge `PX` entry, owned by root.
+fn posix_attributes(mode: u32, links: u32) -> Vec<u8> {
+    let mut entry = vec![b'P', b'X', 36, 1];
+    entry.extend_from_slice(&both_endian_u32(mode));
+    entry.extend_from_slice(&both_endian_u32(links));
+    entry.extend_from_slice(&both_endian_u32(0));
+    entry.extend_from_slice(&both_endian_u32(0));
+    entry
+}
+
+/// Rock Ridge `NM` entry with the original file name.
+fn alternate_name(name: &str) -> Vec<u8> {
+    let mut entry = vec![b'N', b'M', (5 + name.len()) as u8, 1, 0];
+    entry.extend_from_slice(name.as_bytes());
+    entry
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A file found by [`read_iso`].
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct ParsedFile {
+        /// Name in the primary directory.
+        pub primary_name: String,
+        /// Name from the Rock Ridge `NM` entry.
+        pub rock_ridge_name: String,
+        pub data: Vec<u8>,
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct ParsedIso {
+        pub volume_id: String,
+        pub joliet_volume_id: String,
+        pub has_rock_ridge: bool,
+        pub files: Vec<ParsedFile>,
+        pub joliet_files: Vec<(String, Vec<u8>)>,
+    }
+
+    fn u32_le(data: &[u8], offset: usize) -> usize {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize
+    }
+
+    fn sector(image: &[u8], lba: usize) -> &[u8] {
+        &image[lba * SECTOR_SIZE..(lba + 1) * SECTOR_SIZE]
+    }
+
+    fn ucs2_to_string(data: &[u8]) -> String {
+        let units: Vec<u16> = data
+            .chunks(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16(&units).unwrap()
+    }
+
+    /// Records of a directory as `(name, extent, size, system use)`.
+    fn read_directory(image: &[u8], root_record: &[u8]) -> Vec<(Vec<u8>, usize, usize, Vec<u8>)> {
+        let extent = u32_le(root_record, 2);
+        let size = u32_le(root_record, 10);
+        let directory = &image[extent * SECTOR_SIZE..extent * SECTOR_SIZE + size];
+        let mut records = Vec::new();
+        let mut offset = 0;
+        while offset < directory.len() {
+            let len = directory[offset] as usize;
+            if len == 0 {
+                offset = (offset / SECTOR_SIZE + 1) * SECTOR_SIZE;
+                continue;
+            }
+            let record = &directory[offset..offset + len];
+            let name_len = record[32] as usize;
+            let system_use_start = 33 + name_len + (name_len + 1) % 2;
+            records.push((
+                record[33..33 + name_len].to_vec(),
+                u32_le(record, 2),
+                u32_le(record, 10),
+                record[system_use_start..].to_vec(),
+            ));
+            offset += len;
+        }
+        records
+    }
+
+    fn system_use_entry<'a>(system_use: &'a [u8], signature: &[u8]) -> Option<&'a [u8]> {
+        let mut offset = 0;
+        while offset + 4 <= system_use.len() {
+            let len = system_use[offset + 2] as usize;
+            if &system_use[offset..offset + 2] == signature {
+                return Some(&system_use[offset..offset + len]);
+            }
+            offset += len;
+        }
+        None
+    }
+
+    pub(crate) fn read_iso(image: &[u8]) -> ParsedIso {
+        let primary = sector(image, 16);
+        assert_eq!(&primary[0..7], b"\x01CD001\x01");
+        let joliet = sector(image, 17);
+        assert_eq!(&joliet[0..7], b"\x02CD001\x01");
+        assert_eq!(&joliet[88..91], b"%/E");
+        assert_eq!(&sector(image, 18)[0..7], b"\xffCD001\x01");
+        assert_eq!(u32_le(primary, 80) * SECTOR_SIZE, image.len());
+
+        let primary_records = read_directory(image, &primary[156..190]);
+        let root_system_use = &primary_records[0].3;
+        let has_rock_ridge = system_use_entry(root_system_use, b"SP").is_some()
+            && system_use_entry(root_system_use, b"CE")
+                .map(|entry| {
+                    let continuation = sector(image, u32_le(entry, 4));
+                    &continuation[0..2] == b"ER" && &continuation[8..18] == RRIP_ID.as_bytes()
+                })
+                .unwrap_or(false);
+        let files = primary_records[2..]
+            .iter()
+            .map(|(name, extent, size, system_use)| {
+                let nm = system_use_entry(system_use, b"NM").unwrap();
+                ParsedFile {
+                    primary_name: String::from_utf8(name.clone()).unwrap(),
+                    rock_ridge_name: String::from_utf8(nm[5..].to_vec()).unwrap(),
+                    data: image[extent * SECTOR_SIZE..extent * SECTOR_SIZE + size].to_vec(),
+                }
+            })
+            .collect();
+        let joliet_files = read_directory(image, &joliet[156..190])[2..]
+            .iter()
+            .map(|(name, extent, size, _)| {
+                (
+                    ucs2_to_string(name),
+                    image[extent * SECTOR_SIZE..extent * SECTOR_SIZE + size].to_vec(),
+                )
+            })
+            .collect();
+        ParsedIso {
+            volume_id: String::from_utf8(primary[40..72].to_vec())
+                .unwrap()
+                .trim_end()
+                .to_string(),
+            joliet_volume_id: ucs2_to_string(&joliet[40..72]).trim_end().to_string(),
+            has_rock_ridge,
+            files,
+            joliet_files,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let large = vec![0x5Au8; SECTOR_SIZE * 2 + 1];
+        let files = [
+            IsoFile {
+                name: "user-data",
+                data: b"#cloud-config\n",
+            },
+            IsoFile {
+                name: "meta-data",
+                data: b"instance-id: \"iid\"\n",
+            },
+            IsoFile {
+                name: "empty",
+                data: b"",
+            },
+            IsoFile {
+                name: "large.bin",
+                data: &large,
+            },
+        ];
+        let image = write_iso("cidata", &files, 1_700_000_000).unwrap();
+        let iso = read_iso(&image);
+        assert_eq!(iso.volume_id, "cidata");
+        assert_eq!(iso.joliet_volume_id, "cidata");
+        assert!(iso.has_rock_ridge);
+
+        let names: Vec<(&str, &str)> = iso
+            .files
+            .iter()
+            .map(|file| (file.primary_name.as_str(), file.rock_ridge_name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("EMPTY.;1", "empty"),
+                ("LARGE.BIN;1", "large.bin"),
+                ("META_DAT.;1", "meta-data"),
+                ("USER_DAT.;1", "user-data"),
+            ]
+        );
+        for file in &files {
+            let parsed = iso
+                .files
+                .iter()
+                .find(|parsed| parsed.rock_ridge_name == file.name)
+                .unwrap();
+            assert_eq!(parsed.data, file.data);
+            let (_, joliet_data) = iso
+                .joliet_files
+                .iter()
+                .find(|(name, _)| name == file.name)
+                .unwrap();
+            assert_eq!(joliet_data, file.data);
+        }
+    }
+
+    #[test]
+    fn directory_spans_several_sectors() {
+        let names: Vec<String> = (0..60).map(|i| format!("file-number-{:02}", i)).collect();
+        let files: Vec<IsoFile> = names
+            .iter()
+            .map(|name| IsoFile {
+                name,
+                data: name.as_bytes(),
+            })
+            .collect();
+        let iso = read_iso(&write_iso("cidata", &files, 0).unwrap());
+        assert_eq!(iso.files.len(), 60);
+        assert_eq!(iso.joliet_files.len(), 60);
+        let primary_names: std::collections::HashSet<&str> = iso
+            .files
+            .iter()
+            .map(|file| file.primary_name.as_str())
+            .collect();
+        assert_eq!(primary_names.len(), 60);
+        for file in &iso.files {
+            assert_eq!(file.data, file.rock_ridge_name.as_bytes());
+        }
+    }
+
+    #[test]
+    fn recording_date() {
+        let date = RecordingDate::from_unix(951_827_696);
+        assert_eq!(&date.descriptor()[..16], b"2000022912345600");
+        assert_eq!(date.record(), [100, 2, 29, 12, 34, 56, 0]);
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        for name in ["", "..", "a/b", "a;1"] {
+            let files = [IsoFile { name, data: b"" }];
+            assert!(write_iso("cidata", &files, 0).is_err());
+        }
+        let files = [
+            IsoFile {
+                name: "user-data",
+                data: b"",
+            },
+            IsoFile {
+                name: "user-data",
+                data: b"",
+            },
+        ];
+        assert!(write_iso("cidata", &files, 0).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+mod config;
+mod iso9660;
+
+use crate::enums::{AccessMode, DeviceType};
+use crate::{Machine, Medium, VboxError, VirtualBox};
+pub use config::{CloudUser, EthernetConfig, MetaData, NetworkConfig, UserData};
+use iso9660::{write_iso, IsoFile};
+use log::debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Volume label the cloud-init NoCloud data source looks for.
+const VOLUME_ID: &str = "cidata";
+
+/// Generator of cloud-init NoCloud seed ISO images.
+///
+/// The image is labelled `cidata` and holds the `meta-data`, `user-data` and optionally the
+/// `network-config` files in its root directory. It is written by a pure Rust ISO 9660 writer with
+/// Joliet and Rock Ridge extensions, so neither `genisoimage` nor a VirtualBox installation is
+/// needed to build it. Attached as a DVD, it configures Linux and BSD cloud images on their first boot.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{CloudInitSeed, CloudUser, MetaData, UserData};
+///
+/// let mut user = CloudUser::new("admin");
+/// user.sudo = Some("ALL=(ALL) NOPASSWD:ALL".to_string());
+/// user.ssh_authorized_keys = vec!["ssh-ed25519 AAAAC3Nza... admin@host".to_string()];
+/// let user_data = UserData {
+///     hostname: Some("ubuntu".to_string()),
+///     users: vec![user],
+///     ..Default::default()
+/// };
+/// CloudInitSeed::new(MetaData::new("iid-ubuntu-24"))
+///     .user_data(user_data)
+///     .create("/home/user/VirtualBox VMs/Ubuntu/seed.iso")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CloudInitSeed {
+    meta_data: MetaData,
+    user_data: Option<UserData>,
+    network_config: Option<NetworkConfig>,
+}
+
+impl CloudInitSeed {
+    /// Creates a seed with the given `meta-data` and an empty `#cloud-config` as `user-data`.
+    pub fn new(meta_data: MetaData) -> Self {
+        Self {
+            meta_data,
+            user_data: None,
+            network_config: None,
+        }
+    }
+
+    /// Sets the `user-data` file.
+    pub fn user_data(mut self, user_data: UserData) -> Self {
+        self.user_data = Some(user_data);
+        self
+    }
+
+    /// Adds the `network-config` file. Without it, the guest configures its first interface with DHCP.
+    pub fn network_config(mut self, network_config: NetworkConfig) -> Self {
+        self.network_config = Some(network_config);
+        self
+    }
+
+    /// Builds the ISO image in memory.
+    ///
+    /// # Returns
+    ///
+    /// Returns the image on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{CloudInitSeed, MetaData};
+    ///
+    /// let image = CloudInitSeed::new(MetaData::new("iid-ubuntu-24")).to_iso().unwrap();
+    /// ```
+    pub fn to_iso(&self) -> Result<Vec<u8>, VboxError> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        self.to_iso_at(timestamp)
+    }
+
+    fn to_iso_at(&self, timestamp: u64) -> Result<Vec<u8>, VboxError> {
+        let meta_data = self.meta_data.to_yaml();
+        let user_data = self.user_data.clone().unwrap_or_default().to_yaml();
+        let network_config = self.network_config.as_ref().map(|config| config.to_yaml());
+
+        let mut files = vec![
+            IsoFile {
+                name: "meta-data",
+                data: meta_data.as_bytes(),
+            },
+            IsoFile {
+                name: "user-data",
+                data: user_data.as_bytes(),
+            },
+        ];
+        if let Some(network_config) = &network_config {
+            files.push(IsoFile {
+                name: "network-config",
+                data: network_config.as_bytes(),
+            });
+        }
+        // The contents may carry passwords and keys, only their sizes are logged.
+        for file in &files {
+            debug!("CloudInitSeed {}: {} bytes", file.name, file.data.len());
+        }
+        write_iso(VOLUME_ID, &files, timestamp)
+    }
+
+    /// Writes the ISO image to `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Location of the image, usually with an `.iso` extension.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{CloudInitSeed, MetaData};
+    ///
+    /// CloudInitSeed::new(MetaData::new("iid-ubuntu-24"))
+    ///     .create("/home/user/VirtualBox VMs/Ubuntu/seed.iso")
+    ///     .unwrap();
+    /// ```
+    pub fn create(&self, path: &str) -> Result<(), VboxError> {
+        let image = self.to_iso()?;
+        std::fs::write(path, image).map_err(|err| VboxError::io_error("CloudInitSeed::create", err))
+    }
+
+    /// Writes the ISO image to `path`, registers it as a DVD medium and attaches it to the machine.
+    ///
+    /// The machine must be locked by a session, and the settings must be saved afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `vbox` - &[`VirtualBox`].
+    /// * `machine` - &[`Machine`]. Mutable machine obtained from the session.
+    /// * `path` - &str. Location of the image.
+    /// * `controller_name` - &str. Name of the storage controller to attach the DVD drive to.
+    /// * `controller_port` - i32. Port number to attach the DVD drive to.
+    /// * `device` - i32. Device slot in the given port to attach the DVD drive to.
+    ///
+    /// # Returns
+    ///
+    /// Returns the registered [`Medium`] on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{CloudInitSeed, MetaData, Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.find_machines("Ubuntu").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Write).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    ///
+    /// CloudInitSeed::new(MetaData::new("iid-ubuntu-24"))
+    ///     .attach(
+    ///         &vbox,
+    ///         &machine_mut,
+    ///         "/home/user/VirtualBox VMs/Ubuntu/seed.iso",
+    ///         "SATA",
+    ///         1,
+    ///         0,
+    ///     )
+    ///     .unwrap();
+    /// machine_mut.save_settings().unwrap();
+    /// ```
+    pub fn attach(
+        &self,
+        vbox: &VirtualBox,
+        machine: &Machine,
+        path: &str,
+        controller_name: &str,
+        controller_port: i32,
+        device: i32,
+    ) -> Result<Medium, VboxError> {
+        self.create(path)?;
+        let medium = vbox.open_medium(path, DeviceType::DVD, AccessMode::ReadOnly, false)?;
+        machine.attach_device(
+            controller_name,
+            controller_port,
+            device,
+            DeviceType::DVD,
+            Some(&medium),
+        )?;
+        Ok(medium)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cloud_init_seed::iso9660::tests::read_iso;
+
+    #[test]
+    fn seed_contains_cloud_init_files() {
+        let mut meta_data = MetaData::new("iid-ubuntu-24");
+        meta_data.local_hostname = Some("ubuntu".to_string());
+        let mut ethernet = EthernetConfig::new("enp0s3");
+        ethernet.dhcp4 = true;
+        let image = CloudInitSeed::new(meta_data)
+            .network_config(NetworkConfig {
+                ethernets: vec![ethernet],
+            })
+            .to_iso_at(1_700_000_000)
+            .unwrap();
+
+        let iso = read_iso(&image);
+        assert_eq!(iso.volume_id, "cidata");
+        assert_eq!(iso.joliet_volume_id, "cidata");
+        let files: Vec<(&str, &str)> = iso
+            .joliet_files
+            .iter()
+            .map(|(name, data)| (name.as_str(), std::str::from_utf8(data).unwrap()))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "meta-data",
+                    "instance-id: \"iid-ubuntu-24\"\nlocal-hostname: \"ubuntu\"\n"
+                ),
+                (
+                    "network-config",
+                    "version: 2\nethernets:\n  \"enp0s3\":\n    dhcp4: true\n"
+                ),
+                ("user-data", "#cloud-config\n"),
+            ]
+        );
+    }
+}
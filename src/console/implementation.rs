@@ -4,10 +4,15 @@ use crate::progress::Progress;
 use crate::utility::macros::macros::{
     get_function_result_pointer, get_function_result_pointer_vec, get_function_result_unit,
 };
-use crate::{EventSource, Guest, Keyboard, Mouse, PCIDeviceAttachment, VboxError};
+use crate::utility::string_to_c_u64_str;
+use crate::{
+    EventSource, Guest, Keyboard, Mouse, PCIDeviceAttachment, SharedFolder, VRDEServerInfo,
+    VboxError,
+};
 use log::debug;
 use vbox_raw::sys_lib::{
     IDisplay, IEventSource, IGuest, IKeyboard, IMouse, IPCIDeviceAttachment, IProgress,
+    ISharedFolder, IVRDEServerInfo,
 };
 
 impl Console {
@@ -389,4 +394,147 @@ impl Console {
             .map(|object| PCIDeviceAttachment::new(object.clone()))
             .collect())
     }
+
+    /// Interface that provides information on Remote Desktop Extension (VRDE) connection.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`VRDEServerInfo`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    pub fn get_vrde_server_info(&self) -> Result<VRDEServerInfo, VboxError> {
+        let vrde_server_info =
+            get_function_result_pointer!(self.object, GetVRDEServerInfo, *mut IVRDEServerInfo)?;
+        Ok(VRDEServerInfo::new(vrde_server_info))
+    }
+
+    /// Collection of shared folders for the current session (transient shared folders).
+    ///
+    /// These folders are available to the guest OS only while the virtual machine is running and disappear when it is powered off.
+    ///
+    /// New shared folders are added to the collection using [`Console::create_shared_folder`]. Existing shared folders can be removed using [`Console::remove_shared_folder`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<SharedFolder>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let shared_folders = console.get_shared_folders().unwrap();
+    pub fn get_shared_folders(&self) -> Result<Vec<SharedFolder>, VboxError> {
+        let shared_folders =
+            get_function_result_pointer_vec!(self.object, GetSharedFolders, *mut ISharedFolder)?;
+        Ok(shared_folders.into_iter().map(SharedFolder::new).collect())
+    }
+
+    /// Creates a transient new shared folder by associating the given logical name with the given host path, adds it to the collection of shared folders and starts sharing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. Unique logical name of the shared folder.
+    /// * `host_path` - &str. Full path to the shared folder in the host file system.
+    /// * `writable` - bool. Whether the share is writable or read-only.
+    /// * `automount` - bool. Whether the share gets automatically mounted by the guest or not.
+    /// * `auto_mount_point` - &str. Where the guest should automatically mount the folder, if possible. For Windows and OS/2 guests this should be a drive letter, while other guests it should be a absolute directory.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// console.create_shared_folder(
+    ///     "artifacts",
+    ///     "/home/host_user/artifacts",
+    ///     true,
+    ///     true,
+    ///     "/mnt/artifacts"
+    /// ).unwrap();
+    pub fn create_shared_folder(
+        &self,
+        name: &str,
+        host_path: &str,
+        writable: bool,
+        automount: bool,
+        auto_mount_point: &str,
+    ) -> Result<(), VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        let host_path = string_to_c_u64_str(host_path)?;
+        let writable = if writable { 1 } else { 0 };
+        let automount = if automount { 1 } else { 0 };
+        let auto_mount_point = string_to_c_u64_str(auto_mount_point)?;
+        get_function_result_unit!(
+            self.object,
+            CreateSharedFolder,
+            name,
+            host_path,
+            writable,
+            automount,
+            auto_mount_point
+        )
+    }
+
+    /// Removes a transient shared folder with the given name previously created by [`Console::create_shared_folder`] from the collection of shared folders and stops sharing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. Logical name of the shared folder to remove.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// console.remove_shared_folder("artifacts").unwrap();
+    pub fn remove_shared_folder(&self, name: &str) -> Result<(), VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        get_function_result_unit!(self.object, RemoveSharedFolder, name)
+    }
 }
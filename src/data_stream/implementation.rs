@@ -1,3 +1,4 @@
+use crate::utility::array_out_to_vec;
 use crate::utility::macros::macros::{get_function_result_number, get_function_result_pointer};
 use crate::{DataStream, VboxError};
 
@@ -41,6 +42,9 @@ impl DataStream {
     /// Returns [`&[u8]`], or a [`VboxError`] on failure.
     ///  -Array of data read. This may be shorter than the specified size. Returning a zero-sized array indicates the end of the stream, if the status is successful.
     ///
+    /// The array stays allocated by VirtualBox, use [`crate::DataStreamReader`] to read a whole
+    /// converted image.
+    ///
     ///  # Example
     ///
     /// ```no_run
@@ -66,8 +70,31 @@ impl DataStream {
             size,
             timeout_ms,
             &mut data_size
-        )?;
-        let data_slice = unsafe { std::slice::from_raw_parts(data_ptr, size as usize) };
+        );
+        let data_ptr = match data_ptr {
+            Ok(data_ptr) => data_ptr,
+            Err(err) if err.is_null() => return Ok(&[]),
+            Err(err) => return Err(err),
+        };
+        let data_slice = unsafe { std::slice::from_raw_parts(data_ptr, data_size as usize) };
         Ok(data_slice)
     }
+
+    /// Like [`DataStream::read`], but copies the data and frees the buffer allocated by VirtualBox.
+    pub(crate) fn read_to_vec(&self, size: u32, timeout_ms: u32) -> Result<Vec<u8>, VboxError> {
+        let mut data_size: u32 = 0;
+        let data_ptr = get_function_result_pointer!(
+            self.object,
+            Read,
+            *mut u8,
+            size,
+            timeout_ms,
+            &mut data_size
+        );
+        match data_ptr {
+            Ok(data_ptr) => array_out_to_vec(data_ptr, data_size),
+            Err(err) if err.is_null() => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
 }
@@ -1,10 +1,13 @@
 mod implementation;
+mod reader;
 
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IDataStream;
 
+pub use reader::DataStreamReader;
+
 /// The IDataStream interface is used to retrieve a data stream.
 ///
 /// **Reference to the official documentation:**
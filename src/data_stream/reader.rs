@@ -0,0 +1,98 @@
+use crate::{DataStream, Progress, VboxError};
+use std::io::Read;
+
+/// [`Read`] adapter over the [`DataStream`] and [`Progress`] returned by
+/// [`crate::MediumIO::convert_to_stream`].
+///
+/// Reads pull data from the stream while VirtualBox converts the medium. The end of the stream is
+/// reported only after the progress completed successfully, a failed conversion is returned as an
+/// error instead of a truncated image. The converted image never touches the disk, so it can be
+/// written straight into an upload, a hasher or a compressor.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Read;
+/// use virtualbox_rs::enums::MediumVariant;
+/// use virtualbox_rs::VirtualBox;
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let mediums = vbox.get_hard_disks().unwrap();
+/// let medium = mediums.get(0).unwrap();
+/// let medium_io = medium.open_for_io(false, "").unwrap();
+/// let mut reader = medium_io
+///     .convert_to_reader("VMDK", vec![MediumVariant::VmdkStreamOptimized], 64 * 1024 * 1024)
+///     .unwrap();
+/// let mut output = std::fs::File::create("/home/user/upload/disk.vmdk").unwrap();
+/// std::io::copy(&mut reader, &mut output).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DataStreamReader {
+    stream: DataStream,
+    progress: Progress,
+    timeout_ms: u32,
+    finished: bool,
+}
+
+impl DataStreamReader {
+    /// Creates a reader which waits without a time limit for data to become available.
+    pub fn new(stream: DataStream, progress: Progress) -> Self {
+        Self {
+            stream,
+            progress,
+            timeout_ms: 0,
+            finished: false,
+        }
+    }
+
+    /// Limits the wait for data in a single read. An elapsed timeout is returned as
+    /// [`std::io::ErrorKind::TimedOut`] and the read can be retried.
+    pub fn timeout_ms(mut self, timeout_ms: u32) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// The conversion progress.
+    pub fn get_progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// Returns the failed conversion result, or the read error if the conversion is still running.
+    fn read_error(&self, err: VboxError) -> VboxError {
+        // The completion state is only updated while waiting.
+        let completed = self
+            .progress
+            .wait_for_completion(0)
+            .and_then(|_| self.progress.get_completed());
+        match completed {
+            Ok(true) => self
+                .progress
+                .wait_for_success("DataStreamReader::read")
+                .err()
+                .unwrap_or(err),
+            _ => err,
+        }
+    }
+}
+
+impl Read for DataStreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.finished {
+            return Ok(0);
+        }
+        let size = buf.len().min(u32::MAX as usize) as u32;
+        let data = match self.stream.read_to_vec(size, self.timeout_ms) {
+            Ok(data) => data,
+            Err(err) => return Err(self.read_error(err).into()),
+        };
+        if data.is_empty() {
+            // An empty read is the end of the stream, valid only if the conversion succeeded.
+            self.progress.wait_for_success("DataStreamReader::read")?;
+            self.finished = true;
+            return Ok(0);
+        }
+        let count = data.len().min(buf.len());
+        buf[..count].copy_from_slice(&data[..count]);
+        Ok(count)
+    }
+}
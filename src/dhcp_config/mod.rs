@@ -0,0 +1,545 @@
+/// Implements the methods of `IDHCPConfig` for one of the interfaces derived from it.
+///
+/// The interfaces derived from `IDHCPConfig` share the beginning of their vtable, so the methods
+/// only rely on the `object` field of `$name`. The invoking module has to import the items used
+/// by the methods: [`crate::enums::DHCPConfigScope`], [`crate::enums::DHCPOption`],
+/// [`crate::enums::DHCPOptionEncoding`], [`crate::VboxError`], `c_u64_str_to_string`,
+/// `string_to_c_u64_str` and the `get_function_result_*` macros.
+///
+/// `$getter` is the [`crate::DHCPServer`] call used to obtain the configuration in the examples.
+macro_rules! impl_dhcp_config {
+    ($name:ident, $getter:literal) => {
+        impl $name {
+            /// Indicates the kind of config this is.
+            ///
+            /// # Returns
+            ///
+            /// Returns [`DHCPConfigScope`] on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let scope = config.get_scope().unwrap();
+            pub fn get_scope(&self) -> Result<DHCPConfigScope, VboxError> {
+                let scope = get_function_result_number!(self.object, GetScope, u32)?;
+                Ok(DHCPConfigScope::from(scope))
+            }
+
+            /// The minimum lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Returns
+            ///
+            /// Returns u32 on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let min_lease_time = config.get_min_lease_time().unwrap();
+            pub fn get_min_lease_time(&self) -> Result<u32, VboxError> {
+                get_function_result_number!(self.object, GetMinLeaseTime, u32)
+            }
+
+            /// The minimum lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Arguments
+            ///
+            /// * `min_lease_time` - u32.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.set_min_lease_time(300).unwrap();
+            pub fn set_min_lease_time(&self, min_lease_time: u32) -> Result<(), VboxError> {
+                get_function_result_unit!(self.object, SetMinLeaseTime, min_lease_time)
+            }
+
+            /// The default lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Returns
+            ///
+            /// Returns u32 on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let default_lease_time = config.get_default_lease_time().unwrap();
+            pub fn get_default_lease_time(&self) -> Result<u32, VboxError> {
+                get_function_result_number!(self.object, GetDefaultLeaseTime, u32)
+            }
+
+            /// The default lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Arguments
+            ///
+            /// * `default_lease_time` - u32.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.set_default_lease_time(600).unwrap();
+            pub fn set_default_lease_time(&self, default_lease_time: u32) -> Result<(), VboxError> {
+                get_function_result_unit!(self.object, SetDefaultLeaseTime, default_lease_time)
+            }
+
+            /// The maximum lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Returns
+            ///
+            /// Returns u32 on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let max_lease_time = config.get_max_lease_time().unwrap();
+            pub fn get_max_lease_time(&self) -> Result<u32, VboxError> {
+                get_function_result_number!(self.object, GetMaxLeaseTime, u32)
+            }
+
+            /// The maximum lease time in seconds, 0 to inherit it from the parent configuration.
+            ///
+            /// # Arguments
+            ///
+            /// * `max_lease_time` - u32.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.set_max_lease_time(3600).unwrap();
+            pub fn set_max_lease_time(&self, max_lease_time: u32) -> Result<(), VboxError> {
+                get_function_result_unit!(self.object, SetMaxLeaseTime, max_lease_time)
+            }
+
+            /// Options the DHCP server sends to the client whether or not it asked for them.
+            ///
+            /// # Returns
+            ///
+            /// Returns [`Vec<DHCPOption>`] on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let forced_options = config.get_forced_options().unwrap();
+            pub fn get_forced_options(&self) -> Result<Vec<DHCPOption>, VboxError> {
+                let forced_options =
+                    get_function_result_pointer_vec!(self.object, GetForcedOptions, u32)?;
+                Ok(forced_options.into_iter().map(DHCPOption::from).collect())
+            }
+
+            /// Options the DHCP server sends to the client whether or not it asked for them.
+            ///
+            /// # Arguments
+            ///
+            /// * `forced_options` - [`Vec<DHCPOption>`].
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::enums::DHCPOption;
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.set_forced_options(vec![DHCPOption::DomainSearch]).unwrap();
+            pub fn set_forced_options(
+                &self,
+                forced_options: Vec<DHCPOption>,
+            ) -> Result<(), VboxError> {
+                let mut forced_options: Vec<u32> = forced_options
+                    .into_iter()
+                    .map(|option| option.into())
+                    .collect();
+                let forced_options_size = forced_options.len() as u32;
+                let forced_options_ptr = forced_options.as_mut_ptr();
+                get_function_result_unit!(
+                    self.object,
+                    SetForcedOptions,
+                    forced_options_size,
+                    forced_options_ptr
+                )
+            }
+
+            /// Options the DHCP server never sends to the client, even if it asked for them.
+            ///
+            /// # Returns
+            ///
+            /// Returns [`Vec<DHCPOption>`] on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let suppressed_options = config.get_suppressed_options().unwrap();
+            pub fn get_suppressed_options(&self) -> Result<Vec<DHCPOption>, VboxError> {
+                let suppressed_options =
+                    get_function_result_pointer_vec!(self.object, GetSuppressedOptions, u32)?;
+                Ok(suppressed_options
+                    .into_iter()
+                    .map(DHCPOption::from)
+                    .collect())
+            }
+
+            /// Options the DHCP server never sends to the client, even if it asked for them.
+            ///
+            /// # Arguments
+            ///
+            /// * `suppressed_options` - [`Vec<DHCPOption>`].
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::enums::DHCPOption;
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.set_suppressed_options(vec![DHCPOption::DomainSearch]).unwrap();
+            pub fn set_suppressed_options(
+                &self,
+                suppressed_options: Vec<DHCPOption>,
+            ) -> Result<(), VboxError> {
+                let mut suppressed_options: Vec<u32> = suppressed_options
+                    .into_iter()
+                    .map(|option| option.into())
+                    .collect();
+                let suppressed_options_size = suppressed_options.len() as u32;
+                let suppressed_options_ptr = suppressed_options.as_mut_ptr();
+                get_function_result_unit!(
+                    self.object,
+                    SetSuppressedOptions,
+                    suppressed_options_size,
+                    suppressed_options_ptr
+                )
+            }
+
+            /// Sets a DHCP option.
+            ///
+            /// # Arguments
+            ///
+            /// * `option` - [`DHCPOption`]. The DHCP option.
+            /// * `encoding` - [`DHCPOptionEncoding`]. The value encoding.
+            /// * `value` - &str. The DHCP option value.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::enums::{DHCPOption, DHCPOptionEncoding};
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config
+            ///     .set_option(DHCPOption::DomainName, DHCPOptionEncoding::Normal, "lab.local")
+            ///     .unwrap();
+            pub fn set_option(
+                &self,
+                option: DHCPOption,
+                encoding: DHCPOptionEncoding,
+                value: &str,
+            ) -> Result<(), VboxError> {
+                let option: u32 = option.into();
+                let encoding: u32 = encoding.into();
+                let value = string_to_c_u64_str(value)?;
+                get_function_result_unit!(self.object, SetOption, option, encoding, value)
+            }
+
+            /// Removes the given DHCP option.
+            ///
+            /// # Arguments
+            ///
+            /// * `option` - [`DHCPOption`]. The DHCP option.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::enums::DHCPOption;
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.remove_option(DHCPOption::DomainName).unwrap();
+            pub fn remove_option(&self, option: DHCPOption) -> Result<(), VboxError> {
+                let option: u32 = option.into();
+                get_function_result_unit!(self.object, RemoveOption, option)
+            }
+
+            /// Removes all the options.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.remove_all_options().unwrap();
+            pub fn remove_all_options(&self) -> Result<(), VboxError> {
+                get_function_result_unit!(self.object, RemoveAllOptions)
+            }
+
+            /// Gets the value of a single DHCP option.
+            ///
+            /// # Arguments
+            ///
+            /// * `option` - [`DHCPOption`]. The DHCP option being sought.
+            ///
+            /// # Returns
+            ///
+            /// Returns (encoding, value), or a [`VboxError`] on failure.
+            /// - encoding: [`DHCPOptionEncoding`]. The value encoding.
+            /// - value: &str. The value of the requested DHCP option.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::enums::DHCPOption;
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let (encoding, value) = config.get_option(DHCPOption::DomainName).unwrap();
+            pub fn get_option(
+                &self,
+                option: DHCPOption,
+            ) -> Result<(DHCPOptionEncoding, &'static str), VboxError> {
+                let option: u32 = option.into();
+                let mut encoding = 0;
+                let value = get_function_result_pointer!(
+                    self.object,
+                    GetOption,
+                    *mut u16,
+                    option,
+                    &mut encoding
+                )?;
+                Ok((
+                    DHCPOptionEncoding::from(encoding),
+                    c_u64_str_to_string(value)?,
+                ))
+            }
+
+            /// Gets all DHCP options and their values.
+            ///
+            /// # Returns
+            ///
+            /// Returns a list of (option, encoding, value), or a [`VboxError`] on failure.
+            /// - option: [`DHCPOption`]. The DHCP option.
+            /// - encoding: [`DHCPOptionEncoding`]. The value encoding.
+            /// - value: &str. The value of the DHCP option.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// let options = config.get_all_options().unwrap();
+            pub fn get_all_options(
+                &self,
+            ) -> Result<Vec<(DHCPOption, DHCPOptionEncoding, &'static str)>, VboxError> {
+                let mut options_size = 0;
+                let mut options: *mut u32 = std::ptr::null_mut();
+                let mut encodings_size = 0;
+                let mut encodings: *mut u32 = std::ptr::null_mut();
+                let mut values_size = 0;
+                let mut values: *mut *mut u16 = std::ptr::null_mut();
+                get_function_result_unit!(
+                    self.object,
+                    GetAllOptions,
+                    &mut options_size,
+                    &mut options,
+                    &mut encodings_size,
+                    &mut encodings,
+                    &mut values_size,
+                    &mut values
+                )?;
+                if options_size == 0 {
+                    return Ok(Vec::new());
+                }
+                if options.is_null() || encodings.is_null() || values.is_null() {
+                    return Err(VboxError::null_pointer_error("GetAllOptions"));
+                }
+                if encodings_size != options_size || values_size != options_size {
+                    return Err(VboxError::vectors_length_mismatch("GetAllOptions"));
+                }
+                let options = unsafe {
+                    Vec::from_raw_parts(options, options_size as usize, options_size as usize)
+                };
+                let encodings = unsafe {
+                    Vec::from_raw_parts(encodings, encodings_size as usize, encodings_size as usize)
+                };
+                let values = unsafe {
+                    Vec::from_raw_parts(values, values_size as usize, values_size as usize)
+                };
+                options
+                    .into_iter()
+                    .zip(encodings)
+                    .zip(values)
+                    .map(|((option, encoding), value)| {
+                        Ok((
+                            DHCPOption::from(option),
+                            DHCPOptionEncoding::from(encoding),
+                            c_u64_str_to_string(value)?,
+                        ))
+                    })
+                    .collect()
+            }
+
+            /// Remove this group or individual configuration.
+            ///
+            /// Will of course not work on global configurations.
+            ///
+            /// # Returns
+            ///
+            /// Returns () on success, or a [`VboxError`] on failure.
+            ///
+            ///  # Example
+            ///
+            /// ```no_run
+            ///
+            /// use virtualbox_rs::VirtualBox;
+            ///
+            /// let vbox = VirtualBox::init().unwrap();
+            /// let dhcp_server = vbox
+            ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+            ///     .unwrap();
+            #[doc = concat!(" let config = dhcp_server.", $getter, ".unwrap();")]
+            /// config.remove().unwrap();
+            pub fn remove(&self) -> Result<(), VboxError> {
+                get_function_result_unit!(self.object, Remove)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_dhcp_config;
@@ -0,0 +1,11 @@
+use crate::dhcp_config::impl_dhcp_config;
+use crate::enums::{DHCPConfigScope, DHCPOption, DHCPOptionEncoding};
+use crate::utility::c_u64_str_to_string;
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{DHCPGlobalConfig, VboxError};
+
+impl_dhcp_config!(DHCPGlobalConfig, "get_global_config()");
@@ -0,0 +1,39 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IDHCPGlobalConfig;
+
+/// The global configuration of a DHCP server, applying to all clients.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_global_config.html](https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_global_config.html)
+#[derive(Debug)]
+pub struct DHCPGlobalConfig {
+    object: *mut IDHCPGlobalConfig,
+}
+
+impl DHCPGlobalConfig {
+    pub(crate) fn new(object: *mut IDHCPGlobalConfig) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for DHCPGlobalConfig {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("DHCPGlobalConfig refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop DHCPGlobalConfig. Error: {:?}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::enums::DHCPGroupConditionType;
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{DHCPGroupCondition, VboxError};
+
+impl DHCPGroupCondition {
+    /// Whether this is an inclusive or exclusive group membership condition.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// let inclusive = condition.get_inclusive().unwrap();
+    pub fn get_inclusive(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetInclusive)
+    }
+
+    /// Whether this is an inclusive or exclusive group membership condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `inclusive` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// condition.set_inclusive(false).unwrap();
+    pub fn set_inclusive(&self, inclusive: bool) -> Result<(), VboxError> {
+        let inclusive = if inclusive { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetInclusive, inclusive)
+    }
+
+    /// Defines how the [`DHCPGroupCondition::get_value`] is interpreted.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPGroupConditionType`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// let condition_type = condition.get_type().unwrap();
+    pub fn get_type(&self) -> Result<DHCPGroupConditionType, VboxError> {
+        let condition_type = get_function_result_number!(self.object, GetType, u32)?;
+        Ok(DHCPGroupConditionType::from(condition_type))
+    }
+
+    /// Defines how the [`DHCPGroupCondition::get_value`] is interpreted.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition_type` - [`DHCPGroupConditionType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::enums::DHCPGroupConditionType;
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// condition.set_type(DHCPGroupConditionType::MAC).unwrap();
+    pub fn set_type(&self, condition_type: DHCPGroupConditionType) -> Result<(), VboxError> {
+        let condition_type: u32 = condition_type.into();
+        get_function_result_unit!(self.object, SetType, condition_type)
+    }
+
+    /// The condition value.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// let value = condition.get_value().unwrap();
+    pub fn get_value(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetValue)
+    }
+
+    /// The condition value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// condition.set_value("08:00:27:12:34:56").unwrap();
+    pub fn set_value(&self, value: &str) -> Result<(), VboxError> {
+        let value = string_to_c_u64_str(value)?;
+        get_function_result_unit!(self.object, SetValue, value)
+    }
+
+    /// Removes this condition from the group.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", false).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    /// let condition = conditions.get(0).unwrap();
+    /// condition.remove().unwrap();
+    pub fn remove(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Remove)
+    }
+}
@@ -0,0 +1,39 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IDHCPGroupCondition;
+
+/// A condition selecting the members of a DHCP group configuration.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_group_condition.html](https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_group_condition.html)
+#[derive(Debug)]
+pub struct DHCPGroupCondition {
+    object: *mut IDHCPGroupCondition,
+}
+
+impl DHCPGroupCondition {
+    pub(crate) fn new(object: *mut IDHCPGroupCondition) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for DHCPGroupCondition {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("DHCPGroupCondition refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop DHCPGroupCondition. Error: {:?}", err)
+            }
+        }
+    }
+}
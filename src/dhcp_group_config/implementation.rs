@@ -0,0 +1,159 @@
+use crate::dhcp_config::impl_dhcp_config;
+use crate::enums::{DHCPConfigScope, DHCPGroupConditionType, DHCPOption, DHCPOptionEncoding};
+use crate::utility::c_u64_str_to_string;
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_str, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{DHCPGroupCondition, DHCPGroupConfig, VboxError};
+use vbox_raw::sys_lib::IDHCPGroupCondition;
+
+impl_dhcp_config!(DHCPGroupConfig, "get_group_config(\"pxe-clients\", true)");
+
+impl DHCPGroupConfig {
+    /// The group name.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    /// let name = config.get_name().unwrap();
+    pub fn get_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetName)
+    }
+
+    /// The group name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    /// config.set_name("pxe-clients").unwrap();
+    pub fn set_name(&self, name: &str) -> Result<(), VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        get_function_result_unit!(self.object, SetName, name)
+    }
+
+    /// Group membership conditions.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<DHCPGroupCondition>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    /// let conditions = config.get_conditions().unwrap();
+    pub fn get_conditions(&self) -> Result<Vec<DHCPGroupCondition>, VboxError> {
+        let conditions =
+            get_function_result_pointer_vec!(self.object, GetConditions, *mut IDHCPGroupCondition)?;
+        Ok(conditions
+            .into_iter()
+            .map(DHCPGroupCondition::new)
+            .collect())
+    }
+
+    /// Adds a new condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `inclusive` - bool. Whether the condition includes or excludes the matching clients.
+    /// * `condition_type` - [`DHCPGroupConditionType`]. The type of the condition.
+    /// * `value` - &str. The value to match.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPGroupCondition`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::enums::DHCPGroupConditionType;
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    /// let condition = config
+    ///     .add_condition(true, DHCPGroupConditionType::MACWildcard, "08:00:27:*")
+    ///     .unwrap();
+    pub fn add_condition(
+        &self,
+        inclusive: bool,
+        condition_type: DHCPGroupConditionType,
+        value: &str,
+    ) -> Result<DHCPGroupCondition, VboxError> {
+        let inclusive = if inclusive { 1 } else { 0 };
+        let condition_type: u32 = condition_type.into();
+        let value = string_to_c_u64_str(value)?;
+        let condition = get_function_result_pointer!(
+            self.object,
+            AddCondition,
+            *mut IDHCPGroupCondition,
+            inclusive,
+            condition_type,
+            value
+        )?;
+        Ok(DHCPGroupCondition::new(condition))
+    }
+
+    /// Removes all conditions.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    /// config.remove_all_conditions().unwrap();
+    pub fn remove_all_conditions(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, RemoveAllConditions)
+    }
+}
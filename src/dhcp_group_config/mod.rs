@@ -0,0 +1,39 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IDHCPGroupConfig;
+
+/// A configuration of a DHCP server applying to the group of clients matching its conditions.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_group_config.html](https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_group_config.html)
+#[derive(Debug)]
+pub struct DHCPGroupConfig {
+    object: *mut IDHCPGroupConfig,
+}
+
+impl DHCPGroupConfig {
+    pub(crate) fn new(object: *mut IDHCPGroupConfig) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for DHCPGroupConfig {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("DHCPGroupConfig refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop DHCPGroupConfig. Error: {:?}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::dhcp_config::impl_dhcp_config;
+use crate::enums::{DHCPConfigScope, DHCPOption, DHCPOptionEncoding};
+use crate::utility::c_u64_str_to_string;
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_str, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{DHCPIndividualConfig, VboxError};
+
+impl_dhcp_config!(
+    DHCPIndividualConfig,
+    "get_mac_config(\"08:00:27:12:34:56\", true)"
+);
+
+impl DHCPIndividualConfig {
+    /// The MAC address. Empty for configurations of the [`DHCPConfigScope::MachineNIC`] scope.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    /// let mac_address = config.get_mac_address().unwrap();
+    pub fn get_mac_address(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetMACAddress)
+    }
+
+    /// The virtual machine ID. Empty for configurations of the [`DHCPConfigScope::MAC`] scope.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    /// let machine_id = config.get_machine_id().unwrap();
+    pub fn get_machine_id(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetMachineId)
+    }
+
+    /// The virtual machine network adapter slot number. Only valid for configurations of the [`DHCPConfigScope::MachineNIC`] scope.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    /// let slot = config.get_slot().unwrap();
+    pub fn get_slot(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetSlot, u32)
+    }
+
+    /// Fixed IPv4 address assignment, dynamic assignment if empty.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    /// let fixed_address = config.get_fixed_address().unwrap();
+    pub fn get_fixed_address(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetFixedAddress)
+    }
+
+    /// Fixed IPv4 address assignment, dynamic assignment if empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixed_address` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    /// config.set_fixed_address("192.168.56.10").unwrap();
+    pub fn set_fixed_address(&self, fixed_address: &str) -> Result<(), VboxError> {
+        let fixed_address = string_to_c_u64_str(fixed_address)?;
+        get_function_result_unit!(self.object, SetFixedAddress, fixed_address)
+    }
+}
@@ -0,0 +1,39 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IDHCPIndividualConfig;
+
+/// A configuration of a DHCP server applying to a single client, selected by MAC address or by virtual machine network adapter slot.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_individual_config.html](https://www.virtualbox.org/sdkref/interface_i_d_h_c_p_individual_config.html)
+#[derive(Debug)]
+pub struct DHCPIndividualConfig {
+    object: *mut IDHCPIndividualConfig,
+}
+
+impl DHCPIndividualConfig {
+    pub(crate) fn new(object: *mut IDHCPIndividualConfig) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for DHCPIndividualConfig {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("DHCPIndividualConfig refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop DHCPIndividualConfig. Error: {:?}", err)
+            }
+        }
+    }
+}
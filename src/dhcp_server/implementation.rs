@@ -0,0 +1,608 @@
+use crate::enums::DHCPConfigScope;
+use crate::event_detail::utility::create_ns_id_from_str;
+use crate::utility::c_u64_str_to_string;
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_str, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{
+    DHCPGlobalConfig, DHCPGroupConfig, DHCPIndividualConfig, DHCPServer, EventSource, VboxError,
+};
+use log::error;
+use vbox_raw::sys_lib::{
+    nsID, IDHCPConfig, IDHCPGlobalConfig, IDHCPGroupConfig, IDHCPIndividualConfig, IEventSource,
+    IDHCPGROUPCONFIG_IID_STR, IDHCPINDIVIDUALCONFIG_IID_STR,
+};
+
+impl DHCPServer {
+    /// Event source for DHCP server events.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`EventSource`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let event_source = dhcp_server.get_event_source().unwrap();
+    pub fn get_event_source(&self) -> Result<EventSource, VboxError> {
+        let event_source =
+            get_function_result_pointer!(self.object, GetEventSource, *mut IEventSource)?;
+        Ok(EventSource::new(event_source))
+    }
+
+    /// Specifies if the DHCP server is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let enabled = dhcp_server.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Specifies if the DHCP server is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// dhcp_server.set_enabled(true).unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// IP address of the DHCP server.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let ip_address = dhcp_server.get_ip_address().unwrap();
+    pub fn get_ip_address(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetIPAddress)
+    }
+
+    /// Network mask of the DHCP server.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let network_mask = dhcp_server.get_network_mask().unwrap();
+    pub fn get_network_mask(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetworkMask)
+    }
+
+    /// Name of the internal network the DHCP server is running on.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let network_name = dhcp_server.get_network_name().unwrap();
+    pub fn get_network_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetworkName)
+    }
+
+    /// Lower IP address bound of the DHCP lease range.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let lower_ip = dhcp_server.get_lower_ip().unwrap();
+    pub fn get_lower_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLowerIP)
+    }
+
+    /// Upper IP address bound of the DHCP lease range.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let upper_ip = dhcp_server.get_upper_ip().unwrap();
+    pub fn get_upper_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUpperIP)
+    }
+
+    /// Global configuration that applies to all clients.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPGlobalConfig`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let global_config = dhcp_server.get_global_config().unwrap();
+    pub fn get_global_config(&self) -> Result<DHCPGlobalConfig, VboxError> {
+        let global_config =
+            get_function_result_pointer!(self.object, GetGlobalConfig, *mut IDHCPGlobalConfig)?;
+        Ok(DHCPGlobalConfig::new(global_config))
+    }
+
+    /// Configuration groups that apply to selected clients, the selection criteria are part of the group config.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<DHCPGroupConfig>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let group_configs = dhcp_server.get_group_configs().unwrap();
+    pub fn get_group_configs(&self) -> Result<Vec<DHCPGroupConfig>, VboxError> {
+        let group_configs =
+            get_function_result_pointer_vec!(self.object, GetGroupConfigs, *mut IDHCPGroupConfig)?;
+        Ok(group_configs
+            .into_iter()
+            .map(DHCPGroupConfig::new)
+            .collect())
+    }
+
+    /// Individual NIC configurations either by MAC address or VM + NIC number.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<DHCPIndividualConfig>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let individual_configs = dhcp_server.get_individual_configs().unwrap();
+    pub fn get_individual_configs(&self) -> Result<Vec<DHCPIndividualConfig>, VboxError> {
+        let individual_configs = get_function_result_pointer_vec!(
+            self.object,
+            GetIndividualConfigs,
+            *mut IDHCPIndividualConfig
+        )?;
+        Ok(individual_configs
+            .into_iter()
+            .map(DHCPIndividualConfig::new)
+            .collect())
+    }
+
+    /// Configures the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip_address` - &str. Server IP address.
+    /// * `network_mask` - &str. Server network mask.
+    /// * `from_ip_address` - &str. Server From IP address for address range.
+    /// * `to_ip_address` - &str. Server To IP address for address range.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// dhcp_server
+    ///     .set_configuration("192.168.56.2", "255.255.255.0", "192.168.56.100", "192.168.56.199")
+    ///     .unwrap();
+    pub fn set_configuration(
+        &self,
+        ip_address: &str,
+        network_mask: &str,
+        from_ip_address: &str,
+        to_ip_address: &str,
+    ) -> Result<(), VboxError> {
+        let ip_address = string_to_c_u64_str(ip_address)?;
+        let network_mask = string_to_c_u64_str(network_mask)?;
+        let from_ip_address = string_to_c_u64_str(from_ip_address)?;
+        let to_ip_address = string_to_c_u64_str(to_ip_address)?;
+        get_function_result_unit!(
+            self.object,
+            SetConfiguration,
+            ip_address,
+            network_mask,
+            from_ip_address,
+            to_ip_address
+        )
+    }
+
+    /// Starts DHCP server process.
+    ///
+    /// # Arguments
+    ///
+    /// * `trunk_name` - &str. Name of internal network trunk.
+    /// * `trunk_type` - &str. Type of internal network trunk.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// dhcp_server.start("vboxnet0", "netflt").unwrap();
+    pub fn start(&self, trunk_name: &str, trunk_type: &str) -> Result<(), VboxError> {
+        let trunk_name = string_to_c_u64_str(trunk_name)?;
+        let trunk_type = string_to_c_u64_str(trunk_type)?;
+        get_function_result_unit!(self.object, Start, trunk_name, trunk_type)
+    }
+
+    /// Stops DHCP server process.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// dhcp_server.stop().unwrap();
+    pub fn stop(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Stop)
+    }
+
+    /// Restart running DHCP server process.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// dhcp_server.restart().unwrap();
+    pub fn restart(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Restart)
+    }
+
+    /// Queries the persistent lease database by MAC address.
+    ///
+    /// This is handy if the host wants to connect to a server running inside a VM on a host only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `mac` - &str. The MAC address to look up.
+    ///
+    /// # Returns
+    ///
+    /// Returns (address, state, issued, expire), or a [`VboxError`] on failure.
+    /// - address: &str. The assigned address.
+    /// - state: &str. The lease state.
+    /// - issued: i64. Timestamp of when the lease was issued, in seconds since 1970-01-01 UTC.
+    /// - expire: i64. Timestamp of when the lease expires/expired, in seconds since 1970-01-01 UTC.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let (address, state, issued, expire) =
+    ///     dhcp_server.find_lease_by_mac("08:00:27:12:34:56").unwrap();
+    pub fn find_lease_by_mac(
+        &self,
+        mac: &str,
+    ) -> Result<(&'static str, &'static str, i64, i64), VboxError> {
+        let mac = string_to_c_u64_str(mac)?;
+        let mut address: *mut u16 = std::ptr::null_mut();
+        let mut state: *mut u16 = std::ptr::null_mut();
+        let mut issued = 0;
+        let mut expire = 0;
+        get_function_result_unit!(
+            self.object,
+            FindLeaseByMAC,
+            mac,
+            0,
+            &mut address,
+            &mut state,
+            &mut issued,
+            &mut expire
+        )?;
+        let address = c_u64_str_to_string(address)?;
+        let state = c_u64_str_to_string(state)?;
+        Ok((address, state, issued, expire))
+    }
+
+    /// Gets or adds a group configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The group name.
+    /// * `may_add` - bool. Whether to add the configuration if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPGroupConfig`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_group_config("pxe-clients", true).unwrap();
+    pub fn get_group_config(
+        &self,
+        name: &str,
+        may_add: bool,
+    ) -> Result<DHCPGroupConfig, VboxError> {
+        let config: *mut IDHCPGroupConfig = self.get_config(
+            DHCPConfigScope::Group,
+            name,
+            0,
+            may_add,
+            IDHCPGROUPCONFIG_IID_STR,
+        )?;
+        Ok(DHCPGroupConfig::new(config))
+    }
+
+    /// Gets or adds the individual configuration of a client with the given MAC address.
+    ///
+    /// # Arguments
+    ///
+    /// * `mac_address` - &str. The MAC address of the client.
+    /// * `may_add` - bool. Whether to add the configuration if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPIndividualConfig`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_mac_config("08:00:27:12:34:56", true).unwrap();
+    pub fn get_mac_config(
+        &self,
+        mac_address: &str,
+        may_add: bool,
+    ) -> Result<DHCPIndividualConfig, VboxError> {
+        let config: *mut IDHCPIndividualConfig = self.get_config(
+            DHCPConfigScope::MAC,
+            mac_address,
+            0,
+            may_add,
+            IDHCPINDIVIDUALCONFIG_IID_STR,
+        )?;
+        Ok(DHCPIndividualConfig::new(config))
+    }
+
+    /// Gets or adds the individual configuration of a virtual machine network adapter slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `machine` - &str. The name or UUID of the virtual machine.
+    /// * `slot` - u32. The network adapter slot.
+    /// * `may_add` - bool. Whether to add the configuration if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DHCPIndividualConfig`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let dhcp_server = vbox
+    ///     .find_dhcp_server_by_network_name("HostInterfaceNetworking-vboxnet0")
+    ///     .unwrap();
+    /// let config = dhcp_server.get_machine_nic_config("Freebsd_14", 0, true).unwrap();
+    pub fn get_machine_nic_config(
+        &self,
+        machine: &str,
+        slot: u32,
+        may_add: bool,
+    ) -> Result<DHCPIndividualConfig, VboxError> {
+        let config: *mut IDHCPIndividualConfig = self.get_config(
+            DHCPConfigScope::MachineNIC,
+            machine,
+            slot,
+            may_add,
+            IDHCPINDIVIDUALCONFIG_IID_STR,
+        )?;
+        Ok(DHCPIndividualConfig::new(config))
+    }
+
+    /// Gets or adds a configuration.
+    ///
+    /// `GetConfig` declares an `IDHCPConfig` result, which over XPCOM is a proxy implementing only
+    /// that interface. The interface derived from it, `iid_str`, is queried and the `IDHCPConfig`
+    /// reference is released.
+    fn get_config<T>(
+        &self,
+        scope: DHCPConfigScope,
+        name: &str,
+        slot: u32,
+        may_add: bool,
+        iid_str: &[u8; 37],
+    ) -> Result<*mut T, VboxError> {
+        let scope: u32 = scope.into();
+        let name = string_to_c_u64_str(name)?;
+        let may_add = if may_add { 1 } else { 0 };
+        let config = get_function_result_pointer!(
+            self.object,
+            GetConfig,
+            *mut IDHCPConfig,
+            scope,
+            name,
+            slot,
+            may_add
+        )?;
+        let lp_vtbl = unsafe { (*config).lpVtbl };
+        let result = match unsafe { (*lp_vtbl).QueryInterface } {
+            Some(query_fn) => {
+                let mut object: *mut std::os::raw::c_void = std::ptr::null_mut();
+                let iid = create_ns_id_from_str(iid_str);
+                let result_code = unsafe { query_fn(config, &iid as *const nsID, &mut object) };
+                if result_code != 0 {
+                    Err(VboxError::new(
+                        result_code,
+                        "QueryInterface",
+                        "".to_string(),
+                        None,
+                    ))
+                } else if object.is_null() {
+                    Err(VboxError::null_pointer_error("QueryInterface"))
+                } else {
+                    Ok(object.cast())
+                }
+            }
+            None => Err(VboxError::get_fn_error("QueryInterface")),
+        };
+        match unsafe { (*lp_vtbl).Release } {
+            Some(release_fn) => {
+                unsafe { release_fn(config) };
+            }
+            None => error!("Failed release IDHCPConfig. Release is missing"),
+        }
+        result
+    }
+}
@@ -1,3 +1,5 @@
+mod implementation;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
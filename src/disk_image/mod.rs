@@ -0,0 +1,558 @@
+mod qcow2;
+mod vdi;
+mod vhd;
+mod vhdx;
+mod vmdk;
+
+use crate::raw_disk_vmdk::partition_table::format_guid;
+use crate::VboxError;
+use log::debug;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Largest number of table entries read at once while counting allocated blocks.
+const TABLE_CHUNK_ENTRIES: u64 = 64 * 1024;
+
+/// File extensions searched by [`DiskImage::chain`] when a parent has to be found by its UUID.
+const IMAGE_EXTENSIONS: [&str; 5] = ["vdi", "vmdk", "vhd", "vhdx", "qcow2"];
+
+/// Container format of a [`DiskImage`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DiskImageFormat {
+    /// VirtualBox Disk Image.
+    Vdi,
+    /// VMware Virtual Machine Disk, either a sparse extent or a text descriptor.
+    Vmdk,
+    /// Virtual PC and Hyper-V Virtual Hard Disk.
+    Vhd,
+    /// Hyper-V Virtual Hard Disk v2.
+    Vhdx,
+    /// QEMU Copy-On-Write v2 and v3.
+    Qcow2,
+}
+
+impl DiskImageFormat {
+    /// Identifier of the format as used by [`crate::MediumFormat::get_id`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiskImageFormat::Vdi => "VDI",
+            DiskImageFormat::Vmdk => "VMDK",
+            DiskImageFormat::Vhd => "VHD",
+            DiskImageFormat::Vhdx => "VHDX",
+            DiskImageFormat::Qcow2 => "QCOW2",
+        }
+    }
+}
+
+/// Allocation type of a [`DiskImage`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DiskImageVariant {
+    /// All data is preallocated.
+    Fixed,
+    /// Blocks are allocated on first write.
+    Dynamic,
+    /// Only the changes to a parent image are stored.
+    Differencing,
+}
+
+/// Header information of a disk image file, read without VirtualBox.
+///
+/// [`DiskImage::inspect`] parses the headers of VDI, VMDK, VHD, VHDX and QCOW2 images in pure
+/// Rust, so images can be validated and differencing chains rebuilt on machines without
+/// VirtualBox, for example on a backup server. The image data itself is never read, only the block
+/// allocation tables are, to count the allocated blocks.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::DiskImage;
+///
+/// let image = DiskImage::inspect("/home/user/VirtualBox VMs/Ubuntu/Ubuntu.vdi").unwrap();
+/// println!(
+///     "{} {:?} {:?} {} bytes, {:?} of {:?} blocks allocated",
+///     image.format.as_str(),
+///     image.variant,
+///     image.uuid,
+///     image.logical_size,
+///     image.allocated_blocks,
+///     image.total_blocks
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskImage {
+    pub path: String,
+    pub format: DiskImageFormat,
+    pub variant: DiskImageVariant,
+    /// `None` if the format has no image UUID, like QCOW2.
+    pub uuid: Option<String>,
+    /// VDI modification UUID, VMDK `ddb.uuid.modification` or VHDX data write GUID.
+    pub modification_uuid: Option<String>,
+    /// UUID of the parent of a differencing image. For VHDX, this is the data write GUID of the
+    /// parent (`parent_linkage`), which is compared with its [`DiskImage::modification_uuid`].
+    pub parent_uuid: Option<String>,
+    /// Parent locations stored in the image, most reliable first. They may be relative to the
+    /// directory of the image and may use Windows path separators.
+    pub parent_locations: Vec<String>,
+    /// Size of the disk as the guest sees it, in bytes.
+    pub logical_size: u64,
+    /// Allocation unit in bytes. `None` for fixed images without an allocation table.
+    pub block_size: Option<u64>,
+    pub total_blocks: Option<u64>,
+    /// Blocks present in the image. `None` if the image has no allocation table to count.
+    pub allocated_blocks: Option<u64>,
+}
+
+impl DiskImage {
+    /// Reads the headers of the image at `path`.
+    ///
+    /// The format is detected from the content of the file, not from its extension. For a VMDK
+    /// descriptor, the sparse extents it references are read too.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Path to the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DiskImage`] on success, or a [`VboxError`] if the file cannot be read, has an
+    /// unknown format or a damaged header.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{DiskImage, DiskImageVariant};
+    ///
+    /// let image = DiskImage::inspect("/backup/Ubuntu/disk.vhdx").unwrap();
+    /// if image.variant == DiskImageVariant::Differencing {
+    ///     println!("parent: {:?} {:?}", image.parent_uuid, image.parent_locations);
+    /// }
+    /// ```
+    pub fn inspect(path: &str) -> Result<DiskImage, VboxError> {
+        let mut file =
+            File::open(path).map_err(|err| VboxError::io_error("DiskImage::inspect", err))?;
+        let file_size = file
+            .metadata()
+            .map_err(|err| VboxError::io_error("DiskImage::inspect", err))?
+            .len();
+        let dir = Path::new(path).parent();
+        let mut image = parse(&mut file, file_size, dir).map_err(|mut err| {
+            err.msg = format!("{}: {}", path, err.msg);
+            err
+        })?;
+        image.path = path.to_string();
+        debug!("DiskImage::inspect: {:?}", image);
+        Ok(image)
+    }
+
+    /// Follows the differencing chain of the image at `path` down to its base image.
+    ///
+    /// A parent is looked up at the locations stored in the child first, then by file name next to
+    /// the child, and finally by UUID among the images in the directory of the child and the
+    /// directory above it, where VirtualBox keeps the base of the images in `Snapshots`. A parent
+    /// whose UUID does not match the link stored in the child is not accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Path to the image file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the images from `path` to the base image on success, or a [`VboxError`] if an
+    /// image cannot be read, a parent is missing or the chain contains a loop.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::DiskImage;
+    ///
+    /// let chain = DiskImage::chain(
+    ///     "/backup/Ubuntu/Snapshots/{6d7b2d4b-1c8e-4a4c-9d6b-5e1f0b7c2a11}.vdi",
+    /// ).unwrap();
+    /// for image in chain.iter().rev() {
+    ///     println!("{} {:?}", image.path, image.uuid);
+    /// }
+    /// ```
+    pub fn chain(path: &str) -> Result<Vec<DiskImage>, VboxError> {
+        let mut chain = vec![DiskImage::inspect(path)?];
+        let mut visited = HashSet::new();
+        visited.insert(canonical(path));
+        loop {
+            let child = &chain[chain.len() - 1];
+            if child.variant != DiskImageVariant::Differencing {
+                return Ok(chain);
+            }
+            let parent = child.find_parent()?;
+            if !visited.insert(canonical(&parent.path)) {
+                return Err(VboxError::invalid_data(
+                    "DiskImage::chain",
+                    format!("{}: the differencing chain contains a loop", parent.path),
+                ));
+            }
+            chain.push(parent);
+        }
+    }
+
+    /// Whether this image is the parent the differencing image `child` links to.
+    fn is_parent_of(&self, child: &DiskImage) -> bool {
+        let id = match child.format {
+            DiskImageFormat::Vhdx => &self.modification_uuid,
+            _ => &self.uuid,
+        };
+        match (id, &child.parent_uuid) {
+            (Some(id), Some(parent_uuid)) => id.eq_ignore_ascii_case(parent_uuid),
+            _ => true,
+        }
+    }
+
+    fn find_parent(&self) -> Result<DiskImage, VboxError> {
+        let dir = match Path::new(&self.path).parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        for candidate in parent_candidates(&dir, &self.parent_locations) {
+            if !candidate.is_file() {
+                continue;
+            }
+            let image = DiskImage::inspect(&candidate.to_string_lossy())?;
+            if image.is_parent_of(self) {
+                return Ok(image);
+            }
+            debug!(
+                "DiskImage::chain: {} is not the parent of {}",
+                image.path, self.path
+            );
+        }
+        if self.parent_uuid.is_some() {
+            // VirtualBox keeps differencing images in `<VM>/Snapshots` and the base in `<VM>`.
+            let mut search_dirs = vec![dir.clone()];
+            if let Some(up) = canonical(&dir.to_string_lossy()).parent() {
+                search_dirs.push(up.to_path_buf());
+            }
+            for search_dir in search_dirs {
+                if let Some(image) = self.scan_for_parent(&search_dir)? {
+                    return Ok(image);
+                }
+            }
+        }
+        Err(VboxError::invalid_data(
+            "DiskImage::chain",
+            format!(
+                "{}: parent {:?} not found at {:?}",
+                self.path, self.parent_uuid, self.parent_locations
+            ),
+        ))
+    }
+
+    /// Finds the parent by UUID among the images in `dir`. Unreadable images are skipped.
+    fn scan_for_parent(&self, dir: &Path) -> Result<Option<DiskImage>, VboxError> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                debug!("DiskImage::chain: skipping {:?}: {}", dir, err);
+                return Ok(None);
+            }
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_image = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map(|extension| IMAGE_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+                .unwrap_or(false);
+            if !is_image || canonical(&path.to_string_lossy()) == canonical(&self.path) {
+                continue;
+            }
+            match DiskImage::inspect(&path.to_string_lossy()) {
+                Ok(image) if image.uuid.is_some() && image.is_parent_of(self) => {
+                    return Ok(Some(image))
+                }
+                Ok(_) => {}
+                Err(err) => debug!("DiskImage::chain: skipping {:?}: {}", path, err.msg),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Paths to try for a parent: each stored location, with Windows separators converted, then its
+/// file name next to the child.
+fn parent_candidates(dir: &Path, locations: &[String]) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    let mut push = |path: PathBuf| {
+        if !candidates.contains(&path) {
+            candidates.push(path);
+        }
+    };
+    for location in locations {
+        let location = location.strip_prefix("file://").unwrap_or(location);
+        let location = location.strip_prefix("localhost").unwrap_or(location);
+        for location in [location.to_string(), location.replace('\\', "/")] {
+            push(dir.join(location.strip_prefix("./").unwrap_or(&location)));
+        }
+    }
+    for location in locations {
+        let location = location.replace('\\', "/");
+        if let Some(name) = Path::new(&location).file_name() {
+            push(dir.join(name));
+        }
+    }
+    candidates
+}
+
+fn canonical(path: &str) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path))
+}
+
+/// Detects the format and parses the headers. `dir` is used to open the extents of VMDK descriptors.
+fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    dir: Option<&Path>,
+) -> Result<DiskImage, VboxError> {
+    let head = read_at(reader, 0, file_size.min(512) as usize)?;
+    if head.starts_with(vhdx::SIGNATURE) {
+        vhdx::parse(reader, file_size)
+    } else if head.starts_with(qcow2::MAGIC) {
+        qcow2::parse(reader, file_size)
+    } else if head.starts_with(vmdk::SPARSE_MAGIC) || head.starts_with(vmdk::DESCRIPTOR_SIGNATURE) {
+        vmdk::parse(reader, file_size, dir)
+    } else if head.len() >= 0x48 && le_u32(&head, 0x40) == vdi::SIGNATURE {
+        vdi::parse(reader, file_size)
+    } else if head.starts_with(vhd::COOKIE)
+        || (file_size >= 512 && read_at(reader, file_size - 512, 8)? == vhd::COOKIE)
+    {
+        vhd::parse(reader, file_size)
+    } else {
+        Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            "unknown disk image format".to_string(),
+        ))
+    }
+}
+
+fn read_at<R: Read + Seek>(reader: &mut R, offset: u64, len: usize) -> Result<Vec<u8>, VboxError> {
+    let mut buf = vec![0u8; len];
+    reader
+        .seek(SeekFrom::Start(offset))
+        .map_err(|err| VboxError::io_error("DiskImage::inspect", err))?;
+    reader
+        .read_exact(&mut buf)
+        .map_err(|err| VboxError::io_error("DiskImage::inspect", err))?;
+    Ok(buf)
+}
+
+/// Counts the entries of a table which `is_allocated` accepts, checking that the table lies
+/// within the file.
+fn count_entries<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    offset: u64,
+    count: u64,
+    entry_size: u64,
+    mut is_allocated: impl FnMut(&[u8]) -> bool,
+) -> Result<u64, VboxError> {
+    check_range(file_size, offset, count.saturating_mul(entry_size), "table")?;
+    let mut allocated = 0;
+    let mut index = 0;
+    while index < count {
+        let entries = (count - index).min(TABLE_CHUNK_ENTRIES);
+        let chunk = read_at(
+            reader,
+            offset + index * entry_size,
+            (entries * entry_size) as usize,
+        )?;
+        allocated += chunk
+            .chunks_exact(entry_size as usize)
+            .filter(|entry| is_allocated(entry))
+            .count() as u64;
+        index += entries;
+    }
+    Ok(allocated)
+}
+
+fn check_range(file_size: u64, offset: u64, len: u64, what: &str) -> Result<(), VboxError> {
+    match offset.checked_add(len) {
+        Some(end) if end <= file_size => Ok(()),
+        _ => Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            format!(
+                "{} at {} with {} bytes is beyond the end of the file ({} bytes)",
+                what, offset, len, file_size
+            ),
+        )),
+    }
+}
+
+/// Formats a UUID stored in the RFC 4122 byte order. The nil UUID is `None`.
+fn format_uuid(bytes: &[u8]) -> Option<String> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return None;
+    }
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// Formats a GUID stored in the Windows byte order. The nil GUID is `None`.
+fn format_le_uuid(bytes: &[u8]) -> Option<String> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return None;
+    }
+    Some(format_guid(bytes))
+}
+
+/// Normalizes a textual UUID, optionally in braces. The nil UUID is `None`.
+fn parse_uuid(text: &str) -> Option<String> {
+    let uuid = text
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .to_lowercase();
+    if uuid.is_empty() || uuid.chars().all(|c| c == '0' || c == '-') {
+        None
+    } else {
+        Some(uuid)
+    }
+}
+
+/// Decodes NUL terminated UTF-16 text.
+fn utf16_string(bytes: &[u8], big_endian: bool) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| {
+            if big_endian {
+                u16::from_be_bytes([unit[0], unit[1]])
+            } else {
+                u16::from_le_bytes([unit[0], unit[1]])
+            }
+        })
+        .take_while(|unit| *unit != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+fn le_u16(buf: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([buf[offset], buf[offset + 1]])
+}
+
+fn le_u32(buf: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buf[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn le_u64(buf: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+fn be_u32(buf: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buf[offset..offset + 4]);
+    u32::from_be_bytes(bytes)
+}
+
+fn be_u64(buf: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    u64::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("disk_image_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        let mut data = std::io::Cursor::new(vec![0u8; 4096]);
+        let err = parse(&mut data, 4096, None).unwrap_err();
+        assert_eq!(err.msg, "unknown disk image format");
+    }
+
+    #[test]
+    fn follows_chain_by_location_and_uuid() {
+        let dir = fixture_dir("chain");
+        let base = vdi::tests::image(1, 1, None);
+        std::fs::write(dir.join("base.vdi"), base).unwrap();
+        // VDI stores no parent location, the parent is found by UUID.
+        let middle = vdi::tests::image(4, 2, Some(1));
+        std::fs::write(dir.join("middle.vdi"), middle).unwrap();
+        std::fs::create_dir(dir.join("Snapshots")).unwrap();
+        let top = qcow2::tests::image(Some("..\\middle.vdi"));
+        let top_path = dir.join("Snapshots").join("top.qcow2");
+        std::fs::write(&top_path, top).unwrap();
+
+        let chain = DiskImage::chain(&top_path.to_string_lossy()).unwrap();
+        let formats: Vec<_> = chain.iter().map(|image| image.format).collect();
+        assert_eq!(
+            formats,
+            vec![
+                DiskImageFormat::Qcow2,
+                DiskImageFormat::Vdi,
+                DiskImageFormat::Vdi
+            ]
+        );
+        assert_eq!(chain[1].parent_uuid, chain[2].uuid);
+        assert!(chain[2].path.ends_with("base.vdi"));
+        assert_eq!(chain[2].variant, DiskImageVariant::Dynamic);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn finds_base_above_snapshots_directory() {
+        let dir = fixture_dir("snapshots");
+        std::fs::write(dir.join("Ubuntu.vdi"), vdi::tests::image(1, 1, None)).unwrap();
+        std::fs::create_dir(dir.join("Snapshots")).unwrap();
+        let diff_path = dir.join("Snapshots").join("{6d7b2d4b}.vdi");
+        std::fs::write(&diff_path, vdi::tests::image(4, 2, Some(1))).unwrap();
+
+        let chain = DiskImage::chain(&diff_path.to_string_lossy()).unwrap();
+        assert_eq!(chain.len(), 2);
+        assert!(chain[1].path.ends_with("Ubuntu.vdi"));
+        assert_eq!(chain[1].variant, DiskImageVariant::Dynamic);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_parent_fails() {
+        let dir = fixture_dir("missing");
+        let path = dir.join("diff.vdi");
+        std::fs::write(&path, vdi::tests::image(4, 2, Some(1))).unwrap();
+        let err = DiskImage::chain(&path.to_string_lossy()).unwrap_err();
+        assert!(err.msg.contains("not found"), "{}", err.msg);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parent_candidates_convert_windows_paths() {
+        let candidates = parent_candidates(
+            Path::new("/vms/Snapshots"),
+            &[".\\base.vhd".to_string(), "C:\\VMs\\base.vhd".to_string()],
+        );
+        assert!(candidates.contains(&PathBuf::from("/vms/Snapshots/base.vhd")));
+        let next_to_child = PathBuf::from("/vms/Snapshots/base.vhd");
+        assert_eq!(
+            candidates
+                .iter()
+                .filter(|path| **path == next_to_child)
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use super::{be_u32, be_u64, read_at, DiskImage, DiskImageFormat, DiskImageVariant};
+use crate::VboxError;
+use std::io::{Read, Seek};
+
+pub(super) const MAGIC: &[u8; 4] = b"QFI\xfb";
+const HEADER_SIZE: usize = 104;
+/// Host cluster offset bits of L1 and standard L2 entries.
+const OFFSET_MASK: u64 = 0x00FF_FFFF_FFFF_FE00;
+const COMPRESSED_FLAG: u64 = 1 << 62;
+/// Incompatible feature bit of images with 128-bit L2 entries carrying subcluster bitmaps.
+const EXTENDED_L2_FEATURE: u64 = 1 << 4;
+const MIN_CLUSTER_BITS: u32 = 9;
+const MAX_CLUSTER_BITS: u32 = 21;
+const MAX_BACKING_FILE_SIZE: u32 = 1023;
+
+pub(super) fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+) -> Result<DiskImage, VboxError> {
+    super::check_range(file_size, 0, 72, "QCOW2 header")?;
+    let header = read_at(reader, 0, HEADER_SIZE.min(file_size as usize))?;
+    let version = be_u32(&header, 4);
+    if !(2..=3).contains(&version) {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            format!("unsupported QCOW2 version {}", version),
+        ));
+    }
+    let cluster_bits = be_u32(&header, 20);
+    if !(MIN_CLUSTER_BITS..=MAX_CLUSTER_BITS).contains(&cluster_bits) {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            format!("invalid QCOW2 cluster bits {}", cluster_bits),
+        ));
+    }
+    let extended_l2 =
+        version == 3 && header.len() >= 80 && be_u64(&header, 72) & EXTENDED_L2_FEATURE != 0;
+    let cluster_size = 1u64 << cluster_bits;
+    let logical_size = be_u64(&header, 24);
+    let total_blocks = logical_size.div_ceil(cluster_size);
+
+    let backing_file_offset = be_u64(&header, 8);
+    let backing_file_size = be_u32(&header, 16);
+    let mut parent_locations = Vec::new();
+    if backing_file_offset != 0 && backing_file_size != 0 {
+        if backing_file_size > MAX_BACKING_FILE_SIZE {
+            return Err(VboxError::invalid_data(
+                "DiskImage::inspect",
+                format!("QCOW2 backing file name of {} bytes", backing_file_size),
+            ));
+        }
+        super::check_range(
+            file_size,
+            backing_file_offset,
+            backing_file_size as u64,
+            "QCOW2 backing file name",
+        )?;
+        let name = read_at(reader, backing_file_offset, backing_file_size as usize)?;
+        parent_locations.push(String::from_utf8_lossy(&name).to_string());
+    }
+
+    let l1_size = be_u32(&header, 36) as u64;
+    let l1_offset = be_u64(&header, 40);
+    super::check_range(file_size, l1_offset, l1_size * 8, "QCOW2 L1 table")?;
+    let l1_table = read_at(reader, l1_offset, (l1_size * 8) as usize)?;
+    let l2_entry_size = if extended_l2 { 16 } else { 8 };
+    let l2_entries = cluster_size / l2_entry_size;
+    let mut allocated_blocks = 0;
+    for (index, entry) in l1_table.chunks_exact(8).enumerate() {
+        let l2_offset = be_u64(entry, 0) & OFFSET_MASK;
+        let first_block = index as u64 * l2_entries;
+        if l2_offset == 0 || first_block >= total_blocks {
+            continue;
+        }
+        allocated_blocks += super::count_entries(
+            reader,
+            file_size,
+            l2_offset,
+            l2_entries.min(total_blocks - first_block),
+            l2_entry_size,
+            |entry| {
+                let entry = be_u64(entry, 0);
+                entry & COMPRESSED_FLAG != 0 || entry & OFFSET_MASK != 0
+            },
+        )?;
+    }
+
+    Ok(DiskImage {
+        path: String::new(),
+        format: DiskImageFormat::Qcow2,
+        variant: if parent_locations.is_empty() {
+            DiskImageVariant::Dynamic
+        } else {
+            DiskImageVariant::Differencing
+        },
+        uuid: None,
+        modification_uuid: None,
+        parent_uuid: None,
+        parent_locations,
+        logical_size,
+        block_size: Some(cluster_size),
+        total_blocks: Some(total_blocks),
+        allocated_blocks: Some(allocated_blocks),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// A version 3 image with 512 byte clusters and 1 MiB of logical size: header in cluster 0,
+    /// L1 table in cluster 1, one L2 table in cluster 2 with two allocated clusters.
+    pub(crate) fn image(backing_file: Option<&str>) -> Vec<u8> {
+        let mut data = vec![0u8; 512 * 5];
+        data[..4].copy_from_slice(MAGIC);
+        data[4..8].copy_from_slice(&3u32.to_be_bytes());
+        if let Some(backing_file) = backing_file {
+            data[8..16].copy_from_slice(&200u64.to_be_bytes());
+            data[16..20].copy_from_slice(&(backing_file.len() as u32).to_be_bytes());
+            data[200..200 + backing_file.len()].copy_from_slice(backing_file.as_bytes());
+        }
+        data[20..24].copy_from_slice(&9u32.to_be_bytes());
+        data[24..32].copy_from_slice(&(1u64 << 20).to_be_bytes());
+        // 2048 clusters need 32 L2 tables of 64 entries.
+        data[36..40].copy_from_slice(&32u32.to_be_bytes());
+        data[40..48].copy_from_slice(&512u64.to_be_bytes());
+        data[100..104].copy_from_slice(&(HEADER_SIZE as u32).to_be_bytes());
+        data[512..520].copy_from_slice(&((1u64 << 63) | 1024).to_be_bytes());
+        data[1024..1032].copy_from_slice(&((1u64 << 63) | 1536).to_be_bytes());
+        data[1032..1040].copy_from_slice(&(COMPRESSED_FLAG | 2048).to_be_bytes());
+        // Zero cluster without an allocation.
+        data[1040..1048].copy_from_slice(&1u64.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn parses_image_with_backing_file() {
+        let data = image(Some("base.qcow2"));
+        let size = data.len() as u64;
+        let image = parse(&mut Cursor::new(data), size).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Differencing);
+        assert_eq!(image.parent_locations, vec!["base.qcow2"]);
+        assert_eq!(image.logical_size, 1 << 20);
+        assert_eq!(image.block_size, Some(512));
+        assert_eq!(image.total_blocks, Some(2048));
+        assert_eq!(image.allocated_blocks, Some(2));
+    }
+
+    #[test]
+    fn rejects_invalid_cluster_size() {
+        let mut data = image(None);
+        data[20..24].copy_from_slice(&30u32.to_be_bytes());
+        let size = data.len() as u64;
+        assert!(parse(&mut Cursor::new(data), size).is_err());
+    }
+}
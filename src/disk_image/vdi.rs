@@ -0,0 +1,122 @@
+use super::{
+    format_le_uuid, le_u32, le_u64, read_at, DiskImage, DiskImageFormat, DiskImageVariant,
+};
+use crate::VboxError;
+use std::io::{Read, Seek};
+
+pub(super) const SIGNATURE: u32 = 0xBEDA_107F;
+
+/// Size of the pre-header and the version 1 header up to the LCHS geometry.
+const HEADER_SIZE: usize = 0x1C8;
+const TYPE_FIXED: u32 = 2;
+const TYPE_DIFF: u32 = 4;
+/// Block map entry of a block which was never written.
+const BLOCK_FREE: u32 = u32::MAX;
+/// Block map entry of a block which was discarded and reads as zeros.
+const BLOCK_ZERO: u32 = u32::MAX - 1;
+
+pub(super) fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+) -> Result<DiskImage, VboxError> {
+    let header = read_at(reader, 0, HEADER_SIZE.min(file_size as usize))?;
+    if header.len() < HEADER_SIZE {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            "truncated VDI header".to_string(),
+        ));
+    }
+    let version = le_u32(&header, 0x44);
+    if version >> 16 != 1 {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            format!("unsupported VDI version {:#x}", version),
+        ));
+    }
+    let variant = match le_u32(&header, 0x4C) {
+        TYPE_FIXED => DiskImageVariant::Fixed,
+        TYPE_DIFF => DiskImageVariant::Differencing,
+        _ => DiskImageVariant::Dynamic,
+    };
+    let blocks_offset = le_u32(&header, 0x154) as u64;
+    let block_size = le_u32(&header, 0x178) as u64;
+    let total_blocks = le_u32(&header, 0x180) as u64;
+    let allocated_blocks =
+        super::count_entries(reader, file_size, blocks_offset, total_blocks, 4, |entry| {
+            !matches!(le_u32(entry, 0), BLOCK_FREE | BLOCK_ZERO)
+        })?;
+    Ok(DiskImage {
+        path: String::new(),
+        format: DiskImageFormat::Vdi,
+        variant,
+        uuid: format_le_uuid(&header[0x188..0x198]),
+        modification_uuid: format_le_uuid(&header[0x198..0x1A8]),
+        parent_uuid: format_le_uuid(&header[0x1A8..0x1B8]),
+        parent_locations: Vec::new(),
+        logical_size: le_u64(&header, 0x170),
+        block_size: Some(block_size),
+        total_blocks: Some(total_blocks),
+        allocated_blocks: Some(allocated_blocks),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// An image of four 1 MiB blocks with blocks 0 and 2 allocated. UUIDs are filled with `uuid`
+    /// and `parent` bytes.
+    pub(crate) fn image(image_type: u32, uuid: u8, parent: Option<u8>) -> Vec<u8> {
+        let mut data = vec![0u8; 0x200 + 16];
+        data[..40].copy_from_slice(b"<<< Oracle VM VirtualBox Disk Image >>>\n");
+        data[0x40..0x44].copy_from_slice(&SIGNATURE.to_le_bytes());
+        data[0x44..0x48].copy_from_slice(&0x0001_0001u32.to_le_bytes());
+        data[0x48..0x4C].copy_from_slice(&0x180u32.to_le_bytes());
+        data[0x4C..0x50].copy_from_slice(&image_type.to_le_bytes());
+        data[0x154..0x158].copy_from_slice(&0x200u32.to_le_bytes());
+        data[0x158..0x15C].copy_from_slice(&0x400u32.to_le_bytes());
+        data[0x170..0x178].copy_from_slice(&(4u64 << 20).to_le_bytes());
+        data[0x178..0x17C].copy_from_slice(&(1u32 << 20).to_le_bytes());
+        data[0x180..0x184].copy_from_slice(&4u32.to_le_bytes());
+        data[0x184..0x188].copy_from_slice(&2u32.to_le_bytes());
+        data[0x188..0x198].fill(uuid);
+        data[0x198..0x1A8].fill(uuid + 0x10);
+        if let Some(parent) = parent {
+            data[0x1A8..0x1B8].fill(parent);
+        }
+        for (index, entry) in [0, BLOCK_FREE, 1, BLOCK_ZERO].iter().enumerate() {
+            let offset = 0x200 + index * 4;
+            data[offset..offset + 4].copy_from_slice(&entry.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn parses_differencing_image() {
+        let data = image(TYPE_DIFF, 0x11, Some(0x22));
+        let size = data.len() as u64;
+        let image = parse(&mut Cursor::new(data), size).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Differencing);
+        assert_eq!(
+            image.uuid.as_deref(),
+            Some("11111111-1111-1111-1111-111111111111")
+        );
+        assert_eq!(
+            image.parent_uuid.as_deref(),
+            Some("22222222-2222-2222-2222-222222222222")
+        );
+        assert_eq!(image.logical_size, 4 << 20);
+        assert_eq!(image.block_size, Some(1 << 20));
+        assert_eq!(image.total_blocks, Some(4));
+        assert_eq!(image.allocated_blocks, Some(2));
+    }
+
+    #[test]
+    fn truncated_block_map_fails() {
+        let mut data = image(TYPE_FIXED, 1, None);
+        data.truncate(0x204);
+        let size = data.len() as u64;
+        assert!(parse(&mut Cursor::new(data), size).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use super::{
+    be_u32, be_u64, format_uuid, read_at, utf16_string, DiskImage, DiskImageFormat,
+    DiskImageVariant,
+};
+use crate::VboxError;
+use std::io::{Read, Seek};
+
+pub(super) const COOKIE: &[u8; 8] = b"conectix";
+const DYNAMIC_COOKIE: &[u8; 8] = b"cxsparse";
+const FOOTER_SIZE: u64 = 512;
+const DYNAMIC_HEADER_SIZE: usize = 1024;
+const TYPE_FIXED: u32 = 2;
+const TYPE_DYNAMIC: u32 = 3;
+const TYPE_DIFF: u32 = 4;
+const BAT_UNUSED: u32 = u32::MAX;
+
+/// Parent locator platform codes, in the order the locations are tried.
+const LOCATOR_CODES: [&[u8; 4]; 4] = [b"W2ru", b"W2ku", b"MacX", b"Mac "];
+
+pub(super) fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+) -> Result<DiskImage, VboxError> {
+    super::check_range(file_size, 0, FOOTER_SIZE, "VHD footer")?;
+    // Dynamic disks keep a copy of the footer at the start, which survives a truncated file.
+    let mut footer = read_at(reader, file_size - FOOTER_SIZE, FOOTER_SIZE as usize)?;
+    if !footer.starts_with(COOKIE) {
+        footer = read_at(reader, 0, FOOTER_SIZE as usize)?;
+    }
+    let logical_size = be_u64(&footer, 48);
+    let uuid = format_uuid(&footer[68..84]);
+    let variant = match be_u32(&footer, 60) {
+        TYPE_FIXED => DiskImageVariant::Fixed,
+        TYPE_DYNAMIC => DiskImageVariant::Dynamic,
+        TYPE_DIFF => DiskImageVariant::Differencing,
+        disk_type => {
+            return Err(VboxError::invalid_data(
+                "DiskImage::inspect",
+                format!("unsupported VHD disk type {}", disk_type),
+            ))
+        }
+    };
+    let mut image = DiskImage {
+        path: String::new(),
+        format: DiskImageFormat::Vhd,
+        variant,
+        uuid,
+        modification_uuid: None,
+        parent_uuid: None,
+        parent_locations: Vec::new(),
+        logical_size,
+        block_size: None,
+        total_blocks: None,
+        allocated_blocks: None,
+    };
+    if variant == DiskImageVariant::Fixed {
+        return Ok(image);
+    }
+
+    let header_offset = be_u64(&footer, 16);
+    super::check_range(
+        file_size,
+        header_offset,
+        DYNAMIC_HEADER_SIZE as u64,
+        "VHD dynamic disk header",
+    )?;
+    let header = read_at(reader, header_offset, DYNAMIC_HEADER_SIZE)?;
+    if !header.starts_with(DYNAMIC_COOKIE) {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            "missing VHD dynamic disk header".to_string(),
+        ));
+    }
+    let bat_offset = be_u64(&header, 16);
+    let bat_entries = be_u32(&header, 28) as u64;
+    let block_size = be_u32(&header, 32) as u64;
+    if block_size == 0 {
+        return Err(VboxError::invalid_data(
+            "DiskImage::inspect",
+            "VHD block size is 0".to_string(),
+        ));
+    }
+    let total_blocks = logical_size.div_ceil(block_size);
+    image.block_size = Some(block_size);
+    image.total_blocks = Some(total_blocks);
+    image.allocated_blocks = Some(super::count_entries(
+        reader,
+        file_size,
+        bat_offset,
+        total_blocks.min(bat_entries),
+        4,
+        |entry| be_u32(entry, 0) != BAT_UNUSED,
+    )?);
+
+    if variant == DiskImageVariant::Differencing {
+        image.parent_uuid = format_uuid(&header[40..56]);
+        image.parent_locations = parent_locations(reader, file_size, &header)?;
+    }
+    Ok(image)
+}
+
+fn parent_locations<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    header: &[u8],
+) -> Result<Vec<String>, VboxError> {
+    let mut locations = Vec::new();
+    for code in LOCATOR_CODES {
+        for entry in header[576..768].chunks_exact(24) {
+            let length = be_u32(entry, 8) as u64;
+            let offset = be_u64(entry, 16);
+            if &entry[..4] != code || length == 0 {
+                continue;
+            }
+            super::check_range(file_size, offset, length, "VHD parent locator")?;
+            let data = read_at(reader, offset, length as usize)?;
+            let location = if code.starts_with(b"W2") {
+                utf16_string(&data, false)
+            } else {
+                String::from_utf8_lossy(&data)
+                    .trim_end_matches('\0')
+                    .to_string()
+            };
+            if !location.is_empty() {
+                locations.push(location);
+            }
+        }
+    }
+    let name = utf16_string(&header[64..576], true);
+    if !name.is_empty() {
+        locations.push(name);
+    }
+    Ok(locations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn footer(disk_type: u32, header_offset: u64) -> [u8; 512] {
+        let mut footer = [0u8; 512];
+        footer[..8].copy_from_slice(COOKIE);
+        footer[16..24].copy_from_slice(&header_offset.to_be_bytes());
+        footer[48..56].copy_from_slice(&(8u64 << 20).to_be_bytes());
+        footer[60..64].copy_from_slice(&disk_type.to_be_bytes());
+        footer[68..84].copy_from_slice(&[
+            0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab,
+            0xcd, 0xef,
+        ]);
+        footer
+    }
+
+    /// A differencing disk of four 2 MiB blocks with block 1 allocated.
+    fn differencing_image() -> Vec<u8> {
+        let mut data = vec![0u8; 3072];
+        data[..512].copy_from_slice(&footer(TYPE_DIFF, 512));
+
+        let header = &mut data[512..1536];
+        header[..8].copy_from_slice(DYNAMIC_COOKIE);
+        header[8..16].copy_from_slice(&u64::MAX.to_be_bytes());
+        header[16..24].copy_from_slice(&1536u64.to_be_bytes());
+        header[28..32].copy_from_slice(&4u32.to_be_bytes());
+        header[32..36].copy_from_slice(&(2u32 << 20).to_be_bytes());
+        header[40..56].fill(0xAA);
+        for (index, unit) in "base.vhd".encode_utf16().enumerate() {
+            header[64 + index * 2..66 + index * 2].copy_from_slice(&unit.to_be_bytes());
+        }
+        let relative: Vec<u8> = ".\\base.vhd"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+        let locator = &mut header[576..600];
+        locator[..4].copy_from_slice(b"W2ru");
+        locator[4..8].copy_from_slice(&512u32.to_be_bytes());
+        locator[8..12].copy_from_slice(&(relative.len() as u32).to_be_bytes());
+        locator[16..24].copy_from_slice(&2048u64.to_be_bytes());
+
+        for (index, entry) in [BAT_UNUSED, 3, BAT_UNUSED, BAT_UNUSED].iter().enumerate() {
+            data[1536 + index * 4..1540 + index * 4].copy_from_slice(&entry.to_be_bytes());
+        }
+        data[2048..2048 + relative.len()].copy_from_slice(&relative);
+        data[2560..].copy_from_slice(&footer(TYPE_DIFF, 512));
+        data
+    }
+
+    #[test]
+    fn parses_differencing_image() {
+        let data = differencing_image();
+        let size = data.len() as u64;
+        let image = parse(&mut Cursor::new(data), size).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Differencing);
+        assert_eq!(
+            image.uuid.as_deref(),
+            Some("12345678-9abc-def0-0123-456789abcdef")
+        );
+        assert_eq!(
+            image.parent_uuid.as_deref(),
+            Some("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa")
+        );
+        assert_eq!(image.parent_locations, vec![".\\base.vhd", "base.vhd"]);
+        assert_eq!(image.logical_size, 8 << 20);
+        assert_eq!(image.block_size, Some(2 << 20));
+        assert_eq!(image.total_blocks, Some(4));
+        assert_eq!(image.allocated_blocks, Some(1));
+    }
+
+    #[test]
+    fn parses_fixed_image() {
+        let mut data = vec![0u8; 8192];
+        data[8192 - 512..].copy_from_slice(&footer(TYPE_FIXED, u64::MAX));
+        let image = parse(&mut Cursor::new(data), 8192).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Fixed);
+        assert_eq!(image.block_size, None);
+        assert_eq!(image.allocated_blocks, None);
+    }
+}
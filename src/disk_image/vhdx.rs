@@ -0,0 +1,437 @@
+use super::{
+    format_le_uuid, le_u16, le_u32, le_u64, parse_uuid, read_at, utf16_string, DiskImage,
+    DiskImageFormat, DiskImageVariant,
+};
+use crate::raw_disk_vmdk::partition_table::format_guid;
+use crate::VboxError;
+use std::io::{Read, Seek};
+
+pub(super) const SIGNATURE: &[u8; 8] = b"vhdxfile";
+const HEADER_OFFSETS: [u64; 2] = [64 * 1024, 128 * 1024];
+const HEADER_SIGNATURE: &[u8; 4] = b"head";
+const REGION_TABLE_OFFSET: u64 = 192 * 1024;
+const REGION_TABLE_SIGNATURE: &[u8; 4] = b"regi";
+const REGION_TABLE_SIZE: usize = 64 * 1024;
+const METADATA_SIGNATURE: &[u8; 8] = b"metadata";
+const METADATA_TABLE_SIZE: usize = 64 * 1024;
+
+const BAT_REGION: &str = "2dc27766-f623-4200-9d64-115e9bfd4a08";
+const METADATA_REGION: &str = "8b7ca206-4790-4b9a-b8fe-575f050f886e";
+const FILE_PARAMETERS: &str = "caa16737-fa36-4d43-b3b6-33f0aa44e76b";
+const VIRTUAL_DISK_SIZE: &str = "2fa54224-cd1b-4876-b211-5dbed83bf4b8";
+const VIRTUAL_DISK_ID: &str = "beca12ab-b2e6-4523-93ef-c309e000c746";
+const LOGICAL_SECTOR_SIZE: &str = "8141bf1d-a96f-4709-ba47-f233a8faab5f";
+const PARENT_LOCATOR: &str = "a8d35f2d-b30b-454d-abf7-d3d84834ab0c";
+
+const LEAVE_BLOCKS_ALLOCATED: u32 = 1;
+const HAS_PARENT: u32 = 1 << 1;
+const PAYLOAD_BLOCK_FULLY_PRESENT: u64 = 6;
+const PAYLOAD_BLOCK_PARTIALLY_PRESENT: u64 = 7;
+/// Parent locator keys, in the order the locations are tried.
+const LOCATOR_KEYS: [&str; 3] = ["relative_path", "absolute_win32_path", "volume_path"];
+
+/// Metadata items the parser uses.
+#[derive(Default)]
+struct Metadata {
+    block_size: Option<u32>,
+    flags: u32,
+    logical_size: Option<u64>,
+    uuid: Option<String>,
+    logical_sector_size: Option<u32>,
+    parent: Vec<(String, String)>,
+}
+
+pub(super) fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+) -> Result<DiskImage, VboxError> {
+    super::check_range(
+        file_size,
+        REGION_TABLE_OFFSET,
+        REGION_TABLE_SIZE as u64,
+        "VHDX region table",
+    )?;
+    let modification_uuid = current_header(reader)?;
+
+    let region_table = read_at(reader, REGION_TABLE_OFFSET, REGION_TABLE_SIZE)?;
+    if !region_table.starts_with(REGION_TABLE_SIGNATURE) {
+        return Err(invalid("missing VHDX region table"));
+    }
+    let region_count = (le_u32(&region_table, 8) as usize).min((REGION_TABLE_SIZE - 16) / 32);
+    let mut bat = None;
+    let mut metadata = None;
+    for entry in region_table[16..16 + region_count * 32].chunks_exact(32) {
+        let region = (le_u64(entry, 16), le_u32(entry, 24) as u64);
+        match format_guid(&entry[..16]).as_str() {
+            BAT_REGION => bat = Some(region),
+            METADATA_REGION => metadata = Some(region),
+            _ => {}
+        }
+    }
+    let (bat_offset, bat_length) = bat.ok_or(invalid("missing VHDX BAT region"))?;
+    let (metadata_offset, metadata_length) =
+        metadata.ok_or(invalid("missing VHDX metadata region"))?;
+    let metadata = read_metadata(reader, file_size, metadata_offset, metadata_length)?;
+
+    let block_size = metadata
+        .block_size
+        .filter(|size| *size > 0)
+        .ok_or(invalid("missing VHDX block size"))? as u64;
+    let logical_size = metadata
+        .logical_size
+        .ok_or(invalid("missing VHDX virtual disk size"))?;
+    let sector_size = metadata
+        .logical_sector_size
+        .ok_or(invalid("missing VHDX logical sector size"))? as u64;
+    let variant = if metadata.flags & HAS_PARENT != 0 {
+        DiskImageVariant::Differencing
+    } else if metadata.flags & LEAVE_BLOCKS_ALLOCATED != 0 {
+        DiskImageVariant::Fixed
+    } else {
+        DiskImageVariant::Dynamic
+    };
+
+    // Every chunk of payload block entries is followed by the entry of its sector bitmap block.
+    let chunk_ratio = ((1u64 << 23) * sector_size / block_size).max(1);
+    let total_blocks = logical_size.div_ceil(block_size);
+    let bat_entries = total_blocks
+        .checked_add(total_blocks.saturating_sub(1) / chunk_ratio)
+        .ok_or(invalid("VHDX block allocation table size overflows"))?
+        .min(bat_length / 8);
+    let mut index = 0;
+    let allocated_blocks =
+        super::count_entries(reader, file_size, bat_offset, bat_entries, 8, |entry| {
+            let is_payload = index % (chunk_ratio + 1) != chunk_ratio;
+            index += 1;
+            is_payload
+                && matches!(
+                    le_u64(entry, 0) & 7,
+                    PAYLOAD_BLOCK_FULLY_PRESENT | PAYLOAD_BLOCK_PARTIALLY_PRESENT
+                )
+        })?;
+
+    let mut parent_uuid = None;
+    let mut parent_locations = Vec::new();
+    if variant == DiskImageVariant::Differencing {
+        parent_uuid = metadata
+            .parent
+            .iter()
+            .find(|(key, _)| key == "parent_linkage")
+            .and_then(|(_, value)| parse_uuid(value));
+        for locator_key in LOCATOR_KEYS {
+            parent_locations.extend(
+                metadata
+                    .parent
+                    .iter()
+                    .filter(|(key, value)| key == locator_key && !value.is_empty())
+                    .map(|(_, value)| value.clone()),
+            );
+        }
+    }
+
+    Ok(DiskImage {
+        path: String::new(),
+        format: DiskImageFormat::Vhdx,
+        variant,
+        uuid: metadata.uuid,
+        modification_uuid,
+        parent_uuid,
+        parent_locations,
+        logical_size,
+        block_size: Some(block_size),
+        total_blocks: Some(total_blocks),
+        allocated_blocks: Some(allocated_blocks),
+    })
+}
+
+/// Data write GUID of the header with the highest sequence number.
+fn current_header<R: Read + Seek>(reader: &mut R) -> Result<Option<String>, VboxError> {
+    let mut current: Option<(u64, Vec<u8>)> = None;
+    for offset in HEADER_OFFSETS {
+        let header = read_at(reader, offset, 48)?;
+        if !header.starts_with(HEADER_SIGNATURE) {
+            continue;
+        }
+        let sequence = le_u64(&header, 8);
+        if current.as_ref().is_none_or(|(max, _)| sequence > *max) {
+            current = Some((sequence, header));
+        }
+    }
+    let (_, header) = current.ok_or(invalid("missing VHDX header"))?;
+    Ok(format_le_uuid(&header[32..48]))
+}
+
+fn read_metadata<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    offset: u64,
+    length: u64,
+) -> Result<Metadata, VboxError> {
+    super::check_range(file_size, offset, length, "VHDX metadata region")?;
+    if length < METADATA_TABLE_SIZE as u64 {
+        return Err(invalid("VHDX metadata region is too small"));
+    }
+    let table = read_at(reader, offset, METADATA_TABLE_SIZE)?;
+    if !table.starts_with(METADATA_SIGNATURE) {
+        return Err(invalid("missing VHDX metadata table"));
+    }
+    let count = (le_u16(&table, 10) as usize).min(METADATA_TABLE_SIZE / 32 - 1);
+    let mut metadata = Metadata::default();
+    for entry in table[32..32 + count * 32].chunks_exact(32) {
+        let item_offset = le_u32(entry, 16) as u64;
+        let item_length = le_u32(entry, 20) as u64;
+        if item_offset + item_length > length {
+            return Err(invalid("VHDX metadata item beyond its region"));
+        }
+        let item_id = format_guid(&entry[..16]);
+        let known = [
+            FILE_PARAMETERS,
+            VIRTUAL_DISK_SIZE,
+            VIRTUAL_DISK_ID,
+            LOGICAL_SECTOR_SIZE,
+            PARENT_LOCATOR,
+        ];
+        if !known.contains(&item_id.as_str()) {
+            continue;
+        }
+        let item = read_at(reader, offset + item_offset, item_length as usize)?;
+        let too_short = |size: usize| {
+            if item.len() < size {
+                Err(invalid("truncated VHDX metadata item"))
+            } else {
+                Ok(())
+            }
+        };
+        match item_id.as_str() {
+            FILE_PARAMETERS => {
+                too_short(8)?;
+                metadata.block_size = Some(le_u32(&item, 0));
+                metadata.flags = le_u32(&item, 4);
+            }
+            VIRTUAL_DISK_SIZE => {
+                too_short(8)?;
+                metadata.logical_size = Some(le_u64(&item, 0));
+            }
+            VIRTUAL_DISK_ID => {
+                too_short(16)?;
+                metadata.uuid = format_le_uuid(&item[..16]);
+            }
+            LOGICAL_SECTOR_SIZE => {
+                too_short(4)?;
+                metadata.logical_sector_size = Some(le_u32(&item, 0));
+            }
+            _ => metadata.parent = parent_locator(&item)?,
+        }
+    }
+    Ok(metadata)
+}
+
+/// Key-value pairs of a parent locator item.
+fn parent_locator(item: &[u8]) -> Result<Vec<(String, String)>, VboxError> {
+    if item.len() < 20 {
+        return Err(invalid("truncated VHDX parent locator"));
+    }
+    let count = le_u16(item, 18) as usize;
+    let mut pairs = Vec::with_capacity(count);
+    for index in 0..count {
+        let entry = 20 + index * 12;
+        if entry + 12 > item.len() {
+            return Err(invalid("truncated VHDX parent locator"));
+        }
+        let text = |offset: usize, length: usize| {
+            item.get(offset..offset + length)
+                .map(|bytes| utf16_string(bytes, false))
+                .ok_or(invalid("VHDX parent locator entry beyond the item"))
+        };
+        let key = text(
+            le_u32(item, entry) as usize,
+            le_u16(item, entry + 8) as usize,
+        )?;
+        let value = text(
+            le_u32(item, entry + 4) as usize,
+            le_u16(item, entry + 10) as usize,
+        )?;
+        pairs.push((key, value));
+    }
+    Ok(pairs)
+}
+
+fn invalid(msg: &str) -> VboxError {
+    VboxError::invalid_data("DiskImage::inspect", msg.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const BAT_OFFSET: usize = 256 * 1024;
+    const METADATA_OFFSET: usize = 320 * 1024;
+
+    /// Windows byte order of a textual GUID.
+    fn guid(text: &str) -> [u8; 16] {
+        let hex: String = text.chars().filter(|c| *c != '-').collect();
+        let mut bytes = [0u8; 16];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).unwrap();
+        }
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        bytes
+    }
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect()
+    }
+
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn locator(pairs: &[(&str, &str)]) -> Vec<u8> {
+        let mut item = vec![0u8; 20 + pairs.len() * 12];
+        put(&mut item, 18, &(pairs.len() as u16).to_le_bytes());
+        for (index, (key, value)) in pairs.iter().enumerate() {
+            let (key, value) = (utf16(key), utf16(value));
+            let entry = 20 + index * 12;
+            let key_offset = item.len() as u32;
+            item.extend(&key);
+            let value_offset = item.len() as u32;
+            item.extend(&value);
+            put(&mut item, entry, &key_offset.to_le_bytes());
+            put(&mut item, entry + 4, &value_offset.to_le_bytes());
+            put(&mut item, entry + 8, &(key.len() as u16).to_le_bytes());
+            put(&mut item, entry + 10, &(value.len() as u16).to_le_bytes());
+        }
+        item
+    }
+
+    /// A differencing disk of 8 MiB with 1 MiB blocks and 512 byte sectors, so one chunk holds all
+    /// payload blocks. Blocks 0 and 5 are present.
+    fn differencing_image() -> Vec<u8> {
+        let mut data = vec![0u8; 384 * 1024];
+        put(&mut data, 0, SIGNATURE);
+        for (offset, sequence, data_write) in [(64 * 1024, 2u64, 0x22u8), (128 * 1024, 1, 0x11)] {
+            put(&mut data, offset, HEADER_SIGNATURE);
+            put(&mut data, offset + 8, &sequence.to_le_bytes());
+            data[offset + 32..offset + 48].fill(data_write);
+        }
+
+        let regions = REGION_TABLE_OFFSET as usize;
+        put(&mut data, regions, REGION_TABLE_SIGNATURE);
+        put(&mut data, regions + 8, &2u32.to_le_bytes());
+        for (index, (id, offset, length)) in [
+            (BAT_REGION, BAT_OFFSET, 64 * 1024u32),
+            (METADATA_REGION, METADATA_OFFSET, 64 * 1024),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let entry = regions + 16 + index * 32;
+            put(&mut data, entry, &guid(id));
+            put(&mut data, entry + 16, &(*offset as u64).to_le_bytes());
+            put(&mut data, entry + 24, &length.to_le_bytes());
+        }
+
+        put(
+            &mut data,
+            BAT_OFFSET,
+            &(PAYLOAD_BLOCK_FULLY_PRESENT | (4 << 20)).to_le_bytes(),
+        );
+        put(
+            &mut data,
+            BAT_OFFSET + 5 * 8,
+            &PAYLOAD_BLOCK_PARTIALLY_PRESENT.to_le_bytes(),
+        );
+
+        let mut file_parameters = (1u32 << 20).to_le_bytes().to_vec();
+        file_parameters.extend(HAS_PARENT.to_le_bytes());
+        let items = [
+            (FILE_PARAMETERS, file_parameters),
+            (VIRTUAL_DISK_SIZE, (8u64 << 20).to_le_bytes().to_vec()),
+            (
+                VIRTUAL_DISK_ID,
+                guid("01234567-89ab-cdef-0123-456789abcdef").to_vec(),
+            ),
+            (LOGICAL_SECTOR_SIZE, 512u32.to_le_bytes().to_vec()),
+            (
+                PARENT_LOCATOR,
+                locator(&[
+                    ("parent_linkage", "{33333333-3333-3333-3333-333333333333}"),
+                    ("absolute_win32_path", "C:\\VMs\\base.vhdx"),
+                    ("relative_path", ".\\base.vhdx"),
+                ]),
+            ),
+        ];
+        put(&mut data, METADATA_OFFSET, METADATA_SIGNATURE);
+        put(
+            &mut data,
+            METADATA_OFFSET + 10,
+            &(items.len() as u16).to_le_bytes(),
+        );
+        let mut item_offset = 32 * 1024;
+        for (index, (id, item)) in items.iter().enumerate() {
+            let entry = METADATA_OFFSET + 32 + index * 32;
+            put(&mut data, entry, &guid(id));
+            put(&mut data, entry + 16, &(item_offset as u32).to_le_bytes());
+            put(&mut data, entry + 20, &(item.len() as u32).to_le_bytes());
+            put(&mut data, METADATA_OFFSET + item_offset, item);
+            item_offset += 1024;
+        }
+        data
+    }
+
+    #[test]
+    fn parses_differencing_image() {
+        let data = differencing_image();
+        let size = data.len() as u64;
+        let image = parse(&mut Cursor::new(data), size).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Differencing);
+        assert_eq!(
+            image.uuid.as_deref(),
+            Some("01234567-89ab-cdef-0123-456789abcdef")
+        );
+        assert_eq!(
+            image.modification_uuid.as_deref(),
+            Some("22222222-2222-2222-2222-222222222222")
+        );
+        assert_eq!(
+            image.parent_uuid.as_deref(),
+            Some("33333333-3333-3333-3333-333333333333")
+        );
+        assert_eq!(
+            image.parent_locations,
+            vec![".\\base.vhdx", "C:\\VMs\\base.vhdx"]
+        );
+        assert_eq!(image.logical_size, 8 << 20);
+        assert_eq!(image.block_size, Some(1 << 20));
+        assert_eq!(image.total_blocks, Some(8));
+        assert_eq!(image.allocated_blocks, Some(2));
+    }
+
+    #[test]
+    fn missing_region_table_fails() {
+        let mut data = differencing_image();
+        data[REGION_TABLE_OFFSET as usize] = 0;
+        let size = data.len() as u64;
+        let err = parse(&mut Cursor::new(data), size).unwrap_err();
+        assert_eq!(err.msg, "missing VHDX region table");
+    }
+
+    #[test]
+    fn rejects_overflowing_header_values() {
+        let mut data = differencing_image();
+        put(&mut data, METADATA_OFFSET + 32 * 1024, &1u32.to_le_bytes());
+        put(
+            &mut data,
+            METADATA_OFFSET + 33 * 1024,
+            &u64::MAX.to_le_bytes(),
+        );
+        let size = data.len() as u64;
+        let err = parse(&mut Cursor::new(data), size).unwrap_err();
+        assert_eq!(err.msg, "VHDX block allocation table size overflows");
+    }
+}
@@ -0,0 +1,427 @@
+use super::{le_u32, le_u64, parse_uuid, read_at, DiskImage, DiskImageFormat, DiskImageVariant};
+use crate::VboxError;
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::Path;
+
+pub(super) const SPARSE_MAGIC: &[u8; 4] = b"KDMV";
+pub(super) const DESCRIPTOR_SIGNATURE: &[u8; 21] = b"# Disk DescriptorFile";
+const SECTOR_SIZE: u64 = 512;
+const HEADER_SIZE: usize = 512;
+/// Grain directory offset of stream optimized images, whose real offset is in the footer.
+const GD_AT_END: u64 = u64::MAX;
+/// Header flag of images in which the grain table entry 1 marks a zeroed grain.
+const ZERO_GRAIN_GTE_FLAG: u32 = 1 << 2;
+/// Largest descriptor read, descriptor files are a few kilobytes.
+const MAX_DESCRIPTOR_SIZE: u64 = 1024 * 1024;
+
+/// Fields of a VMDK text descriptor.
+#[derive(Debug, Default)]
+struct Descriptor {
+    create_type: String,
+    parent_cid: Option<String>,
+    parent_file_name_hint: Option<String>,
+    uuid: Option<String>,
+    modification_uuid: Option<String>,
+    parent_uuid: Option<String>,
+    extents: Vec<Extent>,
+}
+
+#[derive(Debug)]
+struct Extent {
+    sectors: u64,
+    kind: String,
+    file: Option<String>,
+}
+
+/// Allocation of sparse extents.
+#[derive(Debug, Default)]
+struct Grains {
+    grain_size: u64,
+    total: u64,
+    allocated: u64,
+}
+
+pub(super) fn parse<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    dir: Option<&Path>,
+) -> Result<DiskImage, VboxError> {
+    let (descriptor, logical_size, grains) = if read_at(reader, 0, 4)? == SPARSE_MAGIC {
+        let header = read_at(reader, 0, HEADER_SIZE)?;
+        let descriptor_offset = sectors_to_bytes(le_u64(&header, 28), "descriptor offset")?;
+        let descriptor_size = sectors_to_bytes(le_u64(&header, 36), "descriptor size")?;
+        if descriptor_size == 0 {
+            return Err(invalid(
+                "VMDK sparse extent without a descriptor, inspect its descriptor file instead",
+            ));
+        }
+        let descriptor = read_descriptor(reader, file_size, descriptor_offset, descriptor_size)?;
+        let grains = count_grains(reader, file_size, &header)?;
+        let logical_size = sectors_to_bytes(le_u64(&header, 12), "capacity")?;
+        (descriptor, logical_size, Some(grains))
+    } else {
+        let descriptor = read_descriptor(reader, file_size, 0, file_size)?;
+        let logical_size = descriptor.extents.iter().try_fold(0u64, |size, extent| {
+            size.checked_add(sectors_to_bytes(extent.sectors, "extent size")?)
+                .ok_or(invalid("VMDK extent sizes overflow"))
+        })?;
+        let grains = match dir {
+            Some(dir) => extent_grains(&descriptor, dir)?,
+            None => None,
+        };
+        (descriptor, logical_size, grains)
+    };
+    if descriptor.extents.is_empty() {
+        return Err(invalid("VMDK descriptor without extents"));
+    }
+
+    let create_type = descriptor.create_type.to_lowercase();
+    let has_parent = descriptor.parent_uuid.is_some()
+        || descriptor.parent_file_name_hint.is_some()
+        || descriptor
+            .parent_cid
+            .as_ref()
+            .is_some_and(|cid| !cid.eq_ignore_ascii_case("ffffffff"));
+    let variant = if has_parent {
+        DiskImageVariant::Differencing
+    } else if create_type.contains("flat")
+        || create_type.contains("device")
+        || (create_type.starts_with("vmfs") && !create_type.contains("sparse"))
+    {
+        DiskImageVariant::Fixed
+    } else {
+        DiskImageVariant::Dynamic
+    };
+
+    Ok(DiskImage {
+        path: String::new(),
+        format: DiskImageFormat::Vmdk,
+        variant,
+        uuid: descriptor.uuid,
+        modification_uuid: descriptor.modification_uuid,
+        parent_uuid: descriptor.parent_uuid,
+        parent_locations: descriptor.parent_file_name_hint.into_iter().collect(),
+        logical_size,
+        block_size: grains.as_ref().map(|grains| grains.grain_size),
+        total_blocks: grains.as_ref().map(|grains| grains.total),
+        allocated_blocks: grains.as_ref().map(|grains| grains.allocated),
+    })
+}
+
+fn read_descriptor<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    offset: u64,
+    size: u64,
+) -> Result<Descriptor, VboxError> {
+    if size > MAX_DESCRIPTOR_SIZE {
+        return Err(invalid("VMDK descriptor is too large"));
+    }
+    super::check_range(file_size, offset, size, "VMDK descriptor")?;
+    let data = read_at(reader, offset, size as usize)?;
+    let end = data
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(data.len());
+    Ok(parse_descriptor(&String::from_utf8_lossy(&data[..end])))
+}
+
+fn parse_descriptor(text: &str) -> Descriptor {
+    let mut descriptor = Descriptor::default();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim() {
+                "createType" => descriptor.create_type = value,
+                "parentCID" => descriptor.parent_cid = Some(value),
+                "parentFileNameHint" if !value.is_empty() => {
+                    descriptor.parent_file_name_hint = Some(value)
+                }
+                "ddb.uuid.image" => descriptor.uuid = parse_uuid(&value),
+                "ddb.uuid.modification" => descriptor.modification_uuid = parse_uuid(&value),
+                "ddb.uuid.parent" => descriptor.parent_uuid = parse_uuid(&value),
+                _ => {}
+            }
+        } else if let Some(extent) = parse_extent(line) {
+            descriptor.extents.push(extent);
+        }
+    }
+    descriptor
+}
+
+/// Parses an extent line like `RW 4192256 SPARSE "disk-s001.vmdk"`.
+fn parse_extent(line: &str) -> Option<Extent> {
+    let (fields, file) = match line.split_once('"') {
+        Some((fields, rest)) => (fields, rest.split('"').next().map(str::to_string)),
+        None => (line, None),
+    };
+    let mut fields = fields.split_whitespace();
+    if !matches!(fields.next()?, "RW" | "RDONLY" | "NOACCESS") {
+        return None;
+    }
+    let sectors = fields.next()?.parse().ok()?;
+    let kind = fields.next()?.to_uppercase();
+    Some(Extent {
+        sectors,
+        kind,
+        file,
+    })
+}
+
+/// Allocation of the sparse extent files of a descriptor file, `None` if it has flat extents.
+fn extent_grains(descriptor: &Descriptor, dir: &Path) -> Result<Option<Grains>, VboxError> {
+    let mut grains = Grains::default();
+    for extent in &descriptor.extents {
+        match (extent.kind.as_str(), &extent.file) {
+            ("ZERO", _) => {}
+            ("SPARSE", Some(file)) => {
+                let path = dir.join(file);
+                let mut reader = File::open(&path).map_err(|err| {
+                    VboxError::invalid_data(
+                        "DiskImage::inspect",
+                        format!("VMDK extent {}: {}", path.display(), err),
+                    )
+                })?;
+                let file_size = reader
+                    .metadata()
+                    .map_err(|err| VboxError::io_error("DiskImage::inspect", err))?
+                    .len();
+                let header = read_at(&mut reader, 0, HEADER_SIZE)?;
+                if &header[..4] != SPARSE_MAGIC {
+                    return Err(VboxError::invalid_data(
+                        "DiskImage::inspect",
+                        format!("VMDK extent {} is not a sparse extent", path.display()),
+                    ));
+                }
+                let extent_grains = count_grains(&mut reader, file_size, &header)?;
+                grains.grain_size = extent_grains.grain_size;
+                grains.total = grains
+                    .total
+                    .checked_add(extent_grains.total)
+                    .ok_or(invalid("VMDK extent sizes overflow"))?;
+                grains.allocated += extent_grains.allocated;
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(grains))
+}
+
+/// Counts the allocated grains of a sparse extent with the given header.
+fn count_grains<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    header: &[u8],
+) -> Result<Grains, VboxError> {
+    let mut gd_offset = le_u64(header, 56);
+    if gd_offset == GD_AT_END {
+        // The footer is followed by the end-of-stream marker.
+        super::check_range(file_size, 0, 1024, "VMDK footer")?;
+        let footer = read_at(reader, file_size - 1024, HEADER_SIZE)?;
+        if &footer[..4] != SPARSE_MAGIC {
+            return Err(invalid("missing VMDK footer"));
+        }
+        gd_offset = le_u64(&footer, 56);
+    }
+    let flags = le_u32(header, 8);
+    let capacity = le_u64(header, 12);
+    let grain_sectors = le_u64(header, 20);
+    let gtes_per_gt = le_u32(header, 44) as u64;
+    if grain_sectors == 0 || gtes_per_gt == 0 {
+        return Err(invalid("invalid VMDK grain size"));
+    }
+    let total = capacity.div_ceil(grain_sectors);
+    let tables = total.div_ceil(gtes_per_gt);
+    let directory_offset = sectors_to_bytes(gd_offset, "grain directory offset")?;
+    let directory_size = tables
+        .checked_mul(4)
+        .ok_or(invalid("VMDK grain directory size overflows"))?;
+    super::check_range(
+        file_size,
+        directory_offset,
+        directory_size,
+        "VMDK grain directory",
+    )?;
+    let directory = read_at(reader, directory_offset, directory_size as usize)?;
+    let mut allocated = 0;
+    for (index, entry) in directory.chunks_exact(4).enumerate() {
+        let table_offset = le_u32(entry, 0) as u64;
+        if table_offset == 0 {
+            continue;
+        }
+        let entries = gtes_per_gt.min(total - index as u64 * gtes_per_gt);
+        allocated += super::count_entries(
+            reader,
+            file_size,
+            table_offset * SECTOR_SIZE,
+            entries,
+            4,
+            |entry| match le_u32(entry, 0) {
+                0 => false,
+                1 => flags & ZERO_GRAIN_GTE_FLAG == 0,
+                _ => true,
+            },
+        )?;
+    }
+    Ok(Grains {
+        grain_size: sectors_to_bytes(grain_sectors, "grain size")?,
+        total,
+        allocated,
+    })
+}
+
+/// Converts a sector count read from the image into bytes.
+fn sectors_to_bytes(sectors: u64, what: &str) -> Result<u64, VboxError> {
+    sectors
+        .checked_mul(SECTOR_SIZE)
+        .ok_or(VboxError::invalid_data(
+            "DiskImage::inspect",
+            format!("VMDK {} of {} sectors overflows", what, sectors),
+        ))
+}
+
+fn invalid(msg: &str) -> VboxError {
+    VboxError::invalid_data("DiskImage::inspect", msg.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const DESCRIPTOR: &str = "# Disk DescriptorFile
+version=1
+CID=1a2b3c4d
+parentCID=5e6f7a8b
+createType=\"monolithicSparse\"
+parentFileNameHint=\"/vms/base.vmdk\"
+
+# Extent description
+RW 2048 SPARSE \"diff.vmdk\"
+
+# The disk Data Base
+ddb.virtualHWVersion = \"4\"
+ddb.uuid.image=\"01234567-89ab-cdef-0123-456789abcdef\"
+ddb.uuid.parent=\"fedcba98-7654-3210-fedc-ba9876543210\"
+ddb.uuid.modification=\"00000000-0000-0000-0000-000000000000\"
+";
+
+    /// A 1 MiB extent with 64 KiB grains and 8 entries per grain table. The descriptor is in
+    /// sectors 1-2, the grain directory in sector 3, the grain tables in sectors 4 and 5.
+    fn sparse_image(descriptor: &str, stream_optimized: bool) -> Vec<u8> {
+        let mut data = vec![0u8; 512 * 8];
+        data[..4].copy_from_slice(SPARSE_MAGIC);
+        data[4..8].copy_from_slice(&1u32.to_le_bytes());
+        data[8..12].copy_from_slice(&ZERO_GRAIN_GTE_FLAG.to_le_bytes());
+        data[12..20].copy_from_slice(&2048u64.to_le_bytes());
+        data[20..28].copy_from_slice(&128u64.to_le_bytes());
+        if !descriptor.is_empty() {
+            data[28..36].copy_from_slice(&1u64.to_le_bytes());
+            data[36..44].copy_from_slice(&2u64.to_le_bytes());
+            data[512..512 + descriptor.len()].copy_from_slice(descriptor.as_bytes());
+        }
+        data[44..48].copy_from_slice(&8u32.to_le_bytes());
+        data[1536..1540].copy_from_slice(&4u32.to_le_bytes());
+        data[1540..1544].copy_from_slice(&5u32.to_le_bytes());
+        // Grains 0 and 2 allocated, grain 3 zeroed, grain 12 allocated.
+        data[2048..2052].copy_from_slice(&6u32.to_le_bytes());
+        data[2056..2060].copy_from_slice(&7u32.to_le_bytes());
+        data[2060..2064].copy_from_slice(&1u32.to_le_bytes());
+        data[2576..2580].copy_from_slice(&8u32.to_le_bytes());
+        if stream_optimized {
+            data[56..64].copy_from_slice(&GD_AT_END.to_le_bytes());
+            let mut footer = data[..512].to_vec();
+            footer[56..64].copy_from_slice(&3u64.to_le_bytes());
+            data.extend(vec![0u8; 512]);
+            data.extend(footer);
+            data.extend(vec![0u8; 512]);
+        } else {
+            data[56..64].copy_from_slice(&3u64.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn parses_sparse_image() {
+        for stream_optimized in [false, true] {
+            let data = sparse_image(DESCRIPTOR, stream_optimized);
+            let size = data.len() as u64;
+            let image = parse(&mut Cursor::new(data), size, None).unwrap();
+            assert_eq!(image.variant, DiskImageVariant::Differencing);
+            assert_eq!(
+                image.uuid.as_deref(),
+                Some("01234567-89ab-cdef-0123-456789abcdef")
+            );
+            assert_eq!(image.modification_uuid, None);
+            assert_eq!(
+                image.parent_uuid.as_deref(),
+                Some("fedcba98-7654-3210-fedc-ba9876543210")
+            );
+            assert_eq!(image.parent_locations, vec!["/vms/base.vmdk"]);
+            assert_eq!(image.logical_size, 1 << 20);
+            assert_eq!(image.block_size, Some(64 * 1024));
+            assert_eq!(image.total_blocks, Some(16));
+            assert_eq!(image.allocated_blocks, Some(3));
+        }
+    }
+
+    #[test]
+    fn parses_descriptor_file_with_extents() {
+        let dir = std::env::temp_dir().join(format!("disk_image_vmdk_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("disk-s001.vmdk"), sparse_image("", false)).unwrap();
+        std::fs::write(dir.join("disk-s002.vmdk"), sparse_image("", false)).unwrap();
+        let descriptor = "# Disk DescriptorFile
+CID=1a2b3c4d
+parentCID=ffffffff
+createType=\"twoGbMaxExtentSparse\"
+RW 2048 SPARSE \"disk-s001.vmdk\"
+RW 2048 SPARSE \"disk-s002.vmdk\"
+ddb.uuid.image=\"01234567-89ab-cdef-0123-456789abcdef\"
+";
+        let size = descriptor.len() as u64;
+        let image = parse(&mut Cursor::new(descriptor), size, Some(&dir)).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Dynamic);
+        assert_eq!(image.logical_size, 2 << 20);
+        assert_eq!(image.total_blocks, Some(32));
+        assert_eq!(image.allocated_blocks, Some(6));
+
+        let flat = "# Disk DescriptorFile
+createType=\"monolithicFlat\"
+RW 4096 FLAT \"disk-flat.vmdk\" 0
+";
+        let size = flat.len() as u64;
+        let image = parse(&mut Cursor::new(flat), size, Some(&dir)).unwrap();
+        assert_eq!(image.variant, DiskImageVariant::Fixed);
+        assert_eq!(image.logical_size, 2 << 20);
+        assert_eq!(image.allocated_blocks, None);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_overflowing_header_values() {
+        for (offset, value) in [(12, u64::MAX), (28, u64::MAX / 256), (56, u64::MAX / 256)] {
+            let mut data = sparse_image(DESCRIPTOR, false);
+            data[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+            let size = data.len() as u64;
+            assert!(parse(&mut Cursor::new(data), size, None).is_err());
+        }
+        // One sector per grain and per grain table makes the grain directory size overflow.
+        let mut data = sparse_image(DESCRIPTOR, false);
+        data[12..20].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+        data[20..28].copy_from_slice(&1u64.to_le_bytes());
+        data[44..48].copy_from_slice(&1u32.to_le_bytes());
+        let size = data.len() as u64;
+        assert!(parse(&mut Cursor::new(data), size, None).is_err());
+
+        let descriptor = "# Disk DescriptorFile
+RW 18446744073709551615 FLAT \"disk-flat.vmdk\" 0
+";
+        let size = descriptor.len() as u64;
+        assert!(parse(&mut Cursor::new(descriptor), size, None).is_err());
+    }
+}
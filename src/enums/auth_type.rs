@@ -0,0 +1,44 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// VirtualBox authentication type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AuthType {
+    /// Null value, also means "no authentication".
+    Null,
+    /// Authentication is performed by the external authentication library configured on the host.
+    External,
+    /// Authentication is performed by the guest additions.
+    Guest,
+}
+
+impl From<u32> for AuthType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::AuthType_AuthType_Null => AuthType::Null,
+            raw::AuthType_AuthType_External => AuthType::External,
+            raw::AuthType_AuthType_Guest => AuthType::Guest,
+            _ => {
+                error!("Unknown AuthType value: {}", value);
+                AuthType::Null
+            }
+        }
+    }
+}
+
+impl Into<u32> for AuthType {
+    fn into(self) -> u32 {
+        match self {
+            AuthType::Null => raw::AuthType_AuthType_Null,
+            AuthType::External => raw::AuthType_AuthType_External,
+            AuthType::Guest => raw::AuthType_AuthType_Guest,
+        }
+    }
+}
+
+impl Display for AuthType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use vbox_raw::sys_lib as raw;
 
 /// Device type.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum DeviceType {
     /// Null value, may also mean "no device".
     Null,
@@ -0,0 +1,48 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Scope of a DHCP server configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DHCPConfigScope {
+    /// Global configuration of the DHCP server.
+    Global,
+    /// Configuration applying to a group of clients selected by conditions.
+    Group,
+    /// Configuration applying to a network adapter slot of a virtual machine.
+    MachineNIC,
+    /// Configuration applying to a client with a specific MAC address.
+    MAC,
+}
+
+impl From<u32> for DHCPConfigScope {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::DHCPConfigScope_DHCPConfigScope_Global => DHCPConfigScope::Global,
+            raw::DHCPConfigScope_DHCPConfigScope_Group => DHCPConfigScope::Group,
+            raw::DHCPConfigScope_DHCPConfigScope_MachineNIC => DHCPConfigScope::MachineNIC,
+            raw::DHCPConfigScope_DHCPConfigScope_MAC => DHCPConfigScope::MAC,
+            _ => {
+                error!("Unknown DHCPConfigScope value: {}", value);
+                DHCPConfigScope::Global
+            }
+        }
+    }
+}
+
+impl Into<u32> for DHCPConfigScope {
+    fn into(self) -> u32 {
+        match self {
+            DHCPConfigScope::Global => raw::DHCPConfigScope_DHCPConfigScope_Global,
+            DHCPConfigScope::Group => raw::DHCPConfigScope_DHCPConfigScope_Group,
+            DHCPConfigScope::MachineNIC => raw::DHCPConfigScope_DHCPConfigScope_MachineNIC,
+            DHCPConfigScope::MAC => raw::DHCPConfigScope_DHCPConfigScope_MAC,
+        }
+    }
+}
+
+impl Display for DHCPConfigScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
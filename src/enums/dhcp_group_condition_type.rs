@@ -0,0 +1,76 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Type of a condition selecting the members of a DHCP group configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DHCPGroupConditionType {
+    /// Matches the MAC address of the client.
+    MAC,
+    /// Matches the MAC address of the client with a wildcard pattern.
+    MACWildcard,
+    /// Matches the vendor class ID sent by the client.
+    VendorClassID,
+    /// Matches the vendor class ID sent by the client with a wildcard pattern.
+    VendorClassIDWildcard,
+    /// Matches the user class ID sent by the client.
+    UserClassID,
+    /// Matches the user class ID sent by the client with a wildcard pattern.
+    UserClassIDWildcard,
+}
+
+impl From<u32> for DHCPGroupConditionType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_MAC => DHCPGroupConditionType::MAC,
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_MACWildcard => {
+                DHCPGroupConditionType::MACWildcard
+            }
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_vendorClassID => {
+                DHCPGroupConditionType::VendorClassID
+            }
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_vendorClassIDWildcard => {
+                DHCPGroupConditionType::VendorClassIDWildcard
+            }
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_userClassID => {
+                DHCPGroupConditionType::UserClassID
+            }
+            raw::DHCPGroupConditionType_DHCPGroupConditionType_userClassIDWildcard => {
+                DHCPGroupConditionType::UserClassIDWildcard
+            }
+            _ => {
+                error!("Unknown DHCPGroupConditionType value: {}", value);
+                DHCPGroupConditionType::MAC
+            }
+        }
+    }
+}
+
+impl Into<u32> for DHCPGroupConditionType {
+    fn into(self) -> u32 {
+        match self {
+            DHCPGroupConditionType::MAC => raw::DHCPGroupConditionType_DHCPGroupConditionType_MAC,
+            DHCPGroupConditionType::MACWildcard => {
+                raw::DHCPGroupConditionType_DHCPGroupConditionType_MACWildcard
+            }
+            DHCPGroupConditionType::VendorClassID => {
+                raw::DHCPGroupConditionType_DHCPGroupConditionType_vendorClassID
+            }
+            DHCPGroupConditionType::VendorClassIDWildcard => {
+                raw::DHCPGroupConditionType_DHCPGroupConditionType_vendorClassIDWildcard
+            }
+            DHCPGroupConditionType::UserClassID => {
+                raw::DHCPGroupConditionType_DHCPGroupConditionType_userClassID
+            }
+            DHCPGroupConditionType::UserClassIDWildcard => {
+                raw::DHCPGroupConditionType_DHCPGroupConditionType_userClassIDWildcard
+            }
+        }
+    }
+}
+
+impl Display for DHCPGroupConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,312 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// DHCP option codes supported by the VirtualBox DHCP server.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DHCPOption {
+    /// Option 1, subnet mask.
+    SubnetMask,
+    /// Option 2, time offset.
+    TimeOffset,
+    /// Option 3, routers.
+    Routers,
+    /// Option 4, time servers.
+    TimeServers,
+    /// Option 5, name servers.
+    NameServers,
+    /// Option 6, domain name servers.
+    DomainNameServers,
+    /// Option 7, log servers.
+    LogServers,
+    /// Option 8, cookie servers.
+    CookieServers,
+    /// Option 9, LPR servers.
+    LPRServers,
+    /// Option 10, impress servers.
+    ImpressServers,
+    /// Option 11, resource location servers.
+    ResourseLocationServers,
+    /// Option 12, host name.
+    HostName,
+    /// Option 13, boot file size.
+    BootFileSize,
+    /// Option 14, merit dump file.
+    MeritDumpFile,
+    /// Option 15, domain name.
+    DomainName,
+    /// Option 16, swap server.
+    SwapServer,
+    /// Option 17, root path.
+    RootPath,
+    /// Option 18, extension path.
+    ExtensionPath,
+    /// Option 19, IP forwarding.
+    IPForwarding,
+    /// Option 20, non-local source routing.
+    OptNonLocalSourceRouting,
+    /// Option 21, policy filter.
+    PolicyFilter,
+    /// Option 22, maximum datagram reassembly size.
+    MaxDgramReassemblySize,
+    /// Option 23, default IP TTL.
+    DefaultIPTTL,
+    /// Option 24, path MTU aging timeout.
+    PathMTUAgingTimeout,
+    /// Option 25, path MTU plateau table.
+    PathMTUPlateauTable,
+    /// Option 26, interface MTU.
+    InterfaceMTU,
+    /// Option 27, all subnets are local.
+    AllSubnetsAreLocal,
+    /// Option 28, broadcast address.
+    BroadcastAddress,
+    /// Option 29, perform mask discovery.
+    PerformMaskDiscovery,
+    /// Option 30, mask supplier.
+    MaskSupplier,
+    /// Option 31, perform router discovery.
+    PerformRouterDiscovery,
+    /// Option 32, router solicitation address.
+    RouterSolicitationAddress,
+    /// Option 33, static route.
+    StaticRoute,
+    /// Option 34, trailer encapsulation.
+    TrailerEncapsulation,
+    /// Option 35, ARP cache timeout.
+    ARPCacheTimeout,
+    /// Option 36, ethernet encapsulation.
+    EthernetEncapsulation,
+    /// Option 37, TCP default TTL.
+    TCPDefaultTTL,
+    /// Option 38, TCP keepalive interval.
+    TCPKeepaliveInterval,
+    /// Option 39, TCP keepalive garbage.
+    TCPKeepaliveGarbage,
+    /// Option 40, NIS domain.
+    NISDomain,
+    /// Option 41, NIS servers.
+    NISServers,
+    /// Option 42, NTP servers.
+    NTPServers,
+    /// Option 43, vendor specific information.
+    VendorSpecificInfo,
+    /// Option 44, NetBIOS name servers.
+    NetBIOSNameServers,
+    /// Option 45, NetBIOS datagram servers.
+    NetBIOSDatagramServers,
+    /// Option 46, NetBIOS node type.
+    NetBIOSNodeType,
+    /// Option 47, NetBIOS scope.
+    NetBIOSScope,
+    /// Option 48, X Window System font servers.
+    XWindowsFontServers,
+    /// Option 49, X Window System display manager.
+    XWindowsDisplayManager,
+    /// Option 62, NetWare/IP domain name.
+    NetWareIPDomainName,
+    /// Option 63, NetWare/IP information.
+    NetWareIPInformation,
+    /// Option 64, NIS plus domain.
+    NISPlusDomain,
+    /// Option 65, NIS plus servers.
+    NISPlusServers,
+    /// Option 66, TFTP server name.
+    TFTPServerName,
+    /// Option 67, bootfile name.
+    BootfileName,
+    /// Option 68, mobile IP home agents.
+    MobileIPHomeAgents,
+    /// Option 69, SMTP servers.
+    SMTPServers,
+    /// Option 70, POP3 servers.
+    POP3Servers,
+    /// Option 71, NNTP servers.
+    NNTPServers,
+    /// Option 72, WWW servers.
+    WWWServers,
+    /// Option 73, finger servers.
+    FingerServers,
+    /// Option 74, IRC servers.
+    IRCServers,
+    /// Option 75, StreetTalk servers.
+    StreetTalkServers,
+    /// Option 76, STDA servers.
+    STDAServers,
+    /// Option 78, SLP directory agent.
+    SLPDirectoryAgent,
+    /// Option 79, SLP service scope.
+    SLPServiceScope,
+    /// Option 119, domain search.
+    DomainSearch,
+}
+
+impl From<u32> for DHCPOption {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::DHCPOption_DHCPOption_SubnetMask => DHCPOption::SubnetMask,
+            raw::DHCPOption_DHCPOption_TimeOffset => DHCPOption::TimeOffset,
+            raw::DHCPOption_DHCPOption_Routers => DHCPOption::Routers,
+            raw::DHCPOption_DHCPOption_TimeServers => DHCPOption::TimeServers,
+            raw::DHCPOption_DHCPOption_NameServers => DHCPOption::NameServers,
+            raw::DHCPOption_DHCPOption_DomainNameServers => DHCPOption::DomainNameServers,
+            raw::DHCPOption_DHCPOption_LogServers => DHCPOption::LogServers,
+            raw::DHCPOption_DHCPOption_CookieServers => DHCPOption::CookieServers,
+            raw::DHCPOption_DHCPOption_LPRServers => DHCPOption::LPRServers,
+            raw::DHCPOption_DHCPOption_ImpressServers => DHCPOption::ImpressServers,
+            raw::DHCPOption_DHCPOption_ResourseLocationServers => {
+                DHCPOption::ResourseLocationServers
+            }
+            raw::DHCPOption_DHCPOption_HostName => DHCPOption::HostName,
+            raw::DHCPOption_DHCPOption_BootFileSize => DHCPOption::BootFileSize,
+            raw::DHCPOption_DHCPOption_MeritDumpFile => DHCPOption::MeritDumpFile,
+            raw::DHCPOption_DHCPOption_DomainName => DHCPOption::DomainName,
+            raw::DHCPOption_DHCPOption_SwapServer => DHCPOption::SwapServer,
+            raw::DHCPOption_DHCPOption_RootPath => DHCPOption::RootPath,
+            raw::DHCPOption_DHCPOption_ExtensionPath => DHCPOption::ExtensionPath,
+            raw::DHCPOption_DHCPOption_IPForwarding => DHCPOption::IPForwarding,
+            raw::DHCPOption_DHCPOption_OptNonLocalSourceRouting => {
+                DHCPOption::OptNonLocalSourceRouting
+            }
+            raw::DHCPOption_DHCPOption_PolicyFilter => DHCPOption::PolicyFilter,
+            raw::DHCPOption_DHCPOption_MaxDgramReassemblySize => DHCPOption::MaxDgramReassemblySize,
+            raw::DHCPOption_DHCPOption_DefaultIPTTL => DHCPOption::DefaultIPTTL,
+            raw::DHCPOption_DHCPOption_PathMTUAgingTimeout => DHCPOption::PathMTUAgingTimeout,
+            raw::DHCPOption_DHCPOption_PathMTUPlateauTable => DHCPOption::PathMTUPlateauTable,
+            raw::DHCPOption_DHCPOption_InterfaceMTU => DHCPOption::InterfaceMTU,
+            raw::DHCPOption_DHCPOption_AllSubnetsAreLocal => DHCPOption::AllSubnetsAreLocal,
+            raw::DHCPOption_DHCPOption_BroadcastAddress => DHCPOption::BroadcastAddress,
+            raw::DHCPOption_DHCPOption_PerformMaskDiscovery => DHCPOption::PerformMaskDiscovery,
+            raw::DHCPOption_DHCPOption_MaskSupplier => DHCPOption::MaskSupplier,
+            raw::DHCPOption_DHCPOption_PerformRouterDiscovery => DHCPOption::PerformRouterDiscovery,
+            raw::DHCPOption_DHCPOption_RouterSolicitationAddress => {
+                DHCPOption::RouterSolicitationAddress
+            }
+            raw::DHCPOption_DHCPOption_StaticRoute => DHCPOption::StaticRoute,
+            raw::DHCPOption_DHCPOption_TrailerEncapsulation => DHCPOption::TrailerEncapsulation,
+            raw::DHCPOption_DHCPOption_ARPCacheTimeout => DHCPOption::ARPCacheTimeout,
+            raw::DHCPOption_DHCPOption_EthernetEncapsulation => DHCPOption::EthernetEncapsulation,
+            raw::DHCPOption_DHCPOption_TCPDefaultTTL => DHCPOption::TCPDefaultTTL,
+            raw::DHCPOption_DHCPOption_TCPKeepaliveInterval => DHCPOption::TCPKeepaliveInterval,
+            raw::DHCPOption_DHCPOption_TCPKeepaliveGarbage => DHCPOption::TCPKeepaliveGarbage,
+            raw::DHCPOption_DHCPOption_NISDomain => DHCPOption::NISDomain,
+            raw::DHCPOption_DHCPOption_NISServers => DHCPOption::NISServers,
+            raw::DHCPOption_DHCPOption_NTPServers => DHCPOption::NTPServers,
+            raw::DHCPOption_DHCPOption_VendorSpecificInfo => DHCPOption::VendorSpecificInfo,
+            raw::DHCPOption_DHCPOption_NetBIOSNameServers => DHCPOption::NetBIOSNameServers,
+            raw::DHCPOption_DHCPOption_NetBIOSDatagramServers => DHCPOption::NetBIOSDatagramServers,
+            raw::DHCPOption_DHCPOption_NetBIOSNodeType => DHCPOption::NetBIOSNodeType,
+            raw::DHCPOption_DHCPOption_NetBIOSScope => DHCPOption::NetBIOSScope,
+            raw::DHCPOption_DHCPOption_XWindowsFontServers => DHCPOption::XWindowsFontServers,
+            raw::DHCPOption_DHCPOption_XWindowsDisplayManager => DHCPOption::XWindowsDisplayManager,
+            raw::DHCPOption_DHCPOption_NetWareIPDomainName => DHCPOption::NetWareIPDomainName,
+            raw::DHCPOption_DHCPOption_NetWareIPInformation => DHCPOption::NetWareIPInformation,
+            raw::DHCPOption_DHCPOption_NISPlusDomain => DHCPOption::NISPlusDomain,
+            raw::DHCPOption_DHCPOption_NISPlusServers => DHCPOption::NISPlusServers,
+            raw::DHCPOption_DHCPOption_TFTPServerName => DHCPOption::TFTPServerName,
+            raw::DHCPOption_DHCPOption_BootfileName => DHCPOption::BootfileName,
+            raw::DHCPOption_DHCPOption_MobileIPHomeAgents => DHCPOption::MobileIPHomeAgents,
+            raw::DHCPOption_DHCPOption_SMTPServers => DHCPOption::SMTPServers,
+            raw::DHCPOption_DHCPOption_POP3Servers => DHCPOption::POP3Servers,
+            raw::DHCPOption_DHCPOption_NNTPServers => DHCPOption::NNTPServers,
+            raw::DHCPOption_DHCPOption_WWWServers => DHCPOption::WWWServers,
+            raw::DHCPOption_DHCPOption_FingerServers => DHCPOption::FingerServers,
+            raw::DHCPOption_DHCPOption_IRCServers => DHCPOption::IRCServers,
+            raw::DHCPOption_DHCPOption_StreetTalkServers => DHCPOption::StreetTalkServers,
+            raw::DHCPOption_DHCPOption_STDAServers => DHCPOption::STDAServers,
+            raw::DHCPOption_DHCPOption_SLPDirectoryAgent => DHCPOption::SLPDirectoryAgent,
+            raw::DHCPOption_DHCPOption_SLPServiceScope => DHCPOption::SLPServiceScope,
+            raw::DHCPOption_DHCPOption_DomainSearch => DHCPOption::DomainSearch,
+            _ => {
+                error!("Unknown DHCPOption value: {}", value);
+                DHCPOption::SubnetMask
+            }
+        }
+    }
+}
+
+impl Into<u32> for DHCPOption {
+    fn into(self) -> u32 {
+        match self {
+            DHCPOption::SubnetMask => raw::DHCPOption_DHCPOption_SubnetMask,
+            DHCPOption::TimeOffset => raw::DHCPOption_DHCPOption_TimeOffset,
+            DHCPOption::Routers => raw::DHCPOption_DHCPOption_Routers,
+            DHCPOption::TimeServers => raw::DHCPOption_DHCPOption_TimeServers,
+            DHCPOption::NameServers => raw::DHCPOption_DHCPOption_NameServers,
+            DHCPOption::DomainNameServers => raw::DHCPOption_DHCPOption_DomainNameServers,
+            DHCPOption::LogServers => raw::DHCPOption_DHCPOption_LogServers,
+            DHCPOption::CookieServers => raw::DHCPOption_DHCPOption_CookieServers,
+            DHCPOption::LPRServers => raw::DHCPOption_DHCPOption_LPRServers,
+            DHCPOption::ImpressServers => raw::DHCPOption_DHCPOption_ImpressServers,
+            DHCPOption::ResourseLocationServers => {
+                raw::DHCPOption_DHCPOption_ResourseLocationServers
+            }
+            DHCPOption::HostName => raw::DHCPOption_DHCPOption_HostName,
+            DHCPOption::BootFileSize => raw::DHCPOption_DHCPOption_BootFileSize,
+            DHCPOption::MeritDumpFile => raw::DHCPOption_DHCPOption_MeritDumpFile,
+            DHCPOption::DomainName => raw::DHCPOption_DHCPOption_DomainName,
+            DHCPOption::SwapServer => raw::DHCPOption_DHCPOption_SwapServer,
+            DHCPOption::RootPath => raw::DHCPOption_DHCPOption_RootPath,
+            DHCPOption::ExtensionPath => raw::DHCPOption_DHCPOption_ExtensionPath,
+            DHCPOption::IPForwarding => raw::DHCPOption_DHCPOption_IPForwarding,
+            DHCPOption::OptNonLocalSourceRouting => {
+                raw::DHCPOption_DHCPOption_OptNonLocalSourceRouting
+            }
+            DHCPOption::PolicyFilter => raw::DHCPOption_DHCPOption_PolicyFilter,
+            DHCPOption::MaxDgramReassemblySize => raw::DHCPOption_DHCPOption_MaxDgramReassemblySize,
+            DHCPOption::DefaultIPTTL => raw::DHCPOption_DHCPOption_DefaultIPTTL,
+            DHCPOption::PathMTUAgingTimeout => raw::DHCPOption_DHCPOption_PathMTUAgingTimeout,
+            DHCPOption::PathMTUPlateauTable => raw::DHCPOption_DHCPOption_PathMTUPlateauTable,
+            DHCPOption::InterfaceMTU => raw::DHCPOption_DHCPOption_InterfaceMTU,
+            DHCPOption::AllSubnetsAreLocal => raw::DHCPOption_DHCPOption_AllSubnetsAreLocal,
+            DHCPOption::BroadcastAddress => raw::DHCPOption_DHCPOption_BroadcastAddress,
+            DHCPOption::PerformMaskDiscovery => raw::DHCPOption_DHCPOption_PerformMaskDiscovery,
+            DHCPOption::MaskSupplier => raw::DHCPOption_DHCPOption_MaskSupplier,
+            DHCPOption::PerformRouterDiscovery => raw::DHCPOption_DHCPOption_PerformRouterDiscovery,
+            DHCPOption::RouterSolicitationAddress => {
+                raw::DHCPOption_DHCPOption_RouterSolicitationAddress
+            }
+            DHCPOption::StaticRoute => raw::DHCPOption_DHCPOption_StaticRoute,
+            DHCPOption::TrailerEncapsulation => raw::DHCPOption_DHCPOption_TrailerEncapsulation,
+            DHCPOption::ARPCacheTimeout => raw::DHCPOption_DHCPOption_ARPCacheTimeout,
+            DHCPOption::EthernetEncapsulation => raw::DHCPOption_DHCPOption_EthernetEncapsulation,
+            DHCPOption::TCPDefaultTTL => raw::DHCPOption_DHCPOption_TCPDefaultTTL,
+            DHCPOption::TCPKeepaliveInterval => raw::DHCPOption_DHCPOption_TCPKeepaliveInterval,
+            DHCPOption::TCPKeepaliveGarbage => raw::DHCPOption_DHCPOption_TCPKeepaliveGarbage,
+            DHCPOption::NISDomain => raw::DHCPOption_DHCPOption_NISDomain,
+            DHCPOption::NISServers => raw::DHCPOption_DHCPOption_NISServers,
+            DHCPOption::NTPServers => raw::DHCPOption_DHCPOption_NTPServers,
+            DHCPOption::VendorSpecificInfo => raw::DHCPOption_DHCPOption_VendorSpecificInfo,
+            DHCPOption::NetBIOSNameServers => raw::DHCPOption_DHCPOption_NetBIOSNameServers,
+            DHCPOption::NetBIOSDatagramServers => raw::DHCPOption_DHCPOption_NetBIOSDatagramServers,
+            DHCPOption::NetBIOSNodeType => raw::DHCPOption_DHCPOption_NetBIOSNodeType,
+            DHCPOption::NetBIOSScope => raw::DHCPOption_DHCPOption_NetBIOSScope,
+            DHCPOption::XWindowsFontServers => raw::DHCPOption_DHCPOption_XWindowsFontServers,
+            DHCPOption::XWindowsDisplayManager => raw::DHCPOption_DHCPOption_XWindowsDisplayManager,
+            DHCPOption::NetWareIPDomainName => raw::DHCPOption_DHCPOption_NetWareIPDomainName,
+            DHCPOption::NetWareIPInformation => raw::DHCPOption_DHCPOption_NetWareIPInformation,
+            DHCPOption::NISPlusDomain => raw::DHCPOption_DHCPOption_NISPlusDomain,
+            DHCPOption::NISPlusServers => raw::DHCPOption_DHCPOption_NISPlusServers,
+            DHCPOption::TFTPServerName => raw::DHCPOption_DHCPOption_TFTPServerName,
+            DHCPOption::BootfileName => raw::DHCPOption_DHCPOption_BootfileName,
+            DHCPOption::MobileIPHomeAgents => raw::DHCPOption_DHCPOption_MobileIPHomeAgents,
+            DHCPOption::SMTPServers => raw::DHCPOption_DHCPOption_SMTPServers,
+            DHCPOption::POP3Servers => raw::DHCPOption_DHCPOption_POP3Servers,
+            DHCPOption::NNTPServers => raw::DHCPOption_DHCPOption_NNTPServers,
+            DHCPOption::WWWServers => raw::DHCPOption_DHCPOption_WWWServers,
+            DHCPOption::FingerServers => raw::DHCPOption_DHCPOption_FingerServers,
+            DHCPOption::IRCServers => raw::DHCPOption_DHCPOption_IRCServers,
+            DHCPOption::StreetTalkServers => raw::DHCPOption_DHCPOption_StreetTalkServers,
+            DHCPOption::STDAServers => raw::DHCPOption_DHCPOption_STDAServers,
+            DHCPOption::SLPDirectoryAgent => raw::DHCPOption_DHCPOption_SLPDirectoryAgent,
+            DHCPOption::SLPServiceScope => raw::DHCPOption_DHCPOption_SLPServiceScope,
+            DHCPOption::DomainSearch => raw::DHCPOption_DHCPOption_DomainSearch,
+        }
+    }
+}
+
+impl Display for DHCPOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
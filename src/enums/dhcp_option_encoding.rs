@@ -0,0 +1,40 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Value encoding of a DHCP option.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DHCPOptionEncoding {
+    /// Value is the textual representation used by the option, e.g. a list of comma separated IP addresses.
+    Normal,
+    /// Value is a series of hex bytes, optionally separated by colons, which is put into the option as is.
+    Hex,
+}
+
+impl From<u32> for DHCPOptionEncoding {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::DHCPOptionEncoding_DHCPOptionEncoding_Normal => DHCPOptionEncoding::Normal,
+            raw::DHCPOptionEncoding_DHCPOptionEncoding_Hex => DHCPOptionEncoding::Hex,
+            _ => {
+                error!("Unknown DHCPOptionEncoding value: {}", value);
+                DHCPOptionEncoding::Normal
+            }
+        }
+    }
+}
+
+impl Into<u32> for DHCPOptionEncoding {
+    fn into(self) -> u32 {
+        match self {
+            DHCPOptionEncoding::Normal => raw::DHCPOptionEncoding_DHCPOptionEncoding_Normal,
+            DHCPOptionEncoding::Hex => raw::DHCPOptionEncoding_DHCPOptionEncoding_Hex,
+        }
+    }
+}
+
+impl Display for DHCPOptionEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
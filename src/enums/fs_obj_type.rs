@@ -0,0 +1,68 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// File system object (file) types.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FsObjType {
+    /// Used either if the object has type that is not in this enum, or if the type has not yet been determined or set.
+    Unknown,
+    /// FIFO or named pipe, depending on the platform/terminology.
+    Fifo,
+    /// Character device.
+    DevChar,
+    /// Directory.
+    Directory,
+    /// Block device.
+    DevBlock,
+    /// Regular file.
+    File,
+    /// Symbolic link.
+    Symlink,
+    /// Socket.
+    Socket,
+    /// A white-out file. Found in union mounts where it is used for hiding files after deletion.
+    WhiteOut,
+}
+
+impl From<u32> for FsObjType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::FsObjType_FsObjType_Unknown => FsObjType::Unknown,
+            raw::FsObjType_FsObjType_Fifo => FsObjType::Fifo,
+            raw::FsObjType_FsObjType_DevChar => FsObjType::DevChar,
+            raw::FsObjType_FsObjType_Directory => FsObjType::Directory,
+            raw::FsObjType_FsObjType_DevBlock => FsObjType::DevBlock,
+            raw::FsObjType_FsObjType_File => FsObjType::File,
+            raw::FsObjType_FsObjType_Symlink => FsObjType::Symlink,
+            raw::FsObjType_FsObjType_Socket => FsObjType::Socket,
+            raw::FsObjType_FsObjType_WhiteOut => FsObjType::WhiteOut,
+            _ => {
+                error!("Unknown FsObjType value: {}", value);
+                FsObjType::Unknown
+            }
+        }
+    }
+}
+
+impl Into<u32> for FsObjType {
+    fn into(self) -> u32 {
+        match self {
+            FsObjType::Unknown => raw::FsObjType_FsObjType_Unknown,
+            FsObjType::Fifo => raw::FsObjType_FsObjType_Fifo,
+            FsObjType::DevChar => raw::FsObjType_FsObjType_DevChar,
+            FsObjType::Directory => raw::FsObjType_FsObjType_Directory,
+            FsObjType::DevBlock => raw::FsObjType_FsObjType_DevBlock,
+            FsObjType::File => raw::FsObjType_FsObjType_File,
+            FsObjType::Symlink => raw::FsObjType_FsObjType_Symlink,
+            FsObjType::Socket => raw::FsObjType_FsObjType_Socket,
+            FsObjType::WhiteOut => raw::FsObjType_FsObjType_WhiteOut,
+        }
+    }
+}
+
+impl Display for FsObjType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
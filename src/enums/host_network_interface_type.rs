@@ -17,6 +17,15 @@ impl From<u32> for HostNetworkInterfaceType {
     }
 }
 
+impl Into<u32> for HostNetworkInterfaceType {
+    fn into(self) -> u32 {
+        match self {
+            Self::Bridged => raw::HostNetworkInterfaceType_HostNetworkInterfaceType_Bridged,
+            Self::HostOnly => raw::HostNetworkInterfaceType_HostNetworkInterfaceType_HostOnly,
+        }
+    }
+}
+
 impl Display for HostNetworkInterfaceType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", format!("{:?}", self))
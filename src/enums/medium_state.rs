@@ -7,7 +7,7 @@ use vbox_raw::sys_lib as raw;
 use crate::Medium;
 
 /// Virtual medium state.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MediumState {
     /// Associated medium storage does not exist (either was not created yet or was deleted).
     NotCreated,
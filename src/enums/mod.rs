@@ -8,6 +8,7 @@ mod audio_codec_type;
 mod audio_controller_type;
 mod audio_device_state;
 mod audio_driver_type;
+mod auth_type;
 mod autostop_type;
 mod bandwidth_group_type;
 mod bitmap_format;
@@ -19,6 +20,10 @@ mod clone_options;
 mod cpu_architecture;
 mod cpu_property_type;
 mod device_type;
+mod dhcp_config_scope;
+mod dhcp_group_condition_type;
+mod dhcp_option;
+mod dhcp_option_encoding;
 mod directory_copy_flag;
 mod directory_remove_rec_flag;
 mod dnd_mode;
@@ -30,6 +35,7 @@ mod firmware_type;
 mod front_end_name;
 mod fs_obj_move_flag;
 mod fs_obj_rename_flag;
+mod fs_obj_type;
 mod graphics_controller_type;
 mod graphics_feature;
 mod guest_debug_io_provider;
@@ -54,6 +60,7 @@ mod medium_state;
 mod medium_type;
 mod medium_variant;
 mod mouse_button_state;
+mod nat_alias_mode;
 mod nat_protocol;
 mod network_adapter_promisc_mode_policy;
 mod network_adapter_type;
@@ -63,19 +70,31 @@ mod partition_table_type;
 mod path_style;
 mod platform_architecture;
 mod pointing_hid_type;
+mod port_mode;
 mod process_input_status;
 mod process_status;
+mod recording_audio_codec;
+mod recording_codec_deadline;
+mod recording_destination;
+mod recording_feature;
+mod recording_rate_control_mode;
+mod recording_video_codec;
+mod recording_video_scaling_mode;
 mod scope;
 mod session_sate;
 mod session_type;
 mod storage_bus;
 mod symlink_read_flag;
 mod symlink_type;
+mod uart_type;
 mod update_channel;
 mod update_severity;
 mod update_state;
 mod usb_controller_type;
 mod vbox_event_type;
+mod vfs_type;
+mod virtual_system_description_type;
+mod virtual_system_description_value_type;
 mod vm_proc_priority;
 
 pub use access_mode::AccessMode;
@@ -88,6 +107,7 @@ pub use audio_codec_type::AudioCodecType;
 pub use audio_controller_type::AudioControllerType;
 pub use audio_device_state::AudioDeviceState;
 pub use audio_driver_type::AudioDriverType;
+pub use auth_type::AuthType;
 pub use autostop_type::AutostopType;
 pub use bandwidth_group_type::BandwidthGroupType;
 pub use bitmap_format::BitmapFormat;
@@ -99,6 +119,10 @@ pub use clone_options::CloneOptions;
 pub use cpu_architecture::CPUArchitecture;
 pub use cpu_property_type::CPUPropertyType;
 pub use device_type::DeviceType;
+pub use dhcp_config_scope::DHCPConfigScope;
+pub use dhcp_group_condition_type::DHCPGroupConditionType;
+pub use dhcp_option::DHCPOption;
+pub use dhcp_option_encoding::DHCPOptionEncoding;
 pub use directory_copy_flag::DirectoryCopyFlag;
 pub use directory_remove_rec_flag::DirectoryRemoveRecFlag;
 pub use dnd_mode::DnDMode;
@@ -110,6 +134,7 @@ pub use firmware_type::FirmwareType;
 pub use front_end_name::FrontEndName;
 pub use fs_obj_move_flag::FsObjMoveFlag;
 pub use fs_obj_rename_flag::FsObjRenameFlag;
+pub use fs_obj_type::FsObjType;
 pub use graphics_controller_type::GraphicsControllerType;
 pub use graphics_feature::GraphicsFeature;
 pub use guest_debug_io_provider::GuestDebugIoProvider;
@@ -134,6 +159,7 @@ pub use medium_state::MediumState;
 pub use medium_type::MediumType;
 pub use medium_variant::MediumVariant;
 pub use mouse_button_state::MouseButtonState;
+pub use nat_alias_mode::NATAliasMode;
 pub use nat_protocol::NATProtocol;
 pub use network_adapter_promisc_mode_policy::NetworkAdapterPromiscModePolicy;
 pub use network_adapter_type::NetworkAdapterType;
@@ -143,17 +169,29 @@ pub use partition_table_type::PartitionTableType;
 pub use path_style::PathStyle;
 pub use platform_architecture::PlatformArchitecture;
 pub use pointing_hid_type::PointingHIDType;
+pub use port_mode::PortMode;
 pub use process_input_status::ProcessInputStatus;
 pub use process_status::ProcessStatus;
+pub use recording_audio_codec::RecordingAudioCodec;
+pub use recording_codec_deadline::RecordingCodecDeadline;
+pub use recording_destination::RecordingDestination;
+pub use recording_feature::RecordingFeature;
+pub use recording_rate_control_mode::RecordingRateControlMode;
+pub use recording_video_codec::RecordingVideoCodec;
+pub use recording_video_scaling_mode::RecordingVideoScalingMode;
 pub use scope::Scope;
 pub use session_sate::SessionState;
 pub use session_type::SessionType;
 pub use storage_bus::StorageBus;
 pub use symlink_read_flag::SymlinkReadFlag;
 pub use symlink_type::SymlinkType;
+pub use uart_type::UartType;
 pub use update_channel::UpdateChannel;
 pub use update_severity::UpdateSeverity;
 pub use update_state::UpdateState;
 pub use usb_controller_type::USBControllerType;
 pub use vbox_event_type::VBoxEventType;
+pub use vfs_type::VFSType;
+pub use virtual_system_description_type::VirtualSystemDescriptionType;
+pub use virtual_system_description_value_type::VirtualSystemDescriptionValueType;
 pub use vm_proc_priority::VMProcPriority;
@@ -0,0 +1,52 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Flags of the NAT engine aliasing mode. More than one flag may be set.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NATAliasMode {
+    /// Log the aliasing activity.
+    AliasLog,
+    /// Only alias connections, do not translate the addresses of the packets.
+    AliasProxyOnly,
+    /// Keep the source ports of the guest connections where possible.
+    AliasUseSamePorts,
+}
+
+impl NATAliasMode {
+    pub(crate) const ALL: [NATAliasMode; 3] = [
+        NATAliasMode::AliasLog,
+        NATAliasMode::AliasProxyOnly,
+        NATAliasMode::AliasUseSamePorts,
+    ];
+}
+
+impl From<u32> for NATAliasMode {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::NATAliasMode_NATAliasMode_AliasLog => NATAliasMode::AliasLog,
+            raw::NATAliasMode_NATAliasMode_AliasProxyOnly => NATAliasMode::AliasProxyOnly,
+            raw::NATAliasMode_NATAliasMode_AliasUseSamePorts => NATAliasMode::AliasUseSamePorts,
+            _ => {
+                error!("Unknown NATAliasMode value: {}", value);
+                NATAliasMode::AliasLog
+            }
+        }
+    }
+}
+
+impl Into<u32> for NATAliasMode {
+    fn into(self) -> u32 {
+        match self {
+            NATAliasMode::AliasLog => raw::NATAliasMode_NATAliasMode_AliasLog,
+            NATAliasMode::AliasProxyOnly => raw::NATAliasMode_NATAliasMode_AliasProxyOnly,
+            NATAliasMode::AliasUseSamePorts => raw::NATAliasMode_NATAliasMode_AliasUseSamePorts,
+        }
+    }
+}
+
+impl Display for NATAliasMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
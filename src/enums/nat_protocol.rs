@@ -2,7 +2,7 @@ use log::error;
 use std::fmt::Display;
 use vbox_raw::sys_lib as raw;
 /// Protocol definitions used with NAT port-forwarding rules.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum NATProtocol {
     /// Port-forwarding uses UDP protocol.
     UDP,
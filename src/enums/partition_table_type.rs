@@ -3,7 +3,7 @@ use std::fmt::Display;
 use vbox_raw::sys_lib as raw;
 
 /// Partition table types.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum PartitionTableType {
     MBR,
     GPT
@@ -0,0 +1,52 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// The PortMode enumeration represents possible communication modes for the virtual serial port device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PortMode {
+    /// Virtual device is not attached to any real host device.
+    Disconnected,
+    /// Virtual device is attached to a host pipe.
+    HostPipe,
+    /// Virtual device is attached to a host device.
+    HostDevice,
+    /// Virtual device is attached to a raw file.
+    RawFile,
+    /// Virtual device is attached to a TCP socket.
+    TCP,
+}
+
+impl From<u32> for PortMode {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::PortMode_PortMode_Disconnected => PortMode::Disconnected,
+            raw::PortMode_PortMode_HostPipe => PortMode::HostPipe,
+            raw::PortMode_PortMode_HostDevice => PortMode::HostDevice,
+            raw::PortMode_PortMode_RawFile => PortMode::RawFile,
+            raw::PortMode_PortMode_TCP => PortMode::TCP,
+            _ => {
+                error!("Unknown PortMode value: {}", value);
+                PortMode::Disconnected
+            }
+        }
+    }
+}
+
+impl Into<u32> for PortMode {
+    fn into(self) -> u32 {
+        match self {
+            PortMode::Disconnected => raw::PortMode_PortMode_Disconnected,
+            PortMode::HostPipe => raw::PortMode_PortMode_HostPipe,
+            PortMode::HostDevice => raw::PortMode_PortMode_HostDevice,
+            PortMode::RawFile => raw::PortMode_PortMode_RawFile,
+            PortMode::TCP => raw::PortMode_PortMode_TCP,
+        }
+    }
+}
+
+impl Display for PortMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,70 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording audio codec enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingAudioCodec {
+    /// No codec set.
+    None,
+    /// WAV format, linear PCM, uncompressed.
+    WavPCM,
+    /// MP3 audio codec.
+    MP3,
+    /// Ogg Vorbis audio codec.
+    OggVorbis,
+    /// Opus audio codec.
+    Opus,
+    /// Other codec.
+    Other,
+}
+
+impl From<u32> for RecordingAudioCodec {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::RecordingAudioCodec_RecordingAudioCodec_None => RecordingAudioCodec::None,
+            raw::RecordingAudioCodec_RecordingAudioCodec_WavPCM => RecordingAudioCodec::WavPCM,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingAudioCodec_RecordingAudioCodec_MP3 => RecordingAudioCodec::MP3,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingAudioCodec_RecordingAudioCodec_OggVorbis => {
+                RecordingAudioCodec::OggVorbis
+            }
+            raw::RecordingAudioCodec_RecordingAudioCodec_Opus => RecordingAudioCodec::Opus,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingAudioCodec_RecordingAudioCodec_Other => RecordingAudioCodec::Other,
+            _ => {
+                error!("Unknown RecordingAudioCodec value: {}", value);
+                RecordingAudioCodec::None
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingAudioCodec {
+    fn into(self) -> u32 {
+        match self {
+            RecordingAudioCodec::None => raw::RecordingAudioCodec_RecordingAudioCodec_None,
+            RecordingAudioCodec::WavPCM => raw::RecordingAudioCodec_RecordingAudioCodec_WavPCM,
+            #[cfg(not(is_v_6_1))]
+            RecordingAudioCodec::MP3 => raw::RecordingAudioCodec_RecordingAudioCodec_MP3,
+            #[cfg(not(is_v_6_1))]
+            RecordingAudioCodec::OggVorbis => {
+                raw::RecordingAudioCodec_RecordingAudioCodec_OggVorbis
+            }
+            RecordingAudioCodec::Opus => raw::RecordingAudioCodec_RecordingAudioCodec_Opus,
+            #[cfg(not(is_v_6_1))]
+            RecordingAudioCodec::Other => raw::RecordingAudioCodec_RecordingAudioCodec_Other,
+            #[cfg(is_v_6_1)]
+            RecordingAudioCodec::MP3
+            | RecordingAudioCodec::OggVorbis
+            | RecordingAudioCodec::Other => raw::RecordingAudioCodec_RecordingAudioCodec_None,
+        }
+    }
+}
+
+impl Display for RecordingAudioCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(not(is_v_6_1))]
+use log::error;
+use std::fmt::Display;
+#[cfg(not(is_v_6_1))]
+use vbox_raw::sys_lib as raw;
+
+/// Recording codec deadline, which controls the speed versus quality trade-off of the encoder.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingCodecDeadline {
+    /// Default deadline.
+    Default,
+    /// Realtime quality, often producing bigger output files.
+    Realtime,
+    /// Balance between realtime and best deadline.
+    Good,
+    /// Best quality, slowest.
+    Best,
+}
+
+#[cfg(not(is_v_6_1))]
+impl From<u32> for RecordingCodecDeadline {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::RecordingCodecDeadline_RecordingCodecDeadline_Default => {
+                RecordingCodecDeadline::Default
+            }
+            raw::RecordingCodecDeadline_RecordingCodecDeadline_Realtime => {
+                RecordingCodecDeadline::Realtime
+            }
+            raw::RecordingCodecDeadline_RecordingCodecDeadline_Good => RecordingCodecDeadline::Good,
+            raw::RecordingCodecDeadline_RecordingCodecDeadline_Best => RecordingCodecDeadline::Best,
+            _ => {
+                error!("Unknown RecordingCodecDeadline value: {}", value);
+                RecordingCodecDeadline::Default
+            }
+        }
+    }
+}
+
+#[cfg(not(is_v_6_1))]
+impl Into<u32> for RecordingCodecDeadline {
+    fn into(self) -> u32 {
+        match self {
+            RecordingCodecDeadline::Default => {
+                raw::RecordingCodecDeadline_RecordingCodecDeadline_Default
+            }
+            RecordingCodecDeadline::Realtime => {
+                raw::RecordingCodecDeadline_RecordingCodecDeadline_Realtime
+            }
+            RecordingCodecDeadline::Good => raw::RecordingCodecDeadline_RecordingCodecDeadline_Good,
+            RecordingCodecDeadline::Best => raw::RecordingCodecDeadline_RecordingCodecDeadline_Best,
+        }
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl From<u32> for RecordingCodecDeadline {
+    fn from(_value: u32) -> Self {
+        RecordingCodecDeadline::Default
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl Into<u32> for RecordingCodecDeadline {
+    fn into(self) -> u32 {
+        0
+    }
+}
+
+impl Display for RecordingCodecDeadline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
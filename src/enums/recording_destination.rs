@@ -0,0 +1,40 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording destination enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingDestination {
+    /// No destination.
+    None,
+    /// Recording to a file.
+    File,
+}
+
+impl From<u32> for RecordingDestination {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::RecordingDestination_RecordingDestination_None => RecordingDestination::None,
+            raw::RecordingDestination_RecordingDestination_File => RecordingDestination::File,
+            _ => {
+                error!("Unknown RecordingDestination value: {}", value);
+                RecordingDestination::None
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingDestination {
+    fn into(self) -> u32 {
+        match self {
+            RecordingDestination::None => raw::RecordingDestination_RecordingDestination_None,
+            RecordingDestination::File => raw::RecordingDestination_RecordingDestination_File,
+        }
+    }
+}
+
+impl Display for RecordingDestination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
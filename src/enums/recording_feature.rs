@@ -0,0 +1,44 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording features enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingFeature {
+    /// No feature set.
+    None,
+    /// Video recording.
+    Video,
+    /// Audio recording.
+    Audio,
+}
+
+impl From<u32> for RecordingFeature {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::RecordingFeature_RecordingFeature_None => RecordingFeature::None,
+            raw::RecordingFeature_RecordingFeature_Video => RecordingFeature::Video,
+            raw::RecordingFeature_RecordingFeature_Audio => RecordingFeature::Audio,
+            _ => {
+                error!("Unknown RecordingFeature value: {}", value);
+                RecordingFeature::None
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingFeature {
+    fn into(self) -> u32 {
+        match self {
+            RecordingFeature::None => raw::RecordingFeature_RecordingFeature_None,
+            RecordingFeature::Video => raw::RecordingFeature_RecordingFeature_Video,
+            RecordingFeature::Audio => raw::RecordingFeature_RecordingFeature_Audio,
+        }
+    }
+}
+
+impl Display for RecordingFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,82 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording rate control mode enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingRateControlMode {
+    /// Average bit rate (ABR).
+    ABR,
+    /// Constant bit rate (CBR).
+    CBR,
+    /// Variable bit rate (VBR).
+    VBR,
+}
+
+impl From<u32> for RecordingRateControlMode {
+    fn from(value: u32) -> Self {
+        match value {
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingRateControlMode_RecordingRateControlMode_ABR => {
+                RecordingRateControlMode::ABR
+            }
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingRateControlMode_RecordingRateControlMode_CBR => {
+                RecordingRateControlMode::CBR
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoRateControlMode_RecordingVideoRateControlMode_CBR => {
+                RecordingRateControlMode::CBR
+            }
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingRateControlMode_RecordingRateControlMode_VBR => {
+                RecordingRateControlMode::VBR
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoRateControlMode_RecordingVideoRateControlMode_VBR => {
+                RecordingRateControlMode::VBR
+            }
+            _ => {
+                error!("Unknown RecordingRateControlMode value: {}", value);
+                RecordingRateControlMode::ABR
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingRateControlMode {
+    fn into(self) -> u32 {
+        match self {
+            #[cfg(not(is_v_6_1))]
+            RecordingRateControlMode::ABR => {
+                raw::RecordingRateControlMode_RecordingRateControlMode_ABR
+            }
+            #[cfg(not(is_v_6_1))]
+            RecordingRateControlMode::CBR => {
+                raw::RecordingRateControlMode_RecordingRateControlMode_CBR
+            }
+            #[cfg(is_v_6_1)]
+            RecordingRateControlMode::CBR => {
+                raw::RecordingVideoRateControlMode_RecordingVideoRateControlMode_CBR
+            }
+            #[cfg(not(is_v_6_1))]
+            RecordingRateControlMode::VBR => {
+                raw::RecordingRateControlMode_RecordingRateControlMode_VBR
+            }
+            #[cfg(is_v_6_1)]
+            RecordingRateControlMode::VBR => {
+                raw::RecordingVideoRateControlMode_RecordingVideoRateControlMode_VBR
+            }
+            #[cfg(is_v_6_1)]
+            RecordingRateControlMode::ABR => {
+                raw::RecordingVideoRateControlMode_RecordingVideoRateControlMode_CBR
+            }
+        }
+    }
+}
+
+impl Display for RecordingRateControlMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,91 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording video codec enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingVideoCodec {
+    /// No codec set.
+    None,
+    /// MJPEG codec.
+    MJPEG,
+    /// MPEG-2 codec.
+    H262,
+    /// H.264 codec.
+    H264,
+    /// H.265 codec.
+    H265,
+    /// H.266 codec.
+    H266,
+    /// VP8 codec.
+    VP8,
+    /// VP9 codec.
+    VP9,
+    /// AV1 codec.
+    AV1,
+    /// Other codec.
+    Other,
+}
+
+impl From<u32> for RecordingVideoCodec {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::RecordingVideoCodec_RecordingVideoCodec_None => RecordingVideoCodec::None,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_MJPEG => RecordingVideoCodec::MJPEG,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_H262 => RecordingVideoCodec::H262,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_H264 => RecordingVideoCodec::H264,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_H265 => RecordingVideoCodec::H265,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_H266 => RecordingVideoCodec::H266,
+            raw::RecordingVideoCodec_RecordingVideoCodec_VP8 => RecordingVideoCodec::VP8,
+            raw::RecordingVideoCodec_RecordingVideoCodec_VP9 => RecordingVideoCodec::VP9,
+            raw::RecordingVideoCodec_RecordingVideoCodec_AV1 => RecordingVideoCodec::AV1,
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoCodec_RecordingVideoCodec_Other => RecordingVideoCodec::Other,
+            _ => {
+                error!("Unknown RecordingVideoCodec value: {}", value);
+                RecordingVideoCodec::None
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingVideoCodec {
+    fn into(self) -> u32 {
+        match self {
+            RecordingVideoCodec::None => raw::RecordingVideoCodec_RecordingVideoCodec_None,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::MJPEG => raw::RecordingVideoCodec_RecordingVideoCodec_MJPEG,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::H262 => raw::RecordingVideoCodec_RecordingVideoCodec_H262,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::H264 => raw::RecordingVideoCodec_RecordingVideoCodec_H264,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::H265 => raw::RecordingVideoCodec_RecordingVideoCodec_H265,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::H266 => raw::RecordingVideoCodec_RecordingVideoCodec_H266,
+            RecordingVideoCodec::VP8 => raw::RecordingVideoCodec_RecordingVideoCodec_VP8,
+            RecordingVideoCodec::VP9 => raw::RecordingVideoCodec_RecordingVideoCodec_VP9,
+            RecordingVideoCodec::AV1 => raw::RecordingVideoCodec_RecordingVideoCodec_AV1,
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoCodec::Other => raw::RecordingVideoCodec_RecordingVideoCodec_Other,
+            #[cfg(is_v_6_1)]
+            RecordingVideoCodec::MJPEG
+            | RecordingVideoCodec::H262
+            | RecordingVideoCodec::H264
+            | RecordingVideoCodec::H265
+            | RecordingVideoCodec::H266
+            | RecordingVideoCodec::Other => raw::RecordingVideoCodec_RecordingVideoCodec_None,
+        }
+    }
+}
+
+impl Display for RecordingVideoCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,104 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Recording video scaling mode enumeration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordingVideoScalingMode {
+    /// No scaling performed.
+    None,
+    /// Nearest neighbor.
+    NearestNeighbor,
+    /// Bilinear interpolation.
+    Bilinear,
+    /// Bicubic interpolation.
+    Bicubic,
+}
+
+impl From<u32> for RecordingVideoScalingMode {
+    fn from(value: u32) -> Self {
+        match value {
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoScalingMode_RecordingVideoScalingMode_None => {
+                RecordingVideoScalingMode::None
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_None => {
+                RecordingVideoScalingMode::None
+            }
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoScalingMode_RecordingVideoScalingMode_NearestNeighbor => {
+                RecordingVideoScalingMode::NearestNeighbor
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_NearestNeighbor => {
+                RecordingVideoScalingMode::NearestNeighbor
+            }
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoScalingMode_RecordingVideoScalingMode_Bilinear => {
+                RecordingVideoScalingMode::Bilinear
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_Bilinear => {
+                RecordingVideoScalingMode::Bilinear
+            }
+            #[cfg(not(is_v_6_1))]
+            raw::RecordingVideoScalingMode_RecordingVideoScalingMode_Bicubic => {
+                RecordingVideoScalingMode::Bicubic
+            }
+            #[cfg(is_v_6_1)]
+            raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_Bicubic => {
+                RecordingVideoScalingMode::Bicubic
+            }
+            _ => {
+                error!("Unknown RecordingVideoScalingMode value: {}", value);
+                RecordingVideoScalingMode::None
+            }
+        }
+    }
+}
+
+impl Into<u32> for RecordingVideoScalingMode {
+    fn into(self) -> u32 {
+        match self {
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoScalingMode::None => {
+                raw::RecordingVideoScalingMode_RecordingVideoScalingMode_None
+            }
+            #[cfg(is_v_6_1)]
+            RecordingVideoScalingMode::None => {
+                raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_None
+            }
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoScalingMode::NearestNeighbor => {
+                raw::RecordingVideoScalingMode_RecordingVideoScalingMode_NearestNeighbor
+            }
+            #[cfg(is_v_6_1)]
+            RecordingVideoScalingMode::NearestNeighbor => {
+                raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_NearestNeighbor
+            }
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoScalingMode::Bilinear => {
+                raw::RecordingVideoScalingMode_RecordingVideoScalingMode_Bilinear
+            }
+            #[cfg(is_v_6_1)]
+            RecordingVideoScalingMode::Bilinear => {
+                raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_Bilinear
+            }
+            #[cfg(not(is_v_6_1))]
+            RecordingVideoScalingMode::Bicubic => {
+                raw::RecordingVideoScalingMode_RecordingVideoScalingMode_Bicubic
+            }
+            #[cfg(is_v_6_1)]
+            RecordingVideoScalingMode::Bicubic => {
+                raw::RecordingVideoScalingMethod_RecordingVideoScalingMethod_Bicubic
+            }
+        }
+    }
+}
+
+impl Display for RecordingVideoScalingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
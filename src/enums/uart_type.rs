@@ -0,0 +1,44 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// The UART type represents the emulated UART chip for the serial port device.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum UartType {
+    /// The most basic emulated UART which doesn't support FIFO operation.
+    U16450,
+    /// The successor of the 16450 UART introducing a 16 byte FIFO to reduce operational overhead.
+    U16550A,
+    /// This UART developed by Texas Instruments introduced a 64 byte FIFO and hardware flow control.
+    U16750,
+}
+
+impl From<u32> for UartType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::UartType_UartType_U16450 => UartType::U16450,
+            raw::UartType_UartType_U16550A => UartType::U16550A,
+            raw::UartType_UartType_U16750 => UartType::U16750,
+            _ => {
+                error!("Unknown UartType value: {}", value);
+                UartType::U16450
+            }
+        }
+    }
+}
+
+impl Into<u32> for UartType {
+    fn into(self) -> u32 {
+        match self {
+            UartType::U16450 => raw::UartType_UartType_U16450,
+            UartType::U16550A => raw::UartType_UartType_U16550A,
+            UartType::U16750 => raw::UartType_UartType_U16750,
+        }
+    }
+}
+
+impl Display for UartType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
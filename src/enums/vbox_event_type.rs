@@ -21,13 +21,13 @@ use crate::event_detail::{
     NATNetworkPortForwardEvent, NATNetworkSettingEvent, NATNetworkStartStopEvent, NATRedirectEvent,
     NetworkAdapterChangedEvent, ParallelPortChangedEvent, ProgressCreatedEvent,
     ProgressPercentageChangedEvent, ProgressTaskCompletedEvent, RecordingChangedEvent,
-    RuntimeErrorEvent, SerialPortChangedEvent, SessionStateChangedEvent, SharedFolderChangedEvent,
-    ShowWindowEvent, SnapshotChangedEvent, SnapshotDeletedEvent, SnapshotRestoredEvent,
-    SnapshotTakenEvent, StateChangedEvent, StorageControllerChangedEvent,
-    StorageDeviceChangedEvent, USBControllerChangedEvent, USBDeviceStateChangedEvent,
-    UpdateAgentAvailableEvent, UpdateAgentErrorEvent, UpdateAgentSettingsChangedEvent,
-    UpdateAgentStateChangedEvent, VBoxSVCAvailabilityChangedEvent, VRDEServerChangedEvent,
-    VRDEServerInfoChangedEvent,
+    RecordingScreenStateChangedEvent, RecordingStateChangedEvent, RuntimeErrorEvent,
+    SerialPortChangedEvent, SessionStateChangedEvent, SharedFolderChangedEvent, ShowWindowEvent,
+    SnapshotChangedEvent, SnapshotDeletedEvent, SnapshotRestoredEvent, SnapshotTakenEvent,
+    StateChangedEvent, StorageControllerChangedEvent, StorageDeviceChangedEvent,
+    USBControllerChangedEvent, USBDeviceStateChangedEvent, UpdateAgentAvailableEvent,
+    UpdateAgentErrorEvent, UpdateAgentSettingsChangedEvent, UpdateAgentStateChangedEvent,
+    VBoxSVCAvailabilityChangedEvent, VRDEServerChangedEvent, VRDEServerInfoChangedEvent,
 };
 #[cfg(doc)]
 use crate::EventSource;
@@ -179,6 +179,9 @@ pub enum VBoxEventType {
     /// See [`GuestFileWriteEvent`].
     OnGuestFileWrite,
     /// See [`RecordingChangedEvent`].
+    /// <div class="warning">
+    /// This EventType does not exist in version 7.1, where it was replaced by [`VBoxEventType::OnRecordingStateChanged`] and [`VBoxEventType::OnRecordingScreenStateChanged`].
+    /// </div>
     OnRecordingChanged,
     /// See [`GuestUserStateChangedEvent`].
     OnGuestUserStateChanged,
@@ -290,6 +293,18 @@ pub enum VBoxEventType {
     /// To ensure compatibility with earlier versions, this field has been retained, but this type will never be returned.
     /// </div>
     OnMachineGroupsChanged,
+    /// See [`RecordingStateChangedEvent`].
+    /// <div class="warning">
+    /// This EventType only exists for versions 7.1 and above.
+    /// To ensure compatibility with earlier versions, this field has been retained, but this type will never be returned.
+    /// </div>
+    OnRecordingStateChanged,
+    /// See [`RecordingScreenStateChangedEvent`].
+    /// <div class="warning">
+    /// This EventType only exists for versions 7.1 and above.
+    /// To ensure compatibility with earlier versions, this field has been retained, but this type will never be returned.
+    /// </div>
+    OnRecordingScreenStateChanged,
     /// Must be last event, used for iterations and structures relying on numerical event values.
     /// <div class="warning">
     /// This EventType only exists for versions 7 and above.
@@ -546,6 +561,14 @@ impl From<u32> for VBoxEventType {
             raw::VBoxEventType_VBoxEventType_OnMachineGroupsChanged => {
                 VBoxEventType::OnMachineGroupsChanged
             }
+            #[cfg(is_v_7_1)]
+            raw::VBoxEventType_VBoxEventType_OnRecordingStateChanged => {
+                VBoxEventType::OnRecordingStateChanged
+            }
+            #[cfg(is_v_7_1)]
+            raw::VBoxEventType_VBoxEventType_OnRecordingScreenStateChanged => {
+                VBoxEventType::OnRecordingScreenStateChanged
+            }
             #[cfg(not(is_v_6_1))]
             raw::VBoxEventType_VBoxEventType_End => VBoxEventType::End,
             _ => VBoxEventType::Invalid,
@@ -800,6 +823,14 @@ impl Into<u32> for VBoxEventType {
             VBoxEventType::OnMachineGroupsChanged => {
                 raw::VBoxEventType_VBoxEventType_OnMachineGroupsChanged
             }
+            #[cfg(is_v_7_1)]
+            VBoxEventType::OnRecordingStateChanged => {
+                raw::VBoxEventType_VBoxEventType_OnRecordingStateChanged
+            }
+            #[cfg(is_v_7_1)]
+            VBoxEventType::OnRecordingScreenStateChanged => {
+                raw::VBoxEventType_VBoxEventType_OnRecordingScreenStateChanged
+            }
             #[cfg(not(is_v_6_1))]
             VBoxEventType::End => raw::VBoxEventType_VBoxEventType_End,
             _ => raw::VBoxEventType_VBoxEventType_Invalid,
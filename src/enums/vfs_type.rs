@@ -0,0 +1,48 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Virtual file systems supported by [`crate::VFSExplorer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VFSType {
+    /// Local file system.
+    File,
+    /// Cloud storage.
+    Cloud,
+    /// Amazon S3 compatible storage.
+    S3,
+    /// WebDAV storage.
+    WebDav,
+}
+
+impl From<u32> for VFSType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::VFSType_VFSType_File => VFSType::File,
+            raw::VFSType_VFSType_Cloud => VFSType::Cloud,
+            raw::VFSType_VFSType_S3 => VFSType::S3,
+            raw::VFSType_VFSType_WebDav => VFSType::WebDav,
+            _ => {
+                error!("Unknown VFSType value: {}", value);
+                VFSType::File
+            }
+        }
+    }
+}
+
+impl Into<u32> for VFSType {
+    fn into(self) -> u32 {
+        match self {
+            VFSType::File => raw::VFSType_VFSType_File,
+            VFSType::Cloud => raw::VFSType_VFSType_Cloud,
+            VFSType::S3 => raw::VFSType_VFSType_S3,
+            VFSType::WebDav => raw::VFSType_VFSType_WebDav,
+        }
+    }
+}
+
+impl Display for VFSType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
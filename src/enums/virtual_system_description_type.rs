@@ -0,0 +1,296 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Used with [`crate::VirtualSystemDescription`] to describe the type of a configuration value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VirtualSystemDescriptionType {
+    /// Ignore this entry.
+    Ignore,
+    /// Guest operating system type.
+    OS,
+    /// Name of the virtual system.
+    Name,
+    /// Product name.
+    Product,
+    /// Vendor name.
+    Vendor,
+    /// Product version.
+    Version,
+    /// Product URL.
+    ProductUrl,
+    /// Vendor URL.
+    VendorUrl,
+    /// Description of the virtual system.
+    Description,
+    /// License text.
+    License,
+    /// Miscellaneous settings, for example the OVF operating system type.
+    Miscellaneous,
+    /// Number of virtual CPUs.
+    CPU,
+    /// Memory size. The VirtualBox value is in megabytes, the OVF value in bytes.
+    Memory,
+    /// IDE hard disk controller.
+    HardDiskControllerIDE,
+    /// SATA hard disk controller.
+    HardDiskControllerSATA,
+    /// SCSI hard disk controller.
+    HardDiskControllerSCSI,
+    /// SAS hard disk controller.
+    HardDiskControllerSAS,
+    /// Hard disk image.
+    HardDiskImage,
+    /// Floppy drive.
+    Floppy,
+    /// CD/DVD drive.
+    CDROM,
+    /// Network adapter.
+    NetworkAdapter,
+    /// USB controller.
+    USBController,
+    /// Sound card.
+    SoundCard,
+    /// Target path of the machine settings file. Available on import only.
+    SettingsFile,
+    /// Target base folder of the machine. Available on import only.
+    BaseFolder,
+    /// Primary group of the machine.
+    PrimaryGroup,
+    /// Cloud instance shape.
+    CloudInstanceShape,
+    /// Cloud availability domain.
+    CloudDomain,
+    /// Cloud boot disk size.
+    CloudBootDiskSize,
+    /// Cloud bucket.
+    CloudBucket,
+    /// OCI virtual cloud network.
+    CloudOCIVCN,
+    /// Cloud public IP address.
+    CloudPublicIP,
+    /// Cloud profile name.
+    CloudProfileName,
+    /// OCI subnet.
+    CloudOCISubnet,
+    /// Whether to keep the cloud object.
+    CloudKeepObject,
+    /// Whether to launch the cloud instance.
+    CloudLaunchInstance,
+    /// Cloud instance ID.
+    CloudInstanceId,
+    /// Cloud image ID.
+    CloudImageId,
+    /// Cloud instance state.
+    CloudInstanceState,
+    /// Cloud image state.
+    CloudImageState,
+    /// Cloud instance display name.
+    CloudInstanceDisplayName,
+    /// Cloud image display name.
+    CloudImageDisplayName,
+    /// OCI launch mode.
+    CloudOCILaunchMode,
+    /// Cloud private IP address.
+    CloudPrivateIP,
+    /// Cloud boot volume ID.
+    CloudBootVolumeId,
+    /// OCI virtual cloud network compartment.
+    CloudOCIVCNCompartment,
+    /// OCI subnet compartment.
+    CloudOCISubnetCompartment,
+    /// Cloud public SSH key.
+    CloudPublicSSHKey,
+    /// Booting firmware.
+    BootingFirmware,
+    /// Path of the cloud-init script.
+    CloudInitScriptPath,
+    /// Cloud compartment ID.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudCompartmentId,
+    /// Number of CPUs of the cloud shape.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudShapeCpus,
+    /// Memory of the cloud shape.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudShapeMemory,
+    /// Cloud instance metadata.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudInstanceMetadata,
+    /// Cloud instance free-form tags.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudInstanceFreeFormTags,
+    /// Cloud image free-form tags.
+    /// <div class="warning">
+    ///  This type only exists for versions 7 and above.
+    /// </div>
+    CloudImageFreeFormTags,
+    /// VirtIO SCSI hard disk controller.
+    HardDiskControllerVirtioSCSI,
+    /// NVMe hard disk controller.
+    HardDiskControllerNVMe,
+}
+
+impl From<u32> for VirtualSystemDescriptionType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Ignore => VirtualSystemDescriptionType::Ignore,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_OS => VirtualSystemDescriptionType::OS,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Name => VirtualSystemDescriptionType::Name,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Product => VirtualSystemDescriptionType::Product,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Vendor => VirtualSystemDescriptionType::Vendor,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Version => VirtualSystemDescriptionType::Version,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_ProductUrl => VirtualSystemDescriptionType::ProductUrl,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_VendorUrl => VirtualSystemDescriptionType::VendorUrl,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Description => VirtualSystemDescriptionType::Description,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_License => VirtualSystemDescriptionType::License,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Miscellaneous => VirtualSystemDescriptionType::Miscellaneous,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CPU => VirtualSystemDescriptionType::CPU,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Memory => VirtualSystemDescriptionType::Memory,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerIDE => VirtualSystemDescriptionType::HardDiskControllerIDE,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSATA => VirtualSystemDescriptionType::HardDiskControllerSATA,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSCSI => VirtualSystemDescriptionType::HardDiskControllerSCSI,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSAS => VirtualSystemDescriptionType::HardDiskControllerSAS,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskImage => VirtualSystemDescriptionType::HardDiskImage,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Floppy => VirtualSystemDescriptionType::Floppy,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CDROM => VirtualSystemDescriptionType::CDROM,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_NetworkAdapter => VirtualSystemDescriptionType::NetworkAdapter,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_USBController => VirtualSystemDescriptionType::USBController,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_SoundCard => VirtualSystemDescriptionType::SoundCard,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_SettingsFile => VirtualSystemDescriptionType::SettingsFile,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_BaseFolder => VirtualSystemDescriptionType::BaseFolder,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_PrimaryGroup => VirtualSystemDescriptionType::PrimaryGroup,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceShape => VirtualSystemDescriptionType::CloudInstanceShape,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudDomain => VirtualSystemDescriptionType::CloudDomain,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBootDiskSize => VirtualSystemDescriptionType::CloudBootDiskSize,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBucket => VirtualSystemDescriptionType::CloudBucket,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCIVCN => VirtualSystemDescriptionType::CloudOCIVCN,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPublicIP => VirtualSystemDescriptionType::CloudPublicIP,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudProfileName => VirtualSystemDescriptionType::CloudProfileName,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCISubnet => VirtualSystemDescriptionType::CloudOCISubnet,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudKeepObject => VirtualSystemDescriptionType::CloudKeepObject,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudLaunchInstance => VirtualSystemDescriptionType::CloudLaunchInstance,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceId => VirtualSystemDescriptionType::CloudInstanceId,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageId => VirtualSystemDescriptionType::CloudImageId,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceState => VirtualSystemDescriptionType::CloudInstanceState,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageState => VirtualSystemDescriptionType::CloudImageState,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceDisplayName => VirtualSystemDescriptionType::CloudInstanceDisplayName,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageDisplayName => VirtualSystemDescriptionType::CloudImageDisplayName,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCILaunchMode => VirtualSystemDescriptionType::CloudOCILaunchMode,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPrivateIP => VirtualSystemDescriptionType::CloudPrivateIP,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBootVolumeId => VirtualSystemDescriptionType::CloudBootVolumeId,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCIVCNCompartment => VirtualSystemDescriptionType::CloudOCIVCNCompartment,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCISubnetCompartment => VirtualSystemDescriptionType::CloudOCISubnetCompartment,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPublicSSHKey => VirtualSystemDescriptionType::CloudPublicSSHKey,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_BootingFirmware => VirtualSystemDescriptionType::BootingFirmware,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInitScriptPath => VirtualSystemDescriptionType::CloudInitScriptPath,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudCompartmentId => VirtualSystemDescriptionType::CloudCompartmentId,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudShapeCpus => VirtualSystemDescriptionType::CloudShapeCpus,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudShapeMemory => VirtualSystemDescriptionType::CloudShapeMemory,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceMetadata => VirtualSystemDescriptionType::CloudInstanceMetadata,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceFreeFormTags => VirtualSystemDescriptionType::CloudInstanceFreeFormTags,
+            #[cfg(not(is_v_6_1))]
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageFreeFormTags => VirtualSystemDescriptionType::CloudImageFreeFormTags,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerVirtioSCSI => VirtualSystemDescriptionType::HardDiskControllerVirtioSCSI,
+            raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerNVMe => VirtualSystemDescriptionType::HardDiskControllerNVMe,
+            _ => {
+                error!("Unknown VirtualSystemDescriptionType value: {}", value);
+                VirtualSystemDescriptionType::Ignore
+            }
+        }
+    }
+}
+
+impl Into<u32> for VirtualSystemDescriptionType {
+    fn into(self) -> u32 {
+        match self {
+            VirtualSystemDescriptionType::Ignore => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Ignore,
+            VirtualSystemDescriptionType::OS => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_OS,
+            VirtualSystemDescriptionType::Name => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Name,
+            VirtualSystemDescriptionType::Product => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Product,
+            VirtualSystemDescriptionType::Vendor => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Vendor,
+            VirtualSystemDescriptionType::Version => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Version,
+            VirtualSystemDescriptionType::ProductUrl => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_ProductUrl,
+            VirtualSystemDescriptionType::VendorUrl => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_VendorUrl,
+            VirtualSystemDescriptionType::Description => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Description,
+            VirtualSystemDescriptionType::License => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_License,
+            VirtualSystemDescriptionType::Miscellaneous => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Miscellaneous,
+            VirtualSystemDescriptionType::CPU => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CPU,
+            VirtualSystemDescriptionType::Memory => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Memory,
+            VirtualSystemDescriptionType::HardDiskControllerIDE => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerIDE,
+            VirtualSystemDescriptionType::HardDiskControllerSATA => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSATA,
+            VirtualSystemDescriptionType::HardDiskControllerSCSI => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSCSI,
+            VirtualSystemDescriptionType::HardDiskControllerSAS => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerSAS,
+            VirtualSystemDescriptionType::HardDiskImage => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskImage,
+            VirtualSystemDescriptionType::Floppy => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Floppy,
+            VirtualSystemDescriptionType::CDROM => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CDROM,
+            VirtualSystemDescriptionType::NetworkAdapter => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_NetworkAdapter,
+            VirtualSystemDescriptionType::USBController => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_USBController,
+            VirtualSystemDescriptionType::SoundCard => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_SoundCard,
+            VirtualSystemDescriptionType::SettingsFile => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_SettingsFile,
+            VirtualSystemDescriptionType::BaseFolder => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_BaseFolder,
+            VirtualSystemDescriptionType::PrimaryGroup => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_PrimaryGroup,
+            VirtualSystemDescriptionType::CloudInstanceShape => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceShape,
+            VirtualSystemDescriptionType::CloudDomain => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudDomain,
+            VirtualSystemDescriptionType::CloudBootDiskSize => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBootDiskSize,
+            VirtualSystemDescriptionType::CloudBucket => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBucket,
+            VirtualSystemDescriptionType::CloudOCIVCN => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCIVCN,
+            VirtualSystemDescriptionType::CloudPublicIP => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPublicIP,
+            VirtualSystemDescriptionType::CloudProfileName => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudProfileName,
+            VirtualSystemDescriptionType::CloudOCISubnet => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCISubnet,
+            VirtualSystemDescriptionType::CloudKeepObject => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudKeepObject,
+            VirtualSystemDescriptionType::CloudLaunchInstance => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudLaunchInstance,
+            VirtualSystemDescriptionType::CloudInstanceId => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceId,
+            VirtualSystemDescriptionType::CloudImageId => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageId,
+            VirtualSystemDescriptionType::CloudInstanceState => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceState,
+            VirtualSystemDescriptionType::CloudImageState => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageState,
+            VirtualSystemDescriptionType::CloudInstanceDisplayName => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceDisplayName,
+            VirtualSystemDescriptionType::CloudImageDisplayName => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageDisplayName,
+            VirtualSystemDescriptionType::CloudOCILaunchMode => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCILaunchMode,
+            VirtualSystemDescriptionType::CloudPrivateIP => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPrivateIP,
+            VirtualSystemDescriptionType::CloudBootVolumeId => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudBootVolumeId,
+            VirtualSystemDescriptionType::CloudOCIVCNCompartment => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCIVCNCompartment,
+            VirtualSystemDescriptionType::CloudOCISubnetCompartment => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudOCISubnetCompartment,
+            VirtualSystemDescriptionType::CloudPublicSSHKey => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudPublicSSHKey,
+            VirtualSystemDescriptionType::BootingFirmware => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_BootingFirmware,
+            VirtualSystemDescriptionType::CloudInitScriptPath => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInitScriptPath,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudCompartmentId => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudCompartmentId,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudShapeCpus => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudShapeCpus,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudShapeMemory => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudShapeMemory,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudInstanceMetadata => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceMetadata,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudInstanceFreeFormTags => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudInstanceFreeFormTags,
+            #[cfg(not(is_v_6_1))]
+            VirtualSystemDescriptionType::CloudImageFreeFormTags => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_CloudImageFreeFormTags,
+            VirtualSystemDescriptionType::HardDiskControllerVirtioSCSI => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerVirtioSCSI,
+            VirtualSystemDescriptionType::HardDiskControllerNVMe => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_HardDiskControllerNVMe,
+            #[cfg(is_v_6_1)]
+            VirtualSystemDescriptionType::CloudCompartmentId | VirtualSystemDescriptionType::CloudShapeCpus | VirtualSystemDescriptionType::CloudShapeMemory | VirtualSystemDescriptionType::CloudInstanceMetadata | VirtualSystemDescriptionType::CloudInstanceFreeFormTags | VirtualSystemDescriptionType::CloudImageFreeFormTags => raw::VirtualSystemDescriptionType_VirtualSystemDescriptionType_Ignore,
+        }
+    }
+}
+
+impl Display for VirtualSystemDescriptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
@@ -0,0 +1,56 @@
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib as raw;
+
+/// Used with [`crate::VirtualSystemDescription::get_values_by_type`] to describe the value type to fetch.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VirtualSystemDescriptionValueType {
+    /// Reference value.
+    Reference,
+    /// Original value from the OVF file.
+    Original,
+    /// Value suggested by VirtualBox.
+    Auto,
+    /// Extra configuration value.
+    ExtraConfig,
+}
+
+impl From<u32> for VirtualSystemDescriptionValueType {
+    fn from(value: u32) -> Self {
+        match value {
+            raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Reference => VirtualSystemDescriptionValueType::Reference,
+            raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Original => VirtualSystemDescriptionValueType::Original,
+            raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Auto => VirtualSystemDescriptionValueType::Auto,
+            raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_ExtraConfig => VirtualSystemDescriptionValueType::ExtraConfig,
+            _ => {
+                error!("Unknown VirtualSystemDescriptionValueType value: {}", value);
+                VirtualSystemDescriptionValueType::Reference
+            }
+        }
+    }
+}
+
+impl Into<u32> for VirtualSystemDescriptionValueType {
+    fn into(self) -> u32 {
+        match self {
+            VirtualSystemDescriptionValueType::Reference => {
+                raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Reference
+            }
+            VirtualSystemDescriptionValueType::Original => {
+                raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Original
+            }
+            VirtualSystemDescriptionValueType::Auto => {
+                raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_Auto
+            }
+            VirtualSystemDescriptionValueType::ExtraConfig => {
+                raw::VirtualSystemDescriptionValueType_VirtualSystemDescriptionValueType_ExtraConfig
+            }
+        }
+    }
+}
+
+impl Display for VirtualSystemDescriptionValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self))
+    }
+}
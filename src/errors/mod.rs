@@ -95,6 +95,38 @@ impl VboxError {
             msg: "".to_string(),
         }
     }
+    pub fn io_error(fn_name: &str, err: std::io::Error) -> Self {
+        Self {
+            error_type: VboxErrorType::IoError,
+            code: 0,
+            fn_name: fn_name.to_string(),
+            msg: err.to_string(),
+        }
+    }
+    pub fn invalid_data(fn_name: &str, msg: String) -> Self {
+        Self {
+            error_type: VboxErrorType::InvalidData,
+            code: 0,
+            fn_name: fn_name.to_string(),
+            msg,
+        }
+    }
+    pub fn timeout(fn_name: &str, msg: String) -> Self {
+        Self {
+            error_type: VboxErrorType::VBOX_E_TIMEOUT,
+            code: 0,
+            fn_name: fn_name.to_string(),
+            msg,
+        }
+    }
+    pub fn license_not_accepted(fn_name: &str, msg: String) -> Self {
+        Self {
+            error_type: VboxErrorType::LicenseNotAccepted,
+            code: 0,
+            fn_name: fn_name.to_string(),
+            msg,
+        }
+    }
     pub fn is_null(&self) -> bool {
         self.error_type == VboxErrorType::NullPointerError
     }
@@ -105,3 +137,16 @@ impl Display for VboxError {
         write!(f, "{}", format!("{:?}", self))
     }
 }
+
+impl std::error::Error for VboxError {}
+
+impl From<VboxError> for std::io::Error {
+    fn from(err: VboxError) -> Self {
+        let kind = match err.error_type {
+            VboxErrorType::InvalidData => std::io::ErrorKind::InvalidData,
+            VboxErrorType::VBOX_E_TIMEOUT => std::io::ErrorKind::TimedOut,
+            _ => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, err)
+    }
+}
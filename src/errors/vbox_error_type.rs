@@ -20,6 +20,12 @@ pub enum VboxErrorType {
     },
     /// An internal error occurs when vectors have different lengths, which is required for consistent processing.
     VectorsLengthMismatch,
+    /// An I/O error occurred while accessing a host file, device or socket.
+    IoError,
+    /// Data read from a host file or stream is malformed or not supported.
+    InvalidData,
+    /// The license of an extension pack was not accepted before installing it.
+    LicenseNotAccepted,
     /// A drag and drop error has occurred.
     VBOX_E_DND_ERROR,
     /// Returned when an unexpected error occurs
@@ -68,6 +68,8 @@ mod progress_created_event;
 mod progress_percentage_changed_event;
 mod progress_task_completed_event;
 mod recording_changed_event;
+mod recording_screen_state_changed_event;
+mod recording_state_changed_event;
 mod runtime_error_event;
 mod serial_port_changed_event;
 mod shared_folder_changed_event;
@@ -158,6 +160,8 @@ pub use progress_created_event::ProgressCreatedEvent;
 pub use progress_percentage_changed_event::ProgressPercentageChangedEvent;
 pub use progress_task_completed_event::ProgressTaskCompletedEvent;
 pub use recording_changed_event::RecordingChangedEvent;
+pub use recording_screen_state_changed_event::RecordingScreenStateChangedEvent;
+pub use recording_state_changed_event::RecordingStateChangedEvent;
 pub use runtime_error_event::RuntimeErrorEvent;
 pub use serial_port_changed_event::SerialPortChangedEvent;
 pub use session_state_changed_event::SessionStateChangedEvent;
@@ -180,7 +184,7 @@ pub use vbox_svc_availability_changed_event::VBoxSVCAvailabilityChangedEvent;
 pub use vrde_server_changed_event::VRDEServerChangedEvent;
 pub use vrde_server_info_changed_event::VRDEServerInfoChangedEvent;
 
-mod utility;
+pub(crate) mod utility;
 
 pub(crate) fn create_event_detail(event: &&Event) -> DetailEvent {
     let event_type = event.get_type();
@@ -259,6 +263,10 @@ pub(crate) fn create_event_detail(event: &&Event) -> DetailEvent {
         VBoxEventType::OnGuestFileRead => GuestFileReadEvent::new(event.object),
         VBoxEventType::OnGuestFileWrite => GuestFileWriteEvent::new(event.object),
         VBoxEventType::OnRecordingChanged => RecordingChangedEvent::new(event.object),
+        VBoxEventType::OnRecordingStateChanged => RecordingStateChangedEvent::new(event.object),
+        VBoxEventType::OnRecordingScreenStateChanged => {
+            RecordingScreenStateChangedEvent::new(event.object)
+        }
         VBoxEventType::OnGuestUserStateChanged => GuestUserStateChangedEvent::new(event.object),
         VBoxEventType::OnGuestMultiTouch => GuestMultiTouchEvent::new(event.object),
         VBoxEventType::OnHostNameResolutionConfigurationChange => {
@@ -393,6 +401,8 @@ pub enum DetailEvent {
     MachineGroupsChangedEvent(MachineGroupsChangedEvent),
     HostAudioDeviceChangedEvent(HostAudioDeviceChangedEvent),
     GuestDebugControlChangedEvent(GuestDebugControlChangedEvent),
+    RecordingStateChangedEvent(RecordingStateChangedEvent),
+    RecordingScreenStateChangedEvent(RecordingScreenStateChangedEvent),
     Null,
 }
 
@@ -489,6 +499,8 @@ impl Display for DetailEvent {
             DetailEvent::MachineGroupsChangedEvent(event) => write!(f, "{}", event),
             DetailEvent::HostAudioDeviceChangedEvent(event) => write!(f, "{}", event),
             DetailEvent::GuestDebugControlChangedEvent(event) => write!(f, "{}", event),
+            DetailEvent::RecordingStateChangedEvent(event) => write!(f, "{}", event),
+            DetailEvent::RecordingScreenStateChangedEvent(event) => write!(f, "{}", event),
         }
     }
 }
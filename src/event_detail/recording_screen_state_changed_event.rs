@@ -0,0 +1,89 @@
+#[cfg(is_v_7_1)]
+use crate::event_detail::utility::cast_event;
+use crate::event_detail::DetailEvent;
+#[cfg(is_v_7_1)]
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_pointer,
+};
+#[cfg(is_v_7_1)]
+use crate::VboxError;
+use crate::VirtualBoxErrorInfo;
+#[cfg(is_v_7_1)]
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib::IEvent;
+#[cfg(is_v_7_1)]
+use vbox_raw::sys_lib::{
+    IRecordingScreenStateChangedEvent, IVirtualBoxErrorInfo,
+    IRECORDINGSCREENSTATECHANGEDEVENT_IID_STR,
+};
+
+/// Notification when recording of a single screen of the virtual machine was started or stopped.
+#[derive(Debug)]
+pub struct RecordingScreenStateChangedEvent {
+    /// Whether recording of the screen is enabled or not.
+    pub enabled: bool,
+    /// None on success or an error message object on failure.
+    pub error: Option<VirtualBoxErrorInfo>,
+    /// ID of the screen the event relates to.
+    pub screen: u32,
+}
+
+#[cfg(is_v_7_1)]
+impl RecordingScreenStateChangedEvent {
+    pub fn new(object: *mut IEvent) -> DetailEvent {
+        match Self::create(object) {
+            Ok(detail) => DetailEvent::RecordingScreenStateChangedEvent(detail),
+            Err(err) => {
+                error!("RecordingScreenStateChangedEvent error:{}", err);
+                DetailEvent::Null
+            }
+        }
+    }
+    fn create(object: *mut IEvent) -> Result<Self, VboxError> {
+        let obj1 = cast_event(object, IRECORDINGSCREENSTATECHANGEDEVENT_IID_STR)?;
+        let enabled = Self::get_enabled(obj1)?;
+        let error = Self::get_error(obj1)?;
+        let screen = Self::get_screen(obj1)?;
+        Ok(Self {
+            enabled,
+            error,
+            screen,
+        })
+    }
+
+    fn get_enabled(new_obj: *mut IRecordingScreenStateChangedEvent) -> Result<bool, VboxError> {
+        get_function_result_bool!(new_obj, GetEnabled)
+    }
+    fn get_error(
+        new_obj: *mut IRecordingScreenStateChangedEvent,
+    ) -> Result<Option<VirtualBoxErrorInfo>, VboxError> {
+        let error = get_function_result_pointer!(new_obj, GetError, *mut IVirtualBoxErrorInfo);
+        if let Err(ref error) = error {
+            if error.is_null() {
+                return Ok(None);
+            }
+        }
+        Ok(Some(VirtualBoxErrorInfo::new(error?)))
+    }
+    fn get_screen(new_obj: *mut IRecordingScreenStateChangedEvent) -> Result<u32, VboxError> {
+        get_function_result_number!(new_obj, GetScreen, u32)
+    }
+}
+
+#[cfg(not(is_v_7_1))]
+impl RecordingScreenStateChangedEvent {
+    pub fn new(_object: *mut IEvent) -> DetailEvent {
+        DetailEvent::Null
+    }
+}
+impl Display for RecordingScreenStateChangedEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("{:?}", self);
+        let err = match &self.error {
+            None => "".to_string(),
+            Some(error) => error.to_string(),
+        };
+        write!(f, "{}", format!("{} error:  {}", s, err))
+    }
+}
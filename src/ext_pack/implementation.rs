@@ -0,0 +1,321 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{ExtPack, VboxError};
+
+impl ExtPack {
+    /// The extension pack name. This is unique.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let name = ext_pack.get_name().unwrap();
+    pub fn get_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetName)
+    }
+
+    /// The extension pack description.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let description = ext_pack.get_description().unwrap();
+    pub fn get_description(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDescription)
+    }
+
+    /// The extension pack version string.
+    ///
+    /// This is restricted to the dotted version number and optionally a build indicator. No tree revision or tag will be included in the string as those things are available as separate properties. An optional publisher tag may be present like for [`crate::VirtualBox::get_version`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let version = ext_pack.get_version().unwrap();
+    pub fn get_version(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetVersion)
+    }
+
+    /// The extension pack internal revision number.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let revision = ext_pack.get_revision().unwrap();
+    pub fn get_revision(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetRevision, u32)
+    }
+
+    /// Edition indicator. This is usually empty.
+    ///
+    /// Can for instance be used to help distinguishing between two editions of the same extension pack where only the license, service contract or something differs.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let edition = ext_pack.get_edition().unwrap();
+    pub fn get_edition(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetEdition)
+    }
+
+    /// The name of the VRDE module if the extension pack sports one.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let vrde_module = ext_pack.get_vrde_module().unwrap();
+    pub fn get_vrde_module(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetVRDEModule)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// The name of the cryptographic module if the extension pack sports one.
+    ///
+    /// This module is used for the VM encryption.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let crypto_module = ext_pack.get_crypto_module().unwrap();
+    pub fn get_crypto_module(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetCryptoModule)
+    }
+
+    /// Indicates whether the extension pack is usable or not.
+    ///
+    /// There are a number of reasons why an extension pack might be unusable, a typical example would be broken installation/file or that it is incompatible with the current VirtualBox version.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let usable = ext_pack.get_usable().unwrap();
+    pub fn get_usable(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetUsable)
+    }
+
+    /// String indicating why the extension pack is not usable. This is an empty string if usable and always a non-empty string if not usable.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let why_unusable = ext_pack.get_why_unusable().unwrap();
+    pub fn get_why_unusable(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetWhyUnusable)
+    }
+
+    /// Whether to show the license before installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let show_license = ext_pack.get_show_license().unwrap();
+    pub fn get_show_license(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetShowLicense)
+    }
+
+    /// The default HTML license text for the extension pack.
+    ///
+    /// Same as calling `query_license("", "", "html")`.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let license = ext_pack.get_license().unwrap();
+    pub fn get_license(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLicense)
+    }
+
+    /// Full feature version of the license attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_locale` - &str. The preferred license locale. Pass an empty string to get the default license.
+    /// * `preferred_language` - &str. The preferred license language. Pass an empty string to get the default language for the locale.
+    /// * `format` - &str. The license format: `html`, `rtf` or `txt`. If a license is present there will always be an HTML of it, the rich text format (RTF) and plain text (txt) versions are optional.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    /// let license = ext_pack.query_license("", "", "txt").unwrap();
+    pub fn query_license(
+        &self,
+        preferred_locale: &str,
+        preferred_language: &str,
+        format: &str,
+    ) -> Result<&'static str, VboxError> {
+        let preferred_locale = string_to_c_u64_str(preferred_locale)?;
+        let preferred_language = string_to_c_u64_str(preferred_language)?;
+        let format = string_to_c_u64_str(format)?;
+        get_function_result_str!(
+            self.object,
+            QueryLicense,
+            preferred_locale,
+            preferred_language,
+            format
+        )
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl ExtPack {
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_crypto_module(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "ExtPack::get_crypto_module",
+            "v7_0",
+        ))
+    }
+}
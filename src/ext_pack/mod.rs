@@ -0,0 +1,41 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IExtPack;
+
+/// Interface for querying information about an extension pack as well as accessing COM objects within it.
+///
+/// Installed extension packs are returned by [`crate::ExtPackManager::get_installed_ext_packs`] and [`crate::ExtPackManager::find`].
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_ext_pack.html](https://www.virtualbox.org/sdkref/interface_i_ext_pack.html)
+#[derive(Debug)]
+pub struct ExtPack {
+    object: *mut IExtPack,
+}
+
+impl ExtPack {
+    pub(crate) fn new(object: *mut IExtPack) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for ExtPack {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("ExtPack refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop ExtPack. Error: {:?}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,489 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_pointer,
+    get_function_result_str,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{ExtPackFile, Progress, VboxError};
+use sha2::{Digest, Sha256};
+use vbox_raw::sys_lib::IProgress;
+
+impl ExtPackFile {
+    /// The extension pack name. This is unique.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let name = ext_pack_file.get_name().unwrap();
+    pub fn get_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetName)
+    }
+
+    /// The extension pack description.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let description = ext_pack_file.get_description().unwrap();
+    pub fn get_description(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDescription)
+    }
+
+    /// The extension pack version string.
+    ///
+    /// This is restricted to the dotted version number and optionally a build indicator. No tree revision or tag will be included in the string as those things are available as separate properties. An optional publisher tag may be present like for [`crate::VirtualBox::get_version`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let version = ext_pack_file.get_version().unwrap();
+    pub fn get_version(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetVersion)
+    }
+
+    /// The extension pack internal revision number.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let revision = ext_pack_file.get_revision().unwrap();
+    pub fn get_revision(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetRevision, u32)
+    }
+
+    /// Edition indicator. This is usually empty.
+    ///
+    /// Can for instance be used to help distinguishing between two editions of the same extension pack where only the license, service contract or something differs.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let edition = ext_pack_file.get_edition().unwrap();
+    pub fn get_edition(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetEdition)
+    }
+
+    /// The name of the VRDE module if the extension pack sports one.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let vrde_module = ext_pack_file.get_vrde_module().unwrap();
+    pub fn get_vrde_module(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetVRDEModule)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// The name of the cryptographic module if the extension pack sports one.
+    ///
+    /// This module is used for the VM encryption.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let crypto_module = ext_pack_file.get_crypto_module().unwrap();
+    pub fn get_crypto_module(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetCryptoModule)
+    }
+
+    /// Indicates whether the extension pack is usable or not.
+    ///
+    /// There are a number of reasons why an extension pack might be unusable, a typical example would be broken installation/file or that it is incompatible with the current VirtualBox version.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let usable = ext_pack_file.get_usable().unwrap();
+    pub fn get_usable(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetUsable)
+    }
+
+    /// String indicating why the extension pack is not usable. This is an empty string if usable and always a non-empty string if not usable.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let why_unusable = ext_pack_file.get_why_unusable().unwrap();
+    pub fn get_why_unusable(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetWhyUnusable)
+    }
+
+    /// Whether to show the license before installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let show_license = ext_pack_file.get_show_license().unwrap();
+    pub fn get_show_license(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetShowLicense)
+    }
+
+    /// The default HTML license text for the extension pack.
+    ///
+    /// Same as calling `query_license("", "", "html")`.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let license = ext_pack_file.get_license().unwrap();
+    pub fn get_license(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLicense)
+    }
+
+    /// The path to the extension pack file.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let file_path = ext_pack_file.get_file_path().unwrap();
+    pub fn get_file_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetFilePath)
+    }
+
+    /// Full feature version of the license attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_locale` - &str. The preferred license locale. Pass an empty string to get the default license.
+    /// * `preferred_language` - &str. The preferred license language. Pass an empty string to get the default language for the locale.
+    /// * `format` - &str. The license format: `html`, `rtf` or `txt`. If a license is present there will always be an HTML of it, the rich text format (RTF) and plain text (txt) versions are optional.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let license = ext_pack_file.query_license("", "", "txt").unwrap();
+    pub fn query_license(
+        &self,
+        preferred_locale: &str,
+        preferred_language: &str,
+        format: &str,
+    ) -> Result<&'static str, VboxError> {
+        let preferred_locale = string_to_c_u64_str(preferred_locale)?;
+        let preferred_language = string_to_c_u64_str(preferred_language)?;
+        let format = string_to_c_u64_str(format)?;
+        get_function_result_str!(
+            self.object,
+            QueryLicense,
+            preferred_locale,
+            preferred_language,
+            format
+        )
+    }
+
+    /// SHA-256 digest of the plain text license, as a lowercase hex string.
+    ///
+    /// This is the value `VBoxManage extpack install --accept-license` expects.
+    ///
+    /// # Returns
+    ///
+    /// Returns String on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let license_hash = ext_pack_file.get_license_hash().unwrap();
+    pub fn get_license_hash(&self) -> Result<String, VboxError> {
+        let license = self.query_license("", "", "txt")?;
+        Ok(license_hash(license))
+    }
+
+    /// Install the extension pack.
+    ///
+    /// The license is not checked, use [`ExtPackFile::install_accepting_license`] to install only after the license was accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `replace` - bool. Set this to automatically uninstall any existing extension pack with the same name as the one being installed.
+    /// * `display_info` - &str. Platform specific display information. Reserved for future hacks.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let progress = ext_pack_file.install(true, "").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn install(&self, replace: bool, display_info: &str) -> Result<Progress, VboxError> {
+        let replace = if replace { 1 } else { 0 };
+        let display_info = string_to_c_u64_str(display_info)?;
+        let progress = get_function_result_pointer!(
+            self.object,
+            Install,
+            *mut IProgress,
+            replace,
+            display_info
+        )?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Install the extension pack after checking that its license was accepted.
+    ///
+    /// If the extension pack requires the license to be shown, `accepted_license_hash` must be equal to [`ExtPackFile::get_license_hash`], compared case insensitively. Otherwise the extension pack is not installed.
+    ///
+    /// # Arguments
+    ///
+    /// * `accepted_license_hash` - &str. SHA-256 digest of the accepted plain text license.
+    /// * `replace` - bool. Set this to automatically uninstall any existing extension pack with the same name as the one being installed.
+    /// * `display_info` - &str. Platform specific display information. Reserved for future hacks.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    /// let progress = ext_pack_file
+    ///     .install_accepting_license(
+    ///         "eb31505e56e9b4d0fbca139104da41ac6f6b98f8e78968bdf01b1f3da3c4f9ae",
+    ///         true,
+    ///         "",
+    ///     )
+    ///     .unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn install_accepting_license(
+        &self,
+        accepted_license_hash: &str,
+        replace: bool,
+        display_info: &str,
+    ) -> Result<Progress, VboxError> {
+        if self.get_show_license()? {
+            let license_hash = self.get_license_hash()?;
+            if !license_hash.eq_ignore_ascii_case(accepted_license_hash.trim()) {
+                return Err(VboxError::license_not_accepted(
+                    "ExtPackFile::install_accepting_license",
+                    format!(
+                        "License of {} was not accepted, expected hash: {}",
+                        self.get_name()?,
+                        license_hash
+                    ),
+                ));
+            }
+        }
+        self.install(replace, display_info)
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl ExtPackFile {
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_crypto_module(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "ExtPackFile::get_crypto_module",
+            "v7_0",
+        ))
+    }
+}
+
+fn license_hash(license: &str) -> String {
+    Sha256::digest(license.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::license_hash;
+
+    #[test]
+    fn license_hash_is_lowercase_sha256() {
+        assert_eq!(
+            license_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            license_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IExtPackFile;
+
+/// Extension pack file (aka tarball, .vbox-extpack) representation returned by [`crate::ExtPackManager::open_ext_pack_file`].
+///
+/// This provides the base extension pack information with the addition of the file name.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_ext_pack_file.html](https://www.virtualbox.org/sdkref/interface_i_ext_pack_file.html)
+#[derive(Debug)]
+pub struct ExtPackFile {
+    object: *mut IExtPackFile,
+}
+
+impl ExtPackFile {
+    pub(crate) fn new(object: *mut IExtPackFile) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for ExtPackFile {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("ExtPackFile refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop ExtPackFile. Error: {:?}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{ExtPack, ExtPackFile, ExtPackManager, Progress, VboxError};
+use vbox_raw::sys_lib::{IExtPack, IExtPackFile, IProgress};
+
+impl ExtPackManager {
+    /// List of the installed extension packs.
+    ///
+    /// # Returns
+    ///
+    /// Returns Vec<[`ExtPack`]> on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_packs = ext_pack_manager.get_installed_ext_packs().unwrap();
+    /// for ext_pack in ext_packs {
+    ///     println!("{} {}", ext_pack.get_name().unwrap(), ext_pack.get_version().unwrap());
+    /// }
+    pub fn get_installed_ext_packs(&self) -> Result<Vec<ExtPack>, VboxError> {
+        let ext_packs =
+            get_function_result_pointer_vec!(self.object, GetInstalledExtPacks, *mut IExtPack)?;
+        Ok(ext_packs.into_iter().map(ExtPack::new).collect())
+    }
+
+    /// Returns the extension pack with the specified name if found.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The name of the extension pack to locate.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`ExtPack`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack = ext_pack_manager
+    ///     .find("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    pub fn find(&self, name: &str) -> Result<ExtPack, VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        let ext_pack = get_function_result_pointer!(self.object, Find, *mut IExtPack, name)?;
+        Ok(ExtPack::new(ext_pack))
+    }
+
+    /// Attempts to open an extension pack file in preparation for installation.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. The path of the extension pack tarball. This can optionally be followed by a "::SHA-256=hex-digit" of the tarball.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`ExtPackFile`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let ext_pack_file = ext_pack_manager
+    ///     .open_ext_pack_file("/tmp/Oracle_VirtualBox_Extension_Pack-7.1.4.vbox-extpack")
+    ///     .unwrap();
+    pub fn open_ext_pack_file(&self, path: &str) -> Result<ExtPackFile, VboxError> {
+        let path = string_to_c_u64_str(path)?;
+        let ext_pack_file =
+            get_function_result_pointer!(self.object, OpenExtPackFile, *mut IExtPackFile, path)?;
+        Ok(ExtPackFile::new(ext_pack_file))
+    }
+
+    /// Uninstalls an extension pack, removing all related files.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The name of the extension pack to uninstall.
+    /// * `forced_removal` - bool. Forced removal of the extension pack. This means that the uninstall hook will not be called.
+    /// * `display_info` - &str. Platform specific display information. Reserved for future hacks.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let progress = ext_pack_manager
+    ///     .uninstall("Oracle VirtualBox Extension Pack", false, "")
+    ///     .unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn uninstall(
+        &self,
+        name: &str,
+        forced_removal: bool,
+        display_info: &str,
+    ) -> Result<Progress, VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        let forced_removal = if forced_removal { 1 } else { 0 };
+        let display_info = string_to_c_u64_str(display_info)?;
+        let progress = get_function_result_pointer!(
+            self.object,
+            Uninstall,
+            *mut IProgress,
+            name,
+            forced_removal,
+            display_info
+        )?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Cleans up failed installs and uninstalls
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// ext_pack_manager.cleanup().unwrap();
+    pub fn cleanup(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Cleanup)
+    }
+
+    /// Gets the path to all the plug-in modules for a given frontend.
+    ///
+    /// This is a convenience method that is intended to simplify the plug-in loading process for a frontend.
+    ///
+    /// # Arguments
+    ///
+    /// * `frontend_name` - &str. The name of the frontend or component.
+    ///
+    /// # Returns
+    ///
+    /// Returns Vec<&str> on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let plug_in_modules = ext_pack_manager
+    ///     .query_all_plug_ins_for_frontend("VirtualBox")
+    ///     .unwrap();
+    pub fn query_all_plug_ins_for_frontend(
+        &self,
+        frontend_name: &str,
+    ) -> Result<Vec<&'static str>, VboxError> {
+        let frontend_name = string_to_c_u64_str(frontend_name)?;
+        get_function_result_str_vec!(self.object, QueryAllPlugInsForFrontend, frontend_name)
+    }
+
+    /// Check if the given extension pack is loaded and usable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The name of the extension pack to check for.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let ext_pack_manager = vbox.get_extension_pack_manager().unwrap();
+    /// let usable = ext_pack_manager
+    ///     .is_ext_pack_usable("Oracle VirtualBox Extension Pack")
+    ///     .unwrap();
+    pub fn is_ext_pack_usable(&self, name: &str) -> Result<bool, VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        get_function_result_bool!(self.object, IsExtPackUsable, name)
+    }
+}
@@ -1,9 +1,12 @@
+mod implementation;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IExtPackManager;
 
 /// Interface for managing VirtualBox Extension Packs.
+///
 /// **Reference to the official documentation:**
 ///
 /// [https://www.virtualbox.org/sdkref/interface_i_ext_pack_manager.html](https://www.virtualbox.org/sdkref/interface_i_ext_pack_manager.html)
@@ -1,8 +1,11 @@
+use crate::enums::HostNetworkInterfaceType;
 use crate::utility::macros::macros::{
-    get_function_result_number, get_function_result_pointer, get_function_result_str,
+    get_function_result_number, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_str,
 };
-use crate::{Host, HostNetworkInterface, VboxError};
-use vbox_raw::sys_lib::IHostNetworkInterface;
+use crate::utility::string_to_c_u64_str;
+use crate::{Host, HostNetworkInterface, Progress, VboxError};
+use vbox_raw::sys_lib::{IHostNetworkInterface, IProgress};
 
 impl Host {
     /// Number of (logical) CPUs installed in the host system.
@@ -253,4 +256,179 @@ impl Host {
     pub fn get_os_version(&self) -> Result<&'static str, VboxError> {
         get_function_result_str!(self.object, GetOSVersion)
     }
+
+    /// Creates a new adapter for Host Only Networking.
+    ///
+    /// # Returns
+    ///
+    /// Returns (host_interface, progress), or a [`VboxError`] on failure.
+    /// - host_interface: Created host interface object.
+    /// - progress: Progress object to track the operation completion.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let (network_interface, progress) = host.create_host_only_network_interface().unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// let name = network_interface.get_name().unwrap();
+    pub fn create_host_only_network_interface(
+        &self,
+    ) -> Result<(HostNetworkInterface, Progress), VboxError> {
+        let mut host_interface: *mut IHostNetworkInterface = std::ptr::null_mut();
+        let progress = get_function_result_pointer!(
+            self.object,
+            CreateHostOnlyNetworkInterface,
+            *mut IProgress,
+            &mut host_interface
+        )?;
+        if host_interface.is_null() {
+            return Err(VboxError::null_pointer_error(
+                "CreateHostOnlyNetworkInterface",
+            ));
+        }
+        Ok((
+            HostNetworkInterface::new(host_interface),
+            Progress::new(progress),
+        ))
+    }
+
+    /// Removes the given Host Only Networking interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - &str. Adapter GUID.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    /// let id = network_interface.get_id().unwrap();
+    /// let progress = host.remove_host_only_network_interface(id).unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn remove_host_only_network_interface(&self, id: &str) -> Result<Progress, VboxError> {
+        let id = string_to_c_u64_str(id)?;
+        let progress = get_function_result_pointer!(
+            self.object,
+            RemoveHostOnlyNetworkInterface,
+            *mut IProgress,
+            id
+        )?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Searches through all host network interfaces for an interface with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. Name of the host network interface to search for.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`HostNetworkInterface`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    pub fn find_host_network_interface_by_name(
+        &self,
+        name: &str,
+    ) -> Result<HostNetworkInterface, VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        let network_interface = get_function_result_pointer!(
+            self.object,
+            FindHostNetworkInterfaceByName,
+            *mut IHostNetworkInterface,
+            name
+        )?;
+        Ok(HostNetworkInterface::new(network_interface))
+    }
+
+    /// Searches through all host network interfaces for an interface with the given GUID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - &str. GUID of the host network interface to search for.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`HostNetworkInterface`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host
+    ///     .find_host_network_interface_by_id("786f6276-656e-4074-8000-0a0027000000")
+    ///     .unwrap();
+    pub fn find_host_network_interface_by_id(
+        &self,
+        id: &str,
+    ) -> Result<HostNetworkInterface, VboxError> {
+        let id = string_to_c_u64_str(id)?;
+        let network_interface = get_function_result_pointer!(
+            self.object,
+            FindHostNetworkInterfaceById,
+            *mut IHostNetworkInterface,
+            id
+        )?;
+        Ok(HostNetworkInterface::new(network_interface))
+    }
+
+    /// Searches through all host network interfaces and returns a list of interfaces of the specified type.
+    ///
+    /// # Arguments
+    ///
+    /// * `interface_type` - [`HostNetworkInterfaceType`]. Type of the host network interfaces to search for.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<HostNetworkInterface>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    /// use virtualbox_rs::enums::HostNetworkInterfaceType;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interfaces = host
+    ///     .find_host_network_interfaces_of_type(HostNetworkInterfaceType::HostOnly)
+    ///     .unwrap();
+    pub fn find_host_network_interfaces_of_type(
+        &self,
+        interface_type: HostNetworkInterfaceType,
+    ) -> Result<Vec<HostNetworkInterface>, VboxError> {
+        let interface_type: u32 = interface_type.into();
+        let network_interfaces = get_function_result_pointer_vec!(
+            self.object,
+            FindHostNetworkInterfacesOfType,
+            *mut IHostNetworkInterface,
+            interface_type
+        )?;
+        Ok(network_interfaces
+            .iter()
+            .map(|object| HostNetworkInterface::new(*object))
+            .collect())
+    }
 }
@@ -13,7 +13,7 @@ use vbox_raw::sys_lib::IHost;
 
 #[derive(Debug)]
 pub struct Host {
-    object: *mut IHost,
+    pub(crate) object: *mut IHost,
 }
 
 impl Host {
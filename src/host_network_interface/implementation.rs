@@ -3,7 +3,9 @@ use crate::enums::{
 };
 use crate::utility::macros::macros::{
     get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_unit,
 };
+use crate::utility::string_to_c_u64_str;
 use crate::{HostNetworkInterface, VboxError};
 
 impl HostNetworkInterface {
@@ -307,4 +309,106 @@ impl HostNetworkInterface {
     pub fn get_wireless(&self) -> Result<bool, VboxError> {
         get_function_result_bool!(self.object, GetWireless)
     }
+
+    /// Sets and enables the static IP V4 configuration for the given interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip_address` - &str. IP address.
+    /// * `network_mask` - &str. Network mask.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    /// network_interface.enable_static_ip_config("192.168.56.1", "255.255.255.0").unwrap();
+    pub fn enable_static_ip_config(
+        &self,
+        ip_address: &str,
+        network_mask: &str,
+    ) -> Result<(), VboxError> {
+        let ip_address = string_to_c_u64_str(ip_address)?;
+        let network_mask = string_to_c_u64_str(network_mask)?;
+        get_function_result_unit!(self.object, EnableStaticIPConfig, ip_address, network_mask)
+    }
+
+    /// Sets and enables the static IP V6 configuration for the given interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `ipv6_address` - &str. IP address.
+    /// * `ipv6_network_mask_prefix_length` - u32. Network mask prefix length.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    /// network_interface.enable_static_ip_config_v6("fd00:56::1", 64).unwrap();
+    pub fn enable_static_ip_config_v6(
+        &self,
+        ipv6_address: &str,
+        ipv6_network_mask_prefix_length: u32,
+    ) -> Result<(), VboxError> {
+        let ipv6_address = string_to_c_u64_str(ipv6_address)?;
+        get_function_result_unit!(
+            self.object,
+            EnableStaticIPConfigV6,
+            ipv6_address,
+            ipv6_network_mask_prefix_length
+        )
+    }
+
+    /// Enables the dynamic IP configuration.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    /// network_interface.enable_dynamic_ip_config().unwrap();
+    pub fn enable_dynamic_ip_config(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, EnableDynamicIPConfig)
+    }
+
+    /// Refreshes the IP configuration for DHCP-enabled interface.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Host;
+    ///
+    /// let host = Host::init().unwrap();
+    /// let network_interface = host.find_host_network_interface_by_name("vboxnet0").unwrap();
+    /// network_interface.dhcp_rediscover().unwrap();
+    pub fn dhcp_rediscover(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, DHCPRediscover)
+    }
 }
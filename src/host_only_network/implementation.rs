@@ -0,0 +1,284 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_str, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{HostOnlyNetwork, VboxError};
+
+impl HostOnlyNetwork {
+    /// The name of the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let network_name = network.get_network_name().unwrap();
+    pub fn get_network_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetworkName)
+    }
+
+    /// The name of the host-only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_name` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_network_name("HON").unwrap();
+    pub fn set_network_name(&self, network_name: &str) -> Result<(), VboxError> {
+        let network_name = string_to_c_u64_str(network_name)?;
+        get_function_result_unit!(self.object, SetNetworkName, network_name)
+    }
+
+    /// Whether the host-only network is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let enabled = network.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Whether the host-only network is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_enabled(true).unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// The network mask of the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let network_mask = network.get_network_mask().unwrap();
+    pub fn get_network_mask(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetworkMask)
+    }
+
+    /// The network mask of the host-only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_mask` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_network_mask("255.255.255.0").unwrap();
+    pub fn set_network_mask(&self, network_mask: &str) -> Result<(), VboxError> {
+        let network_mask = string_to_c_u64_str(network_mask)?;
+        get_function_result_unit!(self.object, SetNetworkMask, network_mask)
+    }
+
+    /// The IP address of the host in the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let host_ip = network.get_host_ip().unwrap();
+    pub fn get_host_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetHostIP)
+    }
+
+    /// The lower bound of the IP address range handed out by the DHCP server of the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let lower_ip = network.get_lower_ip().unwrap();
+    pub fn get_lower_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLowerIP)
+    }
+
+    /// The lower bound of the IP address range handed out by the DHCP server of the host-only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower_ip` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_lower_ip("192.168.56.100").unwrap();
+    pub fn set_lower_ip(&self, lower_ip: &str) -> Result<(), VboxError> {
+        let lower_ip = string_to_c_u64_str(lower_ip)?;
+        get_function_result_unit!(self.object, SetLowerIP, lower_ip)
+    }
+
+    /// The upper bound of the IP address range handed out by the DHCP server of the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let upper_ip = network.get_upper_ip().unwrap();
+    pub fn get_upper_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUpperIP)
+    }
+
+    /// The upper bound of the IP address range handed out by the DHCP server of the host-only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper_ip` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_upper_ip("192.168.56.199").unwrap();
+    pub fn set_upper_ip(&self, upper_ip: &str) -> Result<(), VboxError> {
+        let upper_ip = string_to_c_u64_str(upper_ip)?;
+        get_function_result_unit!(self.object, SetUpperIP, upper_ip)
+    }
+
+    /// The UUID of the host-only network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// let id = network.get_id().unwrap();
+    pub fn get_id(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetId)
+    }
+
+    /// The UUID of the host-only network.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_host_only_network_by_name("HON").unwrap();
+    /// network.set_id("0fb6c4b8-8d3b-4b2f-9a3e-1d2c3b4a5f60").unwrap();
+    pub fn set_id(&self, id: &str) -> Result<(), VboxError> {
+        let id = string_to_c_u64_str(id)?;
+        get_function_result_unit!(self.object, SetId, id)
+    }
+}
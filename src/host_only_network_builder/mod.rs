@@ -0,0 +1,117 @@
+use crate::{HostOnlyNetwork, VboxError, VboxErrorType, VirtualBox};
+use log::debug;
+
+/// Creates a fully configured [`HostOnlyNetwork`] in one call.
+///
+/// The builder is idempotent by name: if a host-only network with the same name is already
+/// registered, it is reused and only the configured attributes are updated, so running the same
+/// builder twice yields the same network.
+///
+/// Host-only networks are available since VirtualBox 7.0. With the v6_1 feature, [`HostOnlyNetworkBuilder::build`]
+/// returns an `UnsupportedInCurrentApiVersion` error; use [`crate::Host::create_host_only_network_interface`] instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{HostOnlyNetworkBuilder, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let network = HostOnlyNetworkBuilder::new("lab-net")
+///     .network_mask("255.255.255.0")
+///     .lower_ip("192.168.60.100")
+///     .upper_ip("192.168.60.199")
+///     .enabled(true)
+///     .build(&vbox)
+///     .unwrap();
+/// println!("{}", network.get_id().unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HostOnlyNetworkBuilder {
+    network_name: String,
+    network_mask: Option<String>,
+    lower_ip: Option<String>,
+    upper_ip: Option<String>,
+    enabled: Option<bool>,
+    id: Option<String>,
+}
+
+impl HostOnlyNetworkBuilder {
+    /// Creates a builder for the host-only network with the given name.
+    pub fn new(network_name: &str) -> Self {
+        Self {
+            network_name: network_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the network mask.
+    pub fn network_mask(mut self, network_mask: &str) -> Self {
+        self.network_mask = Some(network_mask.to_string());
+        self
+    }
+
+    /// Sets the lower bound of the DHCP address range.
+    pub fn lower_ip(mut self, lower_ip: &str) -> Self {
+        self.lower_ip = Some(lower_ip.to_string());
+        self
+    }
+
+    /// Sets the upper bound of the DHCP address range.
+    pub fn upper_ip(mut self, upper_ip: &str) -> Self {
+        self.upper_ip = Some(upper_ip.to_string());
+        self
+    }
+
+    /// Enables or disables the network.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the UUID of the network. It is only applied when the network is created.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Finds the host-only network by name, creates it if it does not exist and applies the configured attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `vbox` - [`VirtualBox`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`HostOnlyNetwork`] on success, or a [`VboxError`] on failure.
+    pub fn build(&self, vbox: &VirtualBox) -> Result<HostOnlyNetwork, VboxError> {
+        let network = match vbox.find_host_only_network_by_name(&self.network_name) {
+            Ok(network) => {
+                debug!("HostOnlyNetwork {} already exists", self.network_name);
+                network
+            }
+            Err(err) if err.error_type == VboxErrorType::VBOX_E_OBJECT_NOT_FOUND => {
+                debug!("Creating HostOnlyNetwork {}", self.network_name);
+                let network = vbox.create_host_only_network(&self.network_name)?;
+                if let Some(id) = &self.id {
+                    network.set_id(id)?;
+                }
+                network
+            }
+            Err(err) => return Err(err),
+        };
+
+        if let Some(network_mask) = &self.network_mask {
+            network.set_network_mask(network_mask)?;
+        }
+        if let Some(lower_ip) = &self.lower_ip {
+            network.set_lower_ip(lower_ip)?;
+        }
+        if let Some(upper_ip) = &self.upper_ip {
+            network.set_upper_ip(upper_ip)?;
+        }
+        if let Some(enabled) = self.enabled {
+            network.set_enabled(enabled)?;
+        }
+        Ok(network)
+    }
+}
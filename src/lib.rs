@@ -55,6 +55,7 @@ mod bandwidth_control;
 mod bandwidth_group;
 #[cfg(not(is_v_7_1))]
 mod bios_settings;
+mod cloud_init_seed;
 mod cloud_network;
 mod console;
 mod core;
@@ -62,7 +63,13 @@ mod certificate;
 #[cfg(not(is_v_6_1))]
 mod cpu_profile;
 mod data_stream;
+mod dhcp_config;
+mod dhcp_global_config;
+mod dhcp_group_condition;
+mod dhcp_group_config;
+mod dhcp_individual_config;
 mod dhcp_server;
+mod disk_image;
 mod display;
 pub mod enums;
 mod errors;
@@ -70,6 +77,8 @@ mod event;
 pub mod event_detail;
 mod event_listener;
 mod event_source;
+mod ext_pack;
+mod ext_pack_file;
 mod ext_pack_manager;
 #[cfg(is_v_7_1)]
 mod firmware_settings;
@@ -88,18 +97,23 @@ mod host_audio_device;
 mod host_network_interface;
 #[cfg(not(is_v_6_1))]
 mod host_only_network;
+mod host_only_network_builder;
 mod keyboard;
 mod machine;
 mod medium;
 mod medium_attachment;
 mod medium_format;
+mod medium_graph;
 mod medium_io;
+mod metrics_exporter;
 mod mouse;
+mod nat_engine;
 mod nat_network;
 mod network_adapter;
 mod not_impl;
 #[cfg(not(is_v_6_1))]
 mod nvram_store;
+mod ovf_package;
 mod parallel_port;
 mod pci_device_attachment;
 #[cfg(is_v_7_1)]
@@ -107,8 +121,12 @@ mod platform;
 #[cfg(is_v_7_1)]
 mod platform_properties;
 mod performance_collector;
+mod performance_metric;
 mod progress;
+mod raw_disk_vmdk;
+mod recording_screen_settings;
 mod recording_settings;
+mod serial_console;
 mod serial_port;
 mod session;
 mod shared_folder;
@@ -131,6 +149,7 @@ mod virtualbox_client;
 mod virtualbox_error_info;
 mod vfs_explorer;
 mod vrde_server;
+mod vrde_server_info;
 #[cfg(is_v_7_1)]
 mod platform_x86;
 #[cfg(is_v_7_1)]
@@ -145,12 +164,20 @@ pub use bandwidth_group::BandwidthGroup;
 #[cfg(not(is_v_7_1))]
 pub use bios_settings::BIOSSettings;
 pub use certificate::Certificate;
+pub use cloud_init_seed::{
+    CloudInitSeed, CloudUser, EthernetConfig, MetaData, NetworkConfig, UserData,
+};
 pub use cloud_network::CloudNetwork;
 pub use console::Console;
 #[cfg(not(is_v_6_1))]
 pub use cpu_profile::CPUProfile;
-pub use data_stream::DataStream;
+pub use data_stream::{DataStream, DataStreamReader};
+pub use dhcp_global_config::DHCPGlobalConfig;
+pub use dhcp_group_condition::DHCPGroupCondition;
+pub use dhcp_group_config::DHCPGroupConfig;
+pub use dhcp_individual_config::DHCPIndividualConfig;
 pub use dhcp_server::DHCPServer;
+pub use disk_image::{DiskImage, DiskImageFormat, DiskImageVariant};
 pub use display::Display;
 pub use display::Resolution;
 pub use display::VideoModeHint;
@@ -159,6 +186,8 @@ pub use errors::VboxError;
 pub use event::Event;
 pub use event_listener::EventListener;
 pub use event_source::EventSource;
+pub use ext_pack::ExtPack;
+pub use ext_pack_file::ExtPackFile;
 pub use ext_pack_manager::ExtPackManager;
 #[cfg(is_v_7_1)]
 pub use firmware_settings::FirmwareSettings;
@@ -178,14 +207,20 @@ pub use host_audio_device::HostAudioDevice;
 pub use host_network_interface::HostNetworkInterface;
 #[cfg(not(is_v_6_1))]
 pub use host_only_network::HostOnlyNetwork;
+pub use host_only_network_builder::HostOnlyNetworkBuilder;
 pub use keyboard::Keyboard;
 pub use machine::Machine;
 pub use medium::Medium;
 pub use medium_attachment::MediumAttachment;
 pub use medium_format::MediumFormat;
-pub use medium_io::MediumIO;
+pub use medium_graph::{
+    CleanupAction, CleanupOperation, CleanupPlan, MediumGraph, MediumIssue, MediumNode,
+};
+pub use medium_io::{MediumIO, MediumReader, MediumWriter, RawImageExporter};
+pub use metrics_exporter::MetricsExporter;
 pub use mouse::Mouse;
-pub use nat_network::NATNetwork;
+pub use nat_engine::NATEngine;
+pub use nat_network::{NATNetwork, PortForwardRule};
 pub use network_adapter::NetworkAdapter;
 #[cfg(is_v_7_1)]
 pub use not_impl::bios_settings::BIOSSettings;
@@ -209,6 +244,10 @@ pub use not_impl::trusted_platform_module::TrustedPlatformModule;
 pub use not_impl::update_agent::UpdateAgent;
 #[cfg(not(is_v_6_1))]
 pub use nvram_store::NvramStore;
+pub use ovf_package::{
+    DigestAlgorithm, ManifestEntry, OvfDisk, OvfEnvelope, OvfFile, OvfFileCheck, OvfHardwareItem,
+    OvfNetwork, OvfPackage, OvfResourceType, OvfSigner, OvfVBoxMachine, OvfVirtualSystem,
+};
 pub use parallel_port::ParallelPort;
 #[cfg(is_v_7_1)]
 pub use platform::Platform;
@@ -217,9 +256,13 @@ pub use not_impl::platform::Platform;
 #[cfg(is_v_7_1)]
 pub use platform_properties::PlatformProperties;
 pub use pci_device_attachment::PCIDeviceAttachment;
-pub use performance_collector::PerformanceCollector;
+pub use performance_collector::{PerformanceCollector, PerformanceObject, PerformanceSeries};
+pub use performance_metric::PerformanceMetric;
 pub use progress::Progress;
+pub use raw_disk_vmdk::{HostPartition, RawDiskVmdk};
+pub use recording_screen_settings::RecordingScreenSettings;
 pub use recording_settings::RecordingSettings;
+pub use serial_console::{ExpectMatch, SerialConsole};
 pub use serial_port::SerialPort;
 pub use session::Session;
 pub use shared_folder::SharedFolder;
@@ -235,12 +278,13 @@ pub use update_agent::UpdateAgent;
 pub use usb_controller::USBController;
 pub use usb_device::USBDevice;
 pub use usb_device_filters::USBDeviceFilters;
-pub use virtual_system_description::VirtualSystemDescription;
+pub use virtual_system_description::{VirtualSystemDescription, VirtualSystemDescriptionEntry};
 pub use virtualbox::VirtualBox;
 pub use virtualbox_client::VirtualBoxClient;
 pub use virtualbox_error_info::VirtualBoxErrorInfo;
-pub use vfs_explorer::VFSExplorer;
+pub use vfs_explorer::{VFSExplorer, VFSExplorerEntry};
 pub use vrde_server::VRDEServer;
+pub use vrde_server_info::VRDEServerInfo;
 #[cfg(is_v_7_1)]
 pub use platform_x86::PlatformX86;
 #[cfg(not(is_v_7_1))]
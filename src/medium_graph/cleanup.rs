@@ -0,0 +1,166 @@
+use crate::enums::DeviceType;
+use crate::{Medium, VboxError, VirtualBox};
+use log::debug;
+use std::collections::HashMap;
+
+/// What [`CleanupPlan::execute`] does with a medium.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CleanupOperation {
+    /// [`Medium::close`], which unregisters the medium and keeps its file.
+    Close,
+    /// [`Medium::delete_storage`], which deletes the file and unregisters the medium.
+    DeleteStorage,
+}
+
+/// One step of a [`CleanupPlan`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanupAction {
+    pub medium_id: String,
+    pub location: String,
+    pub device_type: DeviceType,
+    pub operation: CleanupOperation,
+}
+
+/// Media to close or delete, created by [`crate::MediumGraph::cleanup_plan`].
+///
+/// Differencing media come before their parents, because a medium with children cannot be closed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CleanupPlan {
+    pub actions: Vec<CleanupAction>,
+}
+
+impl CleanupPlan {
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Executes the plan in order and stops at the first failure.
+    ///
+    /// Media are looked up by ID in the current registry, so a medium which was attached or
+    /// unregistered since the plan was made fails the execution instead of being touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `vbox` - &[`VirtualBox`].
+    /// * `dry_run` - bool. Only checks that every medium is still registered and unattached, without closing or deleting anything.
+    ///
+    /// # Returns
+    ///
+    /// Returns the executed actions on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{MediumGraph, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let plan = MediumGraph::build(&vbox, true).unwrap().cleanup_plan(false);
+    /// for action in plan.execute(&vbox, true).unwrap() {
+    ///     println!("would {:?} {}", action.operation, action.location);
+    /// }
+    /// ```
+    pub fn execute(
+        &self,
+        vbox: &VirtualBox,
+        dry_run: bool,
+    ) -> Result<Vec<&CleanupAction>, VboxError> {
+        let mut media = registered_media(vbox)?;
+        let mut executed = Vec::with_capacity(self.actions.len());
+        for action in &self.actions {
+            let medium = media
+                .remove(&action.medium_id)
+                .ok_or(VboxError::invalid_data(
+                    "CleanupPlan::execute",
+                    format!("Medium {} is no longer registered", action.medium_id),
+                ))?;
+            if !medium.get_machine_ids()?.is_empty() {
+                return Err(VboxError::invalid_data(
+                    "CleanupPlan::execute",
+                    format!("Medium {} is attached to a machine", action.medium_id),
+                ));
+            }
+            debug!(
+                "CleanupPlan::execute: {:?} {} dry_run: {}",
+                action.operation, action.location, dry_run
+            );
+            if !dry_run {
+                match action.operation {
+                    CleanupOperation::Close => medium.close()?,
+                    CleanupOperation::DeleteStorage => medium
+                        .delete_storage()?
+                        .wait_for_success("CleanupPlan::execute")?,
+                }
+            }
+            executed.push(action);
+        }
+        Ok(executed)
+    }
+}
+
+/// All registered media by ID, including every differencing medium.
+fn registered_media(vbox: &VirtualBox) -> Result<HashMap<String, Medium>, VboxError> {
+    let mut pending = vbox.get_hard_disks()?;
+    pending.extend(vbox.get_dvd_images()?);
+    pending.extend(vbox.get_floppy_images()?);
+    let mut media = HashMap::new();
+    while let Some(medium) = pending.pop() {
+        let id = medium.get_id()?.to_string();
+        if media.contains_key(&id) {
+            continue;
+        }
+        pending.extend(medium.get_children()?);
+        media.insert(id, medium);
+    }
+    Ok(media)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::MediumState;
+    use crate::medium_graph::tests::{attached, graph, node};
+    use crate::MediumGraph;
+
+    fn operations(plan: &CleanupPlan) -> Vec<(&str, CleanupOperation)> {
+        plan.actions
+            .iter()
+            .map(|action| (action.medium_id.as_str(), action.operation))
+            .collect()
+    }
+
+    #[test]
+    fn closes_children_before_parents() {
+        let plan = graph().cleanup_plan(false);
+        assert_eq!(
+            operations(&plan),
+            vec![
+                ("diff-2", CleanupOperation::Close),
+                ("orphan-diff", CleanupOperation::Close),
+                ("orphan", CleanupOperation::Close),
+            ]
+        );
+        assert_eq!(plan.actions[2].location, "/vms/orphan.vdi");
+    }
+
+    #[test]
+    fn deletes_only_accessible_hard_disks() {
+        let mut inaccessible = node("gone", None, &[]);
+        inaccessible.state = MediumState::Inaccessible;
+        let mut iso = node("iso", None, &[]);
+        iso.device_type = DeviceType::DVD;
+        let graph = MediumGraph::from_nodes(vec![
+            inaccessible,
+            iso,
+            node("disk", None, &[]),
+            attached(node("used", None, &[]), "vm-1"),
+        ]);
+        assert_eq!(
+            operations(&graph.cleanup_plan(true)),
+            vec![
+                ("gone", CleanupOperation::Close),
+                ("iso", CleanupOperation::Close),
+                ("disk", CleanupOperation::DeleteStorage),
+            ]
+        );
+    }
+}
@@ -0,0 +1,477 @@
+mod cleanup;
+
+use crate::enums::{DeviceType, MediumState};
+use crate::{Medium, VboxError, VirtualBox};
+pub use cleanup::{CleanupAction, CleanupOperation, CleanupPlan};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+
+/// Snapshot of all registered media and the differencing trees between them.
+///
+/// The graph is built once from [`VirtualBox::get_hard_disks`], [`VirtualBox::get_dvd_images`] and
+/// [`VirtualBox::get_floppy_images`], following [`Medium::get_children`] down every hard disk
+/// tree. It holds plain data only, so it can be inspected after the media changed and it has to be
+/// built again to see those changes.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{MediumGraph, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let graph = MediumGraph::build(&vbox, true).unwrap();
+/// for issue in graph.find_issues() {
+///     println!("{:?}", issue);
+/// }
+/// let plan = graph.cleanup_plan(false);
+/// for action in plan.execute(&vbox, true).unwrap() {
+///     println!("{:?} {}", action.operation, action.location);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MediumGraph {
+    nodes: Vec<MediumNode>,
+    index: HashMap<String, usize>,
+}
+
+/// A registered medium of a [`MediumGraph`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediumNode {
+    pub id: String,
+    pub name: String,
+    pub location: String,
+    pub device_type: DeviceType,
+    pub state: MediumState,
+    /// Why the medium is inaccessible. Empty if the accessibility check was not performed yet.
+    pub last_access_error: String,
+    pub format: String,
+    /// Size of the storage unit in bytes.
+    pub size: i64,
+    /// Size of the medium as the guest sees it, in bytes.
+    pub logical_size: i64,
+    /// `None` for base media.
+    pub parent_id: Option<String>,
+    pub children_ids: Vec<String>,
+    /// Machines the medium is attached to, in the current state or in a snapshot.
+    pub machine_ids: Vec<String>,
+    /// Snapshots using the medium, per machine ID. The machine ID itself in the list means the
+    /// current state of the machine uses it.
+    pub snapshot_ids: Vec<(String, Vec<String>)>,
+}
+
+/// A problem found by [`MediumGraph::find_issues`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediumIssue {
+    /// The storage unit of the medium cannot be accessed.
+    Inaccessible { medium_id: String, error: String },
+    /// Neither the medium nor any medium based on it is attached to a machine or snapshot.
+    ///
+    /// Only the topmost medium of such a tree is reported.
+    Unattached { medium_id: String },
+    /// The parent of the medium is not registered.
+    MissingParent {
+        medium_id: String,
+        parent_id: String,
+    },
+    /// A child listed by the medium is not registered, or names a different parent.
+    InconsistentChild { medium_id: String, child_id: String },
+}
+
+impl MediumNode {
+    fn read(medium: &Medium) -> Result<Self, VboxError> {
+        let id = medium.get_id()?.to_string();
+        let machine_ids: Vec<String> = medium
+            .get_machine_ids()?
+            .iter()
+            .map(|machine_id| machine_id.to_string())
+            .collect();
+        let mut snapshot_ids = Vec::with_capacity(machine_ids.len());
+        for machine_id in &machine_ids {
+            let ids = medium.get_snapshot_ids(machine_id)?;
+            snapshot_ids.push((
+                machine_id.clone(),
+                ids.iter().map(|id| id.to_string()).collect(),
+            ));
+        }
+        let mut children_ids = Vec::new();
+        for child in medium.get_children()? {
+            children_ids.push(child.get_id()?.to_string());
+        }
+        let parent_id = match medium.get_parent()? {
+            None => None,
+            Some(parent) => Some(parent.get_id()?.to_string()),
+        };
+        Ok(Self {
+            id,
+            name: medium.get_name()?.to_string(),
+            location: medium.get_location()?.to_string(),
+            device_type: medium.get_device_type()?,
+            state: medium.get_state()?,
+            last_access_error: medium.get_last_access_error()?.to_string(),
+            format: medium.get_format()?.to_string(),
+            size: medium.get_size()?,
+            logical_size: medium.get_logical_size()?,
+            parent_id,
+            children_ids,
+            machine_ids,
+            snapshot_ids,
+        })
+    }
+
+    /// Whether the medium is attached to a machine or snapshot.
+    pub fn is_attached(&self) -> bool {
+        !self.machine_ids.is_empty()
+    }
+}
+
+impl MediumGraph {
+    /// Reads all registered media.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_state` - bool. Runs [`Medium::refresh_state`] on every medium first. Media whose accessibility was never checked are reported as [`MediumState::Inaccessible`] otherwise, but the check may take a while for remote storage.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`MediumGraph`] on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{MediumGraph, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let graph = MediumGraph::build(&vbox, false).unwrap();
+    /// ```
+    pub fn build(vbox: &VirtualBox, refresh_state: bool) -> Result<Self, VboxError> {
+        let mut nodes = Vec::new();
+        let mut seen = HashSet::new();
+        for medium in vbox.get_hard_disks()? {
+            Self::collect_tree(&medium, refresh_state, &mut seen, &mut nodes)?;
+        }
+        for medium in vbox
+            .get_dvd_images()?
+            .iter()
+            .chain(vbox.get_floppy_images()?.iter())
+        {
+            Self::collect_tree(medium, refresh_state, &mut seen, &mut nodes)?;
+        }
+        debug!("MediumGraph::build: {} media", nodes.len());
+        Ok(Self::from_nodes(nodes))
+    }
+
+    fn collect_tree(
+        medium: &Medium,
+        refresh_state: bool,
+        seen: &mut HashSet<String>,
+        nodes: &mut Vec<MediumNode>,
+    ) -> Result<(), VboxError> {
+        if !seen.insert(medium.get_id()?.to_string()) {
+            return Ok(());
+        }
+        if refresh_state {
+            medium.refresh_state()?;
+        }
+        nodes.push(MediumNode::read(medium)?);
+        for child in medium.get_children()? {
+            Self::collect_tree(&child, refresh_state, seen, nodes)?;
+        }
+        Ok(())
+    }
+
+    /// Builds a graph from already collected nodes. Nodes with a duplicate ID are dropped.
+    pub fn from_nodes(nodes: Vec<MediumNode>) -> Self {
+        let mut graph = Self::default();
+        for node in nodes {
+            if graph.index.contains_key(&node.id) {
+                continue;
+            }
+            graph.index.insert(node.id.clone(), graph.nodes.len());
+            graph.nodes.push(node);
+        }
+        graph
+    }
+
+    /// All media in the order they were collected. [`MediumGraph::build`] puts parents before their children.
+    pub fn nodes(&self) -> &[MediumNode] {
+        &self.nodes
+    }
+
+    pub fn get(&self, id: &str) -> Option<&MediumNode> {
+        self.index.get(id).map(|i| &self.nodes[*i])
+    }
+
+    /// Media without a registered parent: base media and the tops of broken chains.
+    pub fn roots(&self) -> Vec<&MediumNode> {
+        self.nodes
+            .iter()
+            .filter(|node| match &node.parent_id {
+                None => true,
+                Some(parent_id) => self.get(parent_id).is_none(),
+            })
+            .collect()
+    }
+
+    /// Registered children of the medium.
+    pub fn children(&self, id: &str) -> Vec<&MediumNode> {
+        self.get(id)
+            .map(|node| {
+                node.children_ids
+                    .iter()
+                    .filter_map(|child_id| self.get(child_id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The medium and every medium based on it, children before their parents.
+    pub fn subtree(&self, id: &str) -> Vec<&MediumNode> {
+        let mut result = Vec::new();
+        let mut visited = HashSet::new();
+        self.push_subtree(id, &mut visited, &mut result);
+        result
+    }
+
+    fn push_subtree<'a>(
+        &'a self,
+        id: &str,
+        visited: &mut HashSet<&'a str>,
+        result: &mut Vec<&'a MediumNode>,
+    ) {
+        let Some(node) = self.get(id) else {
+            return;
+        };
+        if !visited.insert(node.id.as_str()) {
+            return;
+        }
+        for child in self.children(id) {
+            self.push_subtree(&child.id, visited, result);
+        }
+        result.push(node);
+    }
+
+    /// Parents of the medium up to its base, nearest first. Stops at an unregistered parent.
+    pub fn ancestors(&self, id: &str) -> Vec<&MediumNode> {
+        let mut result: Vec<&MediumNode> = Vec::new();
+        let mut current = self.get(id).and_then(|node| node.parent_id.as_deref());
+        while let Some(parent_id) = current {
+            let Some(parent) = self.get(parent_id) else {
+                break;
+            };
+            if parent.id == id || result.iter().any(|node| node.id == parent.id) {
+                break;
+            }
+            result.push(parent);
+            current = parent.parent_id.as_deref();
+        }
+        result
+    }
+
+    /// Whether neither the medium nor any medium based on it is attached.
+    fn is_unattached_tree(&self, id: &str) -> bool {
+        self.subtree(id).iter().all(|node| !node.is_attached())
+    }
+
+    /// Checks every medium for inaccessible storage, unattached trees and broken parent chains.
+    pub fn find_issues(&self) -> Vec<MediumIssue> {
+        let mut issues = Vec::new();
+        for node in &self.nodes {
+            if node.state == MediumState::Inaccessible {
+                issues.push(MediumIssue::Inaccessible {
+                    medium_id: node.id.clone(),
+                    error: node.last_access_error.clone(),
+                });
+            }
+            if let Some(parent_id) = &node.parent_id {
+                if self.get(parent_id).is_none() {
+                    issues.push(MediumIssue::MissingParent {
+                        medium_id: node.id.clone(),
+                        parent_id: parent_id.clone(),
+                    });
+                }
+            }
+            for child_id in &node.children_ids {
+                let is_consistent = self
+                    .get(child_id)
+                    .map(|child| child.parent_id.as_deref() == Some(node.id.as_str()))
+                    .unwrap_or(false);
+                if !is_consistent {
+                    issues.push(MediumIssue::InconsistentChild {
+                        medium_id: node.id.clone(),
+                        child_id: child_id.clone(),
+                    });
+                }
+            }
+        }
+        for node in self.unattached_roots() {
+            issues.push(MediumIssue::Unattached {
+                medium_id: node.id.clone(),
+            });
+        }
+        issues
+    }
+
+    /// Topmost media of the unattached trees.
+    fn unattached_roots(&self) -> Vec<&MediumNode> {
+        self.nodes
+            .iter()
+            .filter(|node| self.is_unattached_tree(&node.id))
+            .filter(
+                |node| match node.parent_id.as_deref().and_then(|id| self.get(id)) {
+                    None => true,
+                    Some(parent) => !self.is_unattached_tree(&parent.id),
+                },
+            )
+            .collect()
+    }
+
+    /// Plans closing every unattached tree, children first.
+    ///
+    /// Inaccessible and not created media, as well as DVD and floppy images, are only closed. Their
+    /// files are never deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `delete_storage` - bool. Deletes the storage of accessible hard disks instead of only closing them.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`CleanupPlan`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{MediumGraph, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let plan = MediumGraph::build(&vbox, true).unwrap().cleanup_plan(true);
+    /// plan.execute(&vbox, false).unwrap();
+    /// ```
+    pub fn cleanup_plan(&self, delete_storage: bool) -> CleanupPlan {
+        let mut actions = Vec::new();
+        for root in self.unattached_roots() {
+            for node in self.subtree(&root.id) {
+                let can_delete = node.device_type == DeviceType::HardDisk
+                    && !matches!(
+                        node.state,
+                        MediumState::Inaccessible | MediumState::NotCreated
+                    );
+                let operation = if delete_storage && can_delete {
+                    CleanupOperation::DeleteStorage
+                } else {
+                    CleanupOperation::Close
+                };
+                actions.push(CleanupAction {
+                    medium_id: node.id.clone(),
+                    location: node.location.clone(),
+                    device_type: node.device_type,
+                    operation,
+                });
+            }
+        }
+        CleanupPlan { actions }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn node(id: &str, parent_id: Option<&str>, children_ids: &[&str]) -> MediumNode {
+        MediumNode {
+            id: id.to_string(),
+            name: format!("{}.vdi", id),
+            location: format!("/vms/{}.vdi", id),
+            device_type: DeviceType::HardDisk,
+            state: MediumState::Created,
+            last_access_error: String::new(),
+            format: "VDI".to_string(),
+            size: 2 * 1024 * 1024,
+            logical_size: 1024 * 1024 * 1024,
+            parent_id: parent_id.map(|parent_id| parent_id.to_string()),
+            children_ids: children_ids.iter().map(|id| id.to_string()).collect(),
+            machine_ids: Vec::new(),
+            snapshot_ids: Vec::new(),
+        }
+    }
+
+    pub(crate) fn attached(mut node: MediumNode, machine_id: &str) -> MediumNode {
+        node.machine_ids = vec![machine_id.to_string()];
+        node.snapshot_ids = vec![(machine_id.to_string(), vec![machine_id.to_string()])];
+        node
+    }
+
+    /// `base` with the attached child `diff-1`, `orphan` with the unattached child `orphan-diff`.
+    pub(crate) fn graph() -> MediumGraph {
+        MediumGraph::from_nodes(vec![
+            node("base", None, &["diff-1", "diff-2"]),
+            attached(node("diff-1", Some("base"), &[]), "vm-1"),
+            node("diff-2", Some("base"), &[]),
+            node("orphan", None, &["orphan-diff"]),
+            node("orphan-diff", Some("orphan"), &[]),
+        ])
+    }
+
+    fn ids(nodes: Vec<&MediumNode>) -> Vec<&str> {
+        nodes.iter().map(|node| node.id.as_str()).collect()
+    }
+
+    #[test]
+    fn traverses_the_tree() {
+        let graph = graph();
+        assert_eq!(ids(graph.roots()), vec!["base", "orphan"]);
+        assert_eq!(ids(graph.children("base")), vec!["diff-1", "diff-2"]);
+        assert_eq!(ids(graph.subtree("base")), vec!["diff-1", "diff-2", "base"]);
+        assert_eq!(ids(graph.ancestors("diff-2")), vec!["base"]);
+    }
+
+    #[test]
+    fn reports_unattached_trees_once() {
+        let issues = graph().find_issues();
+        assert_eq!(
+            issues,
+            vec![
+                MediumIssue::Unattached {
+                    medium_id: "diff-2".to_string()
+                },
+                MediumIssue::Unattached {
+                    medium_id: "orphan".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_broken_chains_and_inaccessible_media() {
+        let mut missing = attached(node("diff", Some("lost"), &[]), "vm-1");
+        missing.state = MediumState::Inaccessible;
+        missing.last_access_error = "Parent medium not found".to_string();
+        let graph = MediumGraph::from_nodes(vec![
+            missing,
+            attached(node("base", None, &["stray"]), "vm-2"),
+            attached(node("stray", Some("other"), &[]), "vm-2"),
+        ]);
+        assert_eq!(ids(graph.roots()), vec!["diff", "base", "stray"]);
+        assert_eq!(
+            graph.find_issues(),
+            vec![
+                MediumIssue::Inaccessible {
+                    medium_id: "diff".to_string(),
+                    error: "Parent medium not found".to_string()
+                },
+                MediumIssue::MissingParent {
+                    medium_id: "diff".to_string(),
+                    parent_id: "lost".to_string()
+                },
+                MediumIssue::InconsistentChild {
+                    medium_id: "base".to_string(),
+                    child_id: "stray".to_string()
+                },
+                MediumIssue::MissingParent {
+                    medium_id: "stray".to_string(),
+                    parent_id: "other".to_string()
+                },
+            ]
+        );
+    }
+}
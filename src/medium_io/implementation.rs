@@ -3,8 +3,8 @@ use crate::utility::macros::macros::{
     get_function_result_bool, get_function_result_number, get_function_result_pointer,
     get_function_result_unit,
 };
-use crate::utility::string_to_c_u64_str;
-use crate::{DataStream, Medium, MediumIO, Progress, VFSExplorer, VboxError};
+use crate::utility::{array_out_to_vec, string_to_c_u64_str};
+use crate::{DataStream, DataStreamReader, Medium, MediumIO, Progress, VFSExplorer, VboxError};
 use vbox_raw::sys_lib::{IMedium, IProgress, IVFSExplorer};
 
 impl MediumIO {
@@ -88,6 +88,9 @@ impl MediumIO {
     ///
     /// - Array of data read. This may be shorter than the specified size.
     ///
+    /// The array stays allocated by VirtualBox, use [`crate::MediumReader`] to read large amounts
+    /// of data.
+    ///
     ///  # Example
     ///
     /// ```no_run
@@ -103,11 +106,28 @@ impl MediumIO {
     pub fn read(&self, offset: i64, size: u32) -> Result<&[u8], VboxError> {
         let mut data_size: u32 = 0;
         let data_ptr =
-            get_function_result_pointer!(self.object, Read, *mut u8, offset, size, &mut data_size)?;
-        let data_slice = unsafe { std::slice::from_raw_parts(data_ptr, size as usize) };
+            get_function_result_pointer!(self.object, Read, *mut u8, offset, size, &mut data_size);
+        let data_ptr = match data_ptr {
+            Ok(data_ptr) => data_ptr,
+            Err(err) if err.is_null() => return Ok(&[]),
+            Err(err) => return Err(err),
+        };
+        let data_slice = unsafe { std::slice::from_raw_parts(data_ptr, data_size as usize) };
         Ok(data_slice)
     }
 
+    /// Like [`MediumIO::read`], but copies the data and frees the buffer allocated by VirtualBox.
+    pub(crate) fn read_to_vec(&self, offset: i64, size: u32) -> Result<Vec<u8>, VboxError> {
+        let mut data_size: u32 = 0;
+        let data_ptr =
+            get_function_result_pointer!(self.object, Read, *mut u8, offset, size, &mut data_size);
+        match data_ptr {
+            Ok(data_ptr) => array_out_to_vec(data_ptr, data_size),
+            Err(err) if err.is_null() => Ok(Vec::new()),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Write data to the medium.
     ///
     /// # Arguments
@@ -257,6 +277,42 @@ impl MediumIO {
         Ok((DataStream::new(stream), Progress::new(progress)))
     }
 
+    /// Converts the currently opened image into a stream of a selected format and returns it as a [`std::io::Read`].
+    ///
+    /// See [`MediumIO::convert_to_stream`] and [`DataStreamReader`].
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Identifier of the storage format to use for output.
+    /// * `variants` - Image variant flags of the output, for example [`MediumVariant::VmdkStreamOptimized`].
+    /// * `buffer_size` - Requested buffer size (in bytes) for efficient conversion.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`DataStreamReader`], or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mediums = vbox.get_hard_disks().unwrap();
+    /// let medium = mediums.get(0).unwrap();
+    /// let medium_io = medium.open_for_io(false, "").unwrap();
+    /// let mut reader = medium_io.convert_to_reader("VHD", vec![], 64 * 1024 * 1024).unwrap();
+    /// std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+    /// ```
+    pub fn convert_to_reader(
+        &self,
+        format: &str,
+        variants: Vec<MediumVariant>,
+        buffer_size: u32,
+    ) -> Result<DataStreamReader, VboxError> {
+        let (stream, progress) = self.convert_to_stream(format, variants, buffer_size)?;
+        Ok(DataStreamReader::new(stream, progress))
+    }
 
     /// Explictly close the medium I/O rather than waiting for garbage collection and the destructor.
     ///
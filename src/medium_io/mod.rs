@@ -1,9 +1,15 @@
 mod implementation;
+mod raw_export;
+mod stream;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IMediumIO;
 
+pub use raw_export::RawImageExporter;
+pub use stream::{MediumReader, MediumWriter};
+
 #[cfg(doc)]
 use crate::Medium;
 /// The MediumIO interface is used to access and modify the content of a medium.
@@ -0,0 +1,277 @@
+use super::stream::{MediumReader, MAX_CHUNK_SIZE};
+use crate::{Medium, VboxError};
+use log::debug;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+
+/// Streams the content of a [`Medium`] into a raw disk image, for example for offline backups.
+///
+/// The medium is opened read-only with [`Medium::open_for_io`], so it must not be locked for
+/// writing, i.e. the machines using it should be powered off. With [`RawImageExporter::sparse`],
+/// blocks containing only zeros are skipped instead of written, which keeps the image file sparse
+/// on file systems supporting it.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{RawImageExporter, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let mediums = vbox.get_hard_disks().unwrap();
+/// let medium = mediums.get(0).unwrap();
+/// let written = RawImageExporter::new()
+///     .sparse(true)
+///     .export_to_file(medium, "/home/user/backup/disk.img")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RawImageExporter {
+    block_size: usize,
+    sparse: bool,
+    password: String,
+}
+
+impl Default for RawImageExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RawImageExporter {
+    /// Creates an exporter writing every block, with 1 MiB blocks.
+    pub fn new() -> Self {
+        Self {
+            block_size: MAX_CHUNK_SIZE,
+            sparse: false,
+            password: String::new(),
+        }
+    }
+
+    /// Sets the block size, which is also the granularity of zero-block detection.
+    ///
+    /// Values below 512 bytes are raised to 512.
+    pub fn block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size.max(512);
+        self
+    }
+
+    /// Skips blocks containing only zeros. Only used by the exports into seekable outputs.
+    pub fn sparse(mut self, sparse: bool) -> Self {
+        self.sparse = sparse;
+        self
+    }
+
+    /// Password of an encrypted medium.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = password.to_string();
+        self
+    }
+
+    /// Streams the whole medium into `writer`, zero blocks included.
+    ///
+    /// # Arguments
+    ///
+    /// * `medium` - &[`Medium`].
+    /// * `writer` - Any [`Write`], for example a pipe to a compressor or a socket.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bytes written on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{RawImageExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mediums = vbox.get_hard_disks().unwrap();
+    /// let medium = mediums.get(0).unwrap();
+    /// let mut stdout = std::io::stdout().lock();
+    /// RawImageExporter::new().export(medium, &mut stdout).unwrap();
+    /// ```
+    pub fn export<W: Write>(&self, medium: &Medium, writer: &mut W) -> Result<u64, VboxError> {
+        let medium_io = medium.open_for_io(false, &self.password)?;
+        let mut reader = MediumReader::new(&medium_io)?;
+        let size = reader.size();
+        let result = copy_blocks(&mut reader, writer, size, self.block_size, None);
+        let closed = medium_io.close();
+        let written = result?;
+        closed?;
+        Ok(written)
+    }
+
+    /// Streams the whole medium into a seekable `writer`, skipping zero blocks in sparse mode.
+    ///
+    /// The writer has to be positioned at the start of the image, skipped blocks must read as zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `medium` - &[`Medium`].
+    /// * `writer` - A [`Write`] and [`Seek`] output.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bytes written on success, or a [`VboxError`] on failure. Skipped
+    /// zero blocks are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::io::Cursor;
+    /// use virtualbox_rs::{RawImageExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mediums = vbox.get_floppy_images().unwrap();
+    /// let medium = mediums.get(0).unwrap();
+    /// let mut image = Cursor::new(Vec::new());
+    /// RawImageExporter::new().sparse(true).export_seekable(medium, &mut image).unwrap();
+    /// ```
+    pub fn export_seekable<W: Write + Seek>(
+        &self,
+        medium: &Medium,
+        writer: &mut W,
+    ) -> Result<u64, VboxError> {
+        let medium_io = medium.open_for_io(false, &self.password)?;
+        let mut reader = MediumReader::new(&medium_io)?;
+        let size = reader.size();
+        let seek = if self.sparse {
+            Some(seek_relative::<W> as fn(&mut W, i64) -> std::io::Result<()>)
+        } else {
+            None
+        };
+        let result = copy_blocks(&mut reader, writer, size, self.block_size, seek);
+        let closed = medium_io.close();
+        let written = result?;
+        closed?;
+        Ok(written)
+    }
+
+    /// Streams the whole medium into a new raw image file at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `medium` - &[`Medium`].
+    /// * `path` - &str. The file is created or truncated.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of bytes written on success, or a [`VboxError`] on failure. Skipped
+    /// zero blocks are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::{RawImageExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mediums = vbox.get_hard_disks().unwrap();
+    /// let medium = mediums.get(0).unwrap();
+    /// RawImageExporter::new()
+    ///     .sparse(true)
+    ///     .export_to_file(medium, "/home/user/backup/disk.img")
+    ///     .unwrap();
+    /// ```
+    pub fn export_to_file(&self, medium: &Medium, path: &str) -> Result<u64, VboxError> {
+        let file = File::create(path)
+            .map_err(|err| VboxError::io_error("RawImageExporter::export_to_file", err))?;
+        let mut writer = BufWriter::new(file);
+        let written = self.export_seekable(medium, &mut writer)?;
+        let file = writer.into_inner().map_err(|err| {
+            VboxError::io_error("RawImageExporter::export_to_file", err.into_error())
+        })?;
+        file.sync_all()
+            .map_err(|err| VboxError::io_error("RawImageExporter::export_to_file", err))?;
+        Ok(written)
+    }
+}
+
+fn seek_relative<W: Seek>(writer: &mut W, offset: i64) -> std::io::Result<()> {
+    writer.seek(SeekFrom::Current(offset)).map(|_| ())
+}
+
+/// Copies `size` bytes block by block. When `seek` is given, zero blocks are skipped with it.
+fn copy_blocks<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    size: u64,
+    block_size: usize,
+    seek: Option<fn(&mut W, i64) -> std::io::Result<()>>,
+) -> Result<u64, VboxError> {
+    let io_error = |err| VboxError::io_error("RawImageExporter::copy_blocks", err);
+    let mut block = vec![0u8; block_size];
+    let mut position = 0;
+    let mut written = 0;
+    let mut skipped_tail = false;
+    while position < size {
+        let len = (size - position).min(block_size as u64) as usize;
+        let block = &mut block[..len];
+        reader.read_exact(block).map_err(io_error)?;
+        position += len as u64;
+        match seek {
+            Some(seek) if block.iter().all(|byte| *byte == 0) => {
+                seek(writer, len as i64).map_err(io_error)?;
+                skipped_tail = true;
+            }
+            _ => {
+                writer.write_all(block).map_err(io_error)?;
+                written += len as u64;
+                skipped_tail = false;
+            }
+        }
+    }
+    if let (true, Some(seek)) = (skipped_tail, seek) {
+        // Seeking alone does not extend the output, the last byte gives the image its full size.
+        seek(writer, -1).map_err(io_error)?;
+        writer.write_all(&[0]).map_err(io_error)?;
+        written += 1;
+    }
+    writer.flush().map_err(io_error)?;
+    debug!("RawImageExporter: {} of {} bytes written", written, size);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copy_blocks, seek_relative};
+    use std::io::Cursor;
+
+    /// Four 512 byte blocks: data, zeros, data, zeros.
+    fn disk() -> Vec<u8> {
+        let mut disk = vec![0u8; 2048];
+        disk[..512].fill(0xAB);
+        disk[1024 + 17] = 1;
+        disk
+    }
+
+    #[test]
+    fn copies_every_block() {
+        let disk = disk();
+        let mut output = Vec::new();
+        let written = copy_blocks(&mut Cursor::new(&disk), &mut output, 2048, 512, None).unwrap();
+        assert_eq!(written, 2048);
+        assert_eq!(output, disk);
+    }
+
+    #[test]
+    fn sparse_copy_skips_zero_blocks() {
+        let disk = disk();
+        let mut output = Cursor::new(Vec::new());
+        let written = copy_blocks(
+            &mut Cursor::new(&disk),
+            &mut output,
+            2048,
+            512,
+            Some(seek_relative),
+        )
+        .unwrap();
+        assert_eq!(written, 512 + 512 + 1);
+        assert_eq!(output.into_inner(), disk);
+    }
+
+    #[test]
+    fn truncated_input_fails() {
+        let disk = disk();
+        let mut output = Vec::new();
+        assert!(copy_blocks(&mut Cursor::new(&disk), &mut output, 4096, 512, None).is_err());
+    }
+}
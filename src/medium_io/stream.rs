@@ -0,0 +1,188 @@
+use crate::{MediumIO, VboxError};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+/// Largest request passed to [`MediumIO::read`] and [`MediumIO::write`] at once.
+pub(crate) const MAX_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// [`Read`] and [`Seek`] adapter over a [`MediumIO`].
+///
+/// Reads are split into chunks of at most 1 MiB and stop at the logical size of the medium.
+/// Wrap it in a [`std::io::BufReader`] when reading in small pieces, every call is a round trip
+/// to VBoxSVC.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::{Read, Seek, SeekFrom};
+/// use virtualbox_rs::{MediumReader, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let mediums = vbox.get_hard_disks().unwrap();
+/// let medium = mediums.get(0).unwrap();
+/// let medium_io = medium.open_for_io(false, "").unwrap();
+/// let mut reader = MediumReader::new(&medium_io).unwrap();
+/// reader.seek(SeekFrom::Start(510)).unwrap();
+/// let mut signature = [0u8; 2];
+/// reader.read_exact(&mut signature).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MediumReader<'a> {
+    medium_io: &'a MediumIO,
+    position: u64,
+    size: u64,
+}
+
+/// [`Write`] and [`Seek`] adapter over a writable [`MediumIO`].
+///
+/// Writes are split into chunks of at most 1 MiB. The medium is not resized, writing past its
+/// logical size fails.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::{Seek, SeekFrom, Write};
+/// use virtualbox_rs::{MediumWriter, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let mediums = vbox.get_hard_disks().unwrap();
+/// let medium = mediums.get(0).unwrap();
+/// let medium_io = medium.open_for_io(true, "").unwrap();
+/// let mut writer = MediumWriter::new(&medium_io).unwrap();
+/// writer.seek(SeekFrom::Start(510)).unwrap();
+/// writer.write_all(&[0x55, 0xAA]).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MediumWriter<'a> {
+    medium_io: &'a MediumIO,
+    position: u64,
+    size: u64,
+}
+
+impl<'a> MediumReader<'a> {
+    /// Creates a reader positioned at the start of the medium.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`MediumReader`] on success, or a [`VboxError`] on failure.
+    pub fn new(medium_io: &'a MediumIO) -> Result<Self, VboxError> {
+        Ok(Self {
+            medium_io,
+            position: 0,
+            size: logical_size(medium_io)?,
+        })
+    }
+
+    /// Logical size of the medium in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl<'a> MediumWriter<'a> {
+    /// Creates a writer positioned at the start of the medium.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`MediumWriter`] on success, or a [`VboxError`] on failure.
+    pub fn new(medium_io: &'a MediumIO) -> Result<Self, VboxError> {
+        Ok(Self {
+            medium_io,
+            position: 0,
+            size: logical_size(medium_io)?,
+        })
+    }
+
+    /// Logical size of the medium in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+fn logical_size(medium_io: &MediumIO) -> Result<u64, VboxError> {
+    let size = medium_io.get_medium()?.get_logical_size()?;
+    Ok(size.max(0) as u64)
+}
+
+impl Read for MediumReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+        let len = (self.size - self.position)
+            .min(buf.len() as u64)
+            .min(MAX_CHUNK_SIZE as u64) as u32;
+        let data = self.medium_io.read_to_vec(self.position as i64, len)?;
+        if data.is_empty() {
+            // Ok(0) before the end of the medium would read as a complete, truncated disk.
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!(
+                    "no data returned at offset {} of {} bytes",
+                    self.position, self.size
+                ),
+            ));
+        }
+        let count = data.len().min(len as usize);
+        buf[..count].copy_from_slice(&data[..count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Seek for MediumReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.position = seek_position(self.position, self.size, pos)?;
+        Ok(self.position)
+    }
+}
+
+impl Write for MediumWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        // MediumIO::write takes a mutable buffer.
+        let mut chunk = buf[..buf.len().min(MAX_CHUNK_SIZE)].to_vec();
+        let written = self.medium_io.write(self.position as i64, &mut chunk)?;
+        self.position += written as u64;
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for MediumWriter<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.position = seek_position(self.position, self.size, pos)?;
+        Ok(self.position)
+    }
+}
+
+fn seek_position(position: u64, size: u64, pos: SeekFrom) -> std::io::Result<u64> {
+    let (base, offset) = match pos {
+        SeekFrom::Start(offset) => return Ok(offset),
+        SeekFrom::End(offset) => (size, offset),
+        SeekFrom::Current(offset) => (position, offset),
+    };
+    base.checked_add_signed(offset).ok_or(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "invalid seek to a negative or overflowing position",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::seek_position;
+    use std::io::SeekFrom;
+
+    #[test]
+    fn seek_positions() {
+        assert_eq!(seek_position(10, 100, SeekFrom::Start(5)).unwrap(), 5);
+        assert_eq!(seek_position(10, 100, SeekFrom::Current(-4)).unwrap(), 6);
+        assert_eq!(seek_position(10, 100, SeekFrom::End(-1)).unwrap(), 99);
+        assert_eq!(seek_position(10, 100, SeekFrom::End(20)).unwrap(), 120);
+        assert!(seek_position(10, 100, SeekFrom::Current(-11)).is_err());
+    }
+}
@@ -0,0 +1,514 @@
+mod text_format;
+
+use crate::enums::{MachineState, SessionType};
+use crate::{Machine, PerformanceCollector, PerformanceObject, Session, VboxError, VirtualBox};
+use log::{debug, error};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+use text_format::MetricFamilies;
+
+/// Content type of the Prometheus text exposition format.
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+const MEGABYTE: f64 = 1024.0 * 1024.0;
+const KILOBYTE: f64 = 1024.0;
+/// Time a client gets to send the request and to receive the response, so a stalled client
+/// does not block the scrapes behind it.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Maximum size of the request line and headers.
+const MAX_REQUEST_HEAD_SIZE: u64 = 8192;
+
+/// Renders the state and resource usage of the host and all registered virtual machines in the
+/// Prometheus text exposition format and serves it over HTTP.
+///
+/// Machine samples carry the `machine`, `id` and `groups` labels. Performance collector metrics
+/// are exported as `vbox_<metric>` gauges holding the most recent sample, for example
+/// `vbox_cpu_load_user`; guest statistics require a shared session lock and are only collected
+/// for running machines when enabled with [`MetricsExporter::set_guest_statistics`].
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{MetricsExporter, VirtualBox};
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let mut exporter = MetricsExporter::new(vbox);
+/// exporter.setup_performance_metrics(10, 1).unwrap();
+/// exporter.set_guest_statistics(true);
+/// exporter.serve("127.0.0.1:9183").unwrap();
+/// ```
+pub struct MetricsExporter {
+    vbox: VirtualBox,
+    performance_collector: Option<PerformanceCollector>,
+    guest_statistics: bool,
+}
+
+impl MetricsExporter {
+    /// Creates an exporter for the given VirtualBox instance. Only the host and machine
+    /// settings and states are exported until the performance collector is set up.
+    pub fn new(vbox: VirtualBox) -> Self {
+        Self {
+            vbox,
+            performance_collector: None,
+            guest_statistics: false,
+        }
+    }
+
+    /// Enables collecting of all base performance metrics for the host and all machines.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - u32. Time interval in seconds between two consecutive samples.
+    /// * `count` - u32. Number of samples to retain.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{MetricsExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut exporter = MetricsExporter::new(vbox);
+    /// exporter.setup_performance_metrics(10, 1).unwrap();
+    pub fn setup_performance_metrics(&mut self, period: u32, count: u32) -> Result<(), VboxError> {
+        let performance_collector = self.vbox.get_performance_collector()?;
+        performance_collector.setup_metrics(vec!["*"], &[], period, count)?;
+        self.performance_collector = Some(performance_collector);
+        Ok(())
+    }
+
+    /// Enables exporting the statistics reported by the guest additions of running machines.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{MetricsExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut exporter = MetricsExporter::new(vbox);
+    /// exporter.set_guest_statistics(true);
+    pub fn set_guest_statistics(&mut self, enabled: bool) {
+        self.guest_statistics = enabled;
+    }
+
+    /// Collects the current metrics.
+    ///
+    /// # Returns
+    ///
+    /// Returns the metrics in the Prometheus text exposition format on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{MetricsExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let exporter = MetricsExporter::new(vbox);
+    /// println!("{}", exporter.render().unwrap());
+    pub fn render(&self) -> Result<String, VboxError> {
+        let mut families = MetricFamilies::new();
+        self.collect_host(&mut families)?;
+        self.collect_machines(&mut families)?;
+        self.collect_performance(&mut families)?;
+        Ok(families.render())
+    }
+
+    /// Serves the metrics at `/metrics` over HTTP. Blocks the current thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - &str. Address to listen on, for example `127.0.0.1:9183`.
+    ///
+    /// # Returns
+    ///
+    /// Returns () when the listener stops, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{MetricsExporter, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let exporter = MetricsExporter::new(vbox);
+    /// exporter.serve("127.0.0.1:9183").unwrap();
+    pub fn serve(&self, address: &str) -> Result<(), VboxError> {
+        let listener = TcpListener::bind(address)
+            .map_err(|err| VboxError::io_error("MetricsExporter::serve", err))?;
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    if let Err(err) = self.handle(stream) {
+                        error!("MetricsExporter request failed: {:?}", err);
+                    }
+                }
+                Err(err) => error!("MetricsExporter accept failed: {:?}", err),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+        let request_line = match read_request_head(stream.try_clone()?)? {
+            Some(request_line) => request_line,
+            None => {
+                let response = http_response(
+                    "431 Request Header Fields Too Large",
+                    "text/plain",
+                    "Request Header Fields Too Large\n",
+                );
+                stream.write_all(&response)?;
+                return stream.flush();
+            }
+        };
+        debug!("MetricsExporter request: {}", request_line.trim());
+        let response = match parse_request_line(&request_line) {
+            Some(("GET", "/metrics")) => match self.render() {
+                Ok(body) => http_response("200 OK", METRICS_CONTENT_TYPE, &body),
+                Err(err) => {
+                    error!("MetricsExporter render failed: {:?}", err);
+                    http_response(
+                        "500 Internal Server Error",
+                        "text/plain",
+                        &format!("{}\n", err),
+                    )
+                }
+            },
+            Some(("GET", "/")) => http_response(
+                "200 OK",
+                "text/html",
+                "<html><body><a href=\"/metrics\">Metrics</a></body></html>\n",
+            ),
+            Some(("GET", _)) => http_response("404 Not Found", "text/plain", "Not Found\n"),
+            Some(_) => http_response(
+                "405 Method Not Allowed",
+                "text/plain",
+                "Method Not Allowed\n",
+            ),
+            None => http_response("400 Bad Request", "text/plain", "Bad Request\n"),
+        };
+        stream.write_all(&response)?;
+        stream.flush()
+    }
+
+    fn collect_host(&self, families: &mut MetricFamilies) -> Result<(), VboxError> {
+        let host = self.vbox.get_host()?;
+        families.gauge(
+            "vbox_host_processor_count",
+            "Number of (logical) CPUs installed in the host system.",
+            &[],
+            host.get_processor_count()? as f64,
+        );
+        families.gauge(
+            "vbox_host_processor_online_count",
+            "Number of (logical) CPUs online in the host system.",
+            &[],
+            host.get_processor_online_count()? as f64,
+        );
+        families.gauge(
+            "vbox_host_memory_size_bytes",
+            "Amount of system memory of the host.",
+            &[],
+            host.get_memory_size()? as f64 * MEGABYTE,
+        );
+        families.gauge(
+            "vbox_host_memory_available_bytes",
+            "Available system memory of the host.",
+            &[],
+            host.get_memory_available()? as f64 * MEGABYTE,
+        );
+        Ok(())
+    }
+
+    fn collect_machines(&self, families: &mut MetricFamilies) -> Result<(), VboxError> {
+        let machines = self.vbox.get_machines()?;
+        let states = self.vbox.get_machine_states(machines.iter().collect())?;
+        for (machine, state) in machines.iter().zip(states) {
+            if let Err(err) = self.collect_machine(machine, state, families) {
+                debug!(
+                    "Skipping machine {}: {:?}",
+                    machine.get_id().unwrap_or("unknown"),
+                    err
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads every value of a machine before adding its samples, so a machine which fails half
+    /// way does not leave partial samples behind.
+    fn collect_machine(
+        &self,
+        machine: &Machine,
+        state: MachineState,
+        families: &mut MetricFamilies,
+    ) -> Result<(), VboxError> {
+        let id = machine.get_id()?;
+        if !machine.get_accessible()? {
+            families.gauge(
+                "vbox_machine_accessible",
+                "Whether the machine settings could be read.",
+                &[("id", id)],
+                0.0,
+            );
+            return Ok(());
+        }
+        let labels = MachineLabels::new(machine)?;
+        let labels = labels.as_slice();
+        let os_type_id = machine.get_os_type_id()?;
+        let cpu_count = machine.get_cpu_count()?;
+        let memory_size = machine.get_memory_size()?;
+        families.gauge(
+            "vbox_machine_accessible",
+            "Whether the machine settings could be read.",
+            &labels,
+            1.0,
+        );
+        families.gauge(
+            "vbox_machine_info",
+            "Information about the machine, the value is always 1.",
+            &[labels.as_slice(), &[("os_type", os_type_id)]].concat(),
+            1.0,
+        );
+        let state_name = state.to_string();
+        families.gauge(
+            "vbox_machine_state",
+            "Current execution state of the machine, the value is always 1.",
+            &[labels.as_slice(), &[("state", state_name.as_str())]].concat(),
+            1.0,
+        );
+        let running = matches!(state, MachineState::Running | MachineState::Paused);
+        families.gauge(
+            "vbox_machine_running",
+            "Whether the machine is running or paused.",
+            &labels,
+            if running { 1.0 } else { 0.0 },
+        );
+        families.gauge(
+            "vbox_machine_cpu_count",
+            "Number of virtual CPUs in the machine.",
+            &labels,
+            cpu_count as f64,
+        );
+        families.gauge(
+            "vbox_machine_memory_size_bytes",
+            "Amount of memory of the machine.",
+            &labels,
+            memory_size as f64 * MEGABYTE,
+        );
+        if self.guest_statistics && state == MachineState::Running {
+            if let Err(err) = collect_guest(machine, &labels, families) {
+                debug!("Guest statistics of {} are not available: {:?}", id, err);
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_performance(&self, families: &mut MetricFamilies) -> Result<(), VboxError> {
+        let performance_collector = match &self.performance_collector {
+            Some(performance_collector) => performance_collector,
+            None => return Ok(()),
+        };
+        for series in performance_collector.query_metrics_data(vec!["*"], &[])? {
+            if series.metric_name.contains(':') {
+                continue;
+            }
+            let value = match series.last_value() {
+                Some(value) => value,
+                None => continue,
+            };
+            let labels = match &series.object {
+                PerformanceObject::Host(_) => None,
+                PerformanceObject::Machine(machine) => match MachineLabels::new(machine) {
+                    Ok(labels) => Some(labels),
+                    Err(err) => {
+                        debug!("Skipping {} of a machine: {:?}", series.metric_name, err);
+                        continue;
+                    }
+                },
+            };
+            let labels = match &labels {
+                Some(labels) => labels.as_slice().to_vec(),
+                None => Vec::new(),
+            };
+            families.gauge(
+                &format!("vbox_{}", series.metric_name),
+                &format!(
+                    "VirtualBox performance metric {}, unit: {}.",
+                    series.metric_name, series.unit
+                ),
+                &labels,
+                value,
+            );
+        }
+        Ok(())
+    }
+}
+
+struct MachineLabels {
+    name: &'static str,
+    id: &'static str,
+    groups: String,
+}
+
+impl MachineLabels {
+    fn new(machine: &Machine) -> Result<Self, VboxError> {
+        Ok(Self {
+            name: machine.get_name()?,
+            id: machine.get_id()?,
+            groups: machine.get_groups()?.join(","),
+        })
+    }
+
+    fn as_slice(&self) -> [(&str, &str); 3] {
+        [
+            ("machine", self.name),
+            ("id", self.id),
+            ("groups", self.groups.as_str()),
+        ]
+    }
+}
+
+fn collect_guest(
+    machine: &Machine,
+    labels: &[(&str, &str)],
+    families: &mut MetricFamilies,
+) -> Result<(), VboxError> {
+    let mut session = Session::init()?;
+    machine.lock_machine(&mut session, SessionType::Shared)?;
+    let statistics = session
+        .get_console()
+        .and_then(|console| console.get_guest())
+        .and_then(|guest| guest.internal_get_statistics());
+    session.unlock_machine()?;
+    let statistics = statistics?;
+    let percents = [
+        ("user", statistics.cpu_user),
+        ("kernel", statistics.cpu_kernel),
+        ("idle", statistics.cpu_idle),
+    ];
+    for (mode, value) in percents {
+        families.gauge(
+            "vbox_guest_cpu_percent",
+            "Percentage of processor time as seen by the guest.",
+            &[labels, &[("mode", mode)]].concat(),
+            value as f64,
+        );
+    }
+    let memory = [
+        ("total", statistics.mem_total),
+        ("free", statistics.mem_free),
+        ("balloon", statistics.mem_balloon),
+        ("shared", statistics.mem_shared),
+        ("cache", statistics.mem_cache),
+        ("paged_total", statistics.paged_total),
+    ];
+    for (kind, value) in memory {
+        families.gauge(
+            "vbox_guest_memory_bytes",
+            "Guest memory as reported by the guest additions.",
+            &[labels, &[("kind", kind)]].concat(),
+            value as f64 * KILOBYTE,
+        );
+    }
+    Ok(())
+}
+
+/// Reads the request line and skips the headers. Returns `None` if they are larger than
+/// [`MAX_REQUEST_HEAD_SIZE`].
+fn read_request_head<R: Read>(reader: R) -> std::io::Result<Option<String>> {
+    let mut reader = BufReader::new(reader.take(MAX_REQUEST_HEAD_SIZE));
+    let mut request_line = String::new();
+    let mut size = reader.read_line(&mut request_line)?;
+    loop {
+        let mut header = String::new();
+        let read = reader.read_line(&mut header)?;
+        size += read;
+        if read == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+    if size as u64 >= MAX_REQUEST_HEAD_SIZE {
+        return Ok(None);
+    }
+    Ok(Some(request_line))
+}
+
+fn parse_request_line(line: &str) -> Option<(&str, &str)> {
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?;
+    let target = parts.next()?;
+    parts.next()?.strip_prefix("HTTP/")?;
+    let path = target.split('?').next().unwrap_or(target);
+    Some((method, path))
+}
+
+fn http_response(status: &str, content_type: &str, body: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{http_response, parse_request_line, read_request_head, MAX_REQUEST_HEAD_SIZE};
+
+    #[test]
+    fn parse_request_line_strips_query() {
+        assert_eq!(
+            parse_request_line("GET /metrics?x=1 HTTP/1.1\r\n"),
+            Some(("GET", "/metrics"))
+        );
+        assert_eq!(
+            parse_request_line("POST / HTTP/1.0\r\n"),
+            Some(("POST", "/"))
+        );
+        assert_eq!(parse_request_line("GET /metrics\r\n"), None);
+        assert_eq!(parse_request_line(""), None);
+    }
+
+    #[test]
+    fn http_response_has_content_length() {
+        let response = String::from_utf8(http_response("200 OK", "text/plain", "a 1\n")).unwrap();
+        assert_eq!(
+            response,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 4\r\nConnection: close\r\n\r\na 1\n"
+        );
+    }
+
+    #[test]
+    fn read_request_head_skips_headers() {
+        let request = "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        assert_eq!(
+            read_request_head(request.as_bytes()).unwrap().as_deref(),
+            Some("GET /metrics HTTP/1.1\r\n")
+        );
+    }
+
+    #[test]
+    fn read_request_head_rejects_oversized_headers() {
+        let request = format!(
+            "GET /metrics HTTP/1.1\r\nCookie: {}\r\n\r\n",
+            "a".repeat(MAX_REQUEST_HEAD_SIZE as usize)
+        );
+        assert_eq!(read_request_head(request.as_bytes()).unwrap(), None);
+    }
+}
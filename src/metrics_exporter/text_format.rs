@@ -0,0 +1,171 @@
+/// Gauge samples grouped into metric families, rendered in the Prometheus text exposition format.
+#[derive(Debug, Default)]
+pub(crate) struct MetricFamilies {
+    families: Vec<MetricFamily>,
+}
+
+#[derive(Debug)]
+struct MetricFamily {
+    name: String,
+    help: String,
+    samples: Vec<(Vec<(String, String)>, f64)>,
+}
+
+impl MetricFamilies {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sample to the gauge family `name`. The family is created on first use, with `help`
+    /// as its description.
+    pub(crate) fn gauge(&mut self, name: &str, help: &str, labels: &[(&str, &str)], value: f64) {
+        let name = metric_name(name);
+        let labels = labels
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        match self.families.iter_mut().find(|family| family.name == name) {
+            Some(family) => family.samples.push((labels, value)),
+            None => self.families.push(MetricFamily {
+                name,
+                help: help.to_string(),
+                samples: vec![(labels, value)],
+            }),
+        }
+    }
+
+    pub(crate) fn render(&self) -> String {
+        let mut out = String::new();
+        for family in &self.families {
+            out.push_str(&format!(
+                "# HELP {} {}\n# TYPE {} gauge\n",
+                family.name,
+                escape_help(&family.help),
+                family.name
+            ));
+            for (labels, value) in &family.samples {
+                out.push_str(&family.name);
+                if !labels.is_empty() {
+                    let labels: Vec<String> = labels
+                        .iter()
+                        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label_value(value)))
+                        .collect();
+                    out.push_str(&format!("{{{}}}", labels.join(",")));
+                }
+                out.push_str(&format!(" {}\n", format_value(*value)));
+            }
+        }
+        out
+    }
+}
+
+/// Turns an arbitrary name such as `CPU/Load/User` into a valid metric name (`cpu_load_user`).
+pub(crate) fn metric_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 {
+            "+Inf".to_string()
+        } else {
+            "-Inf".to_string()
+        }
+    } else {
+        format!("{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{metric_name, MetricFamilies};
+
+    #[test]
+    fn render_groups_samples_by_family() {
+        let mut families = MetricFamilies::new();
+        families.gauge(
+            "vbox_machine_running",
+            "Running machines.",
+            &[("machine", "a")],
+            1.0,
+        );
+        families.gauge("vbox_host_processor_count", "Host CPUs.", &[], 8.0);
+        families.gauge(
+            "vbox_machine_running",
+            "Running machines.",
+            &[("machine", "b")],
+            0.0,
+        );
+        assert_eq!(
+            families.render(),
+            "# HELP vbox_machine_running Running machines.\n\
+             # TYPE vbox_machine_running gauge\n\
+             vbox_machine_running{machine=\"a\"} 1\n\
+             vbox_machine_running{machine=\"b\"} 0\n\
+             # HELP vbox_host_processor_count Host CPUs.\n\
+             # TYPE vbox_host_processor_count gauge\n\
+             vbox_host_processor_count 8\n"
+        );
+    }
+
+    #[test]
+    fn render_escapes_label_values() {
+        let mut families = MetricFamilies::new();
+        families.gauge(
+            "vbox_machine_info",
+            "Line\\break\nhelp",
+            &[("machine", "say \"hi\"\\\n")],
+            0.5,
+        );
+        assert_eq!(
+            families.render(),
+            "# HELP vbox_machine_info Line\\\\break\\nhelp\n\
+             # TYPE vbox_machine_info gauge\n\
+             vbox_machine_info{machine=\"say \\\"hi\\\"\\\\\\n\"} 0.5\n"
+        );
+    }
+
+    #[test]
+    fn render_special_values() {
+        let mut families = MetricFamilies::new();
+        families.gauge("a", "", &[], f64::NAN);
+        families.gauge("a", "", &[], f64::INFINITY);
+        families.gauge("a", "", &[], f64::NEG_INFINITY);
+        assert!(families.render().ends_with("a NaN\na +Inf\na -Inf\n"));
+    }
+
+    #[test]
+    fn metric_names_are_sanitized() {
+        assert_eq!(metric_name("vbox_CPU/Load/User"), "vbox_cpu_load_user");
+        assert_eq!(
+            metric_name("vbox_Net/eth0/Load/Rx"),
+            "vbox_net_eth0_load_rx"
+        );
+        assert_eq!(metric_name("vbox_RAM//Usage"), "vbox_ram_usage");
+        assert_eq!(metric_name("1st"), "_1st");
+    }
+}
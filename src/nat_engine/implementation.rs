@@ -0,0 +1,805 @@
+use crate::enums::{NATAliasMode, NATProtocol};
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{NATEngine, PortForwardRule, VboxError};
+
+impl NATEngine {
+    /// The network attribute of the NAT engine in CIDR notation, the same as in the VBoxManage `--natnet` option.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let network = nat_engine.get_network().unwrap();
+    pub fn get_network(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetwork)
+    }
+
+    /// The network attribute of the NAT engine in CIDR notation, the same as in the VBoxManage `--natnet` option.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_network("10.0.3.0/24").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_network(&self, network: &str) -> Result<(), VboxError> {
+        let network = string_to_c_u64_str(network)?;
+        get_function_result_unit!(self.object, SetNetwork, network)
+    }
+
+    /// IP of the host interface to bind all opened sockets to. Empty to bind to all interfaces.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let host_ip = nat_engine.get_host_ip().unwrap();
+    pub fn get_host_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetHostIP)
+    }
+
+    /// IP of the host interface to bind all opened sockets to. Empty to bind to all interfaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `host_ip` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_host_ip("192.168.1.10").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_host_ip(&self, host_ip: &str) -> Result<(), VboxError> {
+        let host_ip = string_to_c_u64_str(host_ip)?;
+        get_function_result_unit!(self.object, SetHostIP, host_ip)
+    }
+
+    /// TFTP prefix attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let tftp_prefix = nat_engine.get_tftp_prefix().unwrap();
+    pub fn get_tftp_prefix(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetTFTPPrefix)
+    }
+
+    /// TFTP prefix attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Arguments
+    ///
+    /// * `tftp_prefix` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_tftp_prefix("/srv/tftp").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_tftp_prefix(&self, tftp_prefix: &str) -> Result<(), VboxError> {
+        let tftp_prefix = string_to_c_u64_str(tftp_prefix)?;
+        get_function_result_unit!(self.object, SetTFTPPrefix, tftp_prefix)
+    }
+
+    /// TFTP boot file attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let tftp_boot_file = nat_engine.get_tftp_boot_file().unwrap();
+    pub fn get_tftp_boot_file(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetTFTPBootFile)
+    }
+
+    /// TFTP boot file attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Arguments
+    ///
+    /// * `tftp_boot_file` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_tftp_boot_file("pxelinux.0").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_tftp_boot_file(&self, tftp_boot_file: &str) -> Result<(), VboxError> {
+        let tftp_boot_file = string_to_c_u64_str(tftp_boot_file)?;
+        get_function_result_unit!(self.object, SetTFTPBootFile, tftp_boot_file)
+    }
+
+    /// TFTP server attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let tftp_next_server = nat_engine.get_tftp_next_server().unwrap();
+    pub fn get_tftp_next_server(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetTFTPNextServer)
+    }
+
+    /// TFTP server attribute which is used with the built-in DHCP server to fill the corresponding fields of DHCP leases.
+    ///
+    /// # Arguments
+    ///
+    /// * `tftp_next_server` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_tftp_next_server("10.0.2.4").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_tftp_next_server(&self, tftp_next_server: &str) -> Result<(), VboxError> {
+        let tftp_next_server = string_to_c_u64_str(tftp_next_server)?;
+        get_function_result_unit!(self.object, SetTFTPNextServer, tftp_next_server)
+    }
+
+    /// Whether the DHCP server passes the host domain name to the guest.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let dns_pass_domain = nat_engine.get_dns_pass_domain().unwrap();
+    pub fn get_dns_pass_domain(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetDNSPassDomain)
+    }
+
+    /// Whether the DHCP server passes the host domain name to the guest.
+    ///
+    /// # Arguments
+    ///
+    /// * `dns_pass_domain` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_dns_pass_domain(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_dns_pass_domain(&self, dns_pass_domain: bool) -> Result<(), VboxError> {
+        let dns_pass_domain = if dns_pass_domain { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetDNSPassDomain, dns_pass_domain)
+    }
+
+    /// Whether the DHCP server passes the NAT DNS proxy address to the guest instead of the host DNS servers.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let dns_proxy = nat_engine.get_dns_proxy().unwrap();
+    pub fn get_dns_proxy(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetDNSProxy)
+    }
+
+    /// Whether the DHCP server passes the NAT DNS proxy address to the guest instead of the host DNS servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `dns_proxy` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_dns_proxy(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_dns_proxy(&self, dns_proxy: bool) -> Result<(), VboxError> {
+        let dns_proxy = if dns_proxy { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetDNSProxy, dns_proxy)
+    }
+
+    /// Whether the NAT engine resolves guest DNS requests with the host resolver API.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let dns_use_host_resolver = nat_engine.get_dns_use_host_resolver().unwrap();
+    pub fn get_dns_use_host_resolver(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetDNSUseHostResolver)
+    }
+
+    /// Whether the NAT engine resolves guest DNS requests with the host resolver API.
+    ///
+    /// # Arguments
+    ///
+    /// * `dns_use_host_resolver` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_dns_use_host_resolver(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_dns_use_host_resolver(&self, dns_use_host_resolver: bool) -> Result<(), VboxError> {
+        let dns_use_host_resolver = if dns_use_host_resolver { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetDNSUseHostResolver, dns_use_host_resolver)
+    }
+
+    /// Aliasing mode of the NAT engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<NATAliasMode>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let alias_mode = nat_engine.get_alias_mode().unwrap();
+    pub fn get_alias_mode(&self) -> Result<Vec<NATAliasMode>, VboxError> {
+        let alias_mode = get_function_result_number!(self.object, GetAliasMode, u32)?;
+        Ok(NATAliasMode::ALL
+            .into_iter()
+            .filter(|flag| {
+                let flag: u32 = (*flag).into();
+                alias_mode & flag != 0
+            })
+            .collect())
+    }
+
+    /// Aliasing mode of the NAT engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias_mode` - [`Vec<NATAliasMode>`]. Flags to set. An empty list resets the mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{NATAliasMode, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_alias_mode(vec![NATAliasMode::AliasUseSamePorts]).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_alias_mode(&self, alias_mode: Vec<NATAliasMode>) -> Result<(), VboxError> {
+        let alias_mode = alias_mode.into_iter().fold(0u32, |mode, flag| {
+            let flag: u32 = flag.into();
+            mode | flag
+        });
+        get_function_result_unit!(self.object, SetAliasMode, alias_mode)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Whether the guest can reach the host loopback interface through the NAT gateway.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let localhost_reachable = nat_engine.get_localhost_reachable().unwrap();
+    pub fn get_localhost_reachable(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetLocalhostReachable)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Whether the guest can reach the host loopback interface through the NAT gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `localhost_reachable` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_localhost_reachable(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_localhost_reachable(&self, localhost_reachable: bool) -> Result<(), VboxError> {
+        let localhost_reachable = if localhost_reachable { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetLocalhostReachable, localhost_reachable)
+    }
+
+    /// Port-forwarding rules of the NAT engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PortForwardRule>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let redirects = nat_engine.get_redirects().unwrap();
+    pub fn get_redirects(&self) -> Result<Vec<PortForwardRule>, VboxError> {
+        let redirects: Vec<&str> = get_function_result_str_vec!(self.object, GetRedirects)?;
+        redirects
+            .into_iter()
+            .map(PortForwardRule::parse_redirect)
+            .collect()
+    }
+
+    /// Sets network configuration of the NAT engine.
+    ///
+    /// A value of 0 keeps the default of the corresponding setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `mtu` - u32. MTU (maximum transmission unit).
+    /// * `sock_snd` - u32. Socket send buffer size in KB.
+    /// * `sock_rcv` - u32. Socket receive buffer size in KB.
+    /// * `tcp_wnd_snd` - u32. Initial size of the NAT engine's sending TCP window in KB.
+    /// * `tcp_wnd_rcv` - u32. Initial size of the NAT engine's receiving TCP window in KB.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.set_network_settings(0, 128, 128, 0, 0).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_network_settings(
+        &self,
+        mtu: u32,
+        sock_snd: u32,
+        sock_rcv: u32,
+        tcp_wnd_snd: u32,
+        tcp_wnd_rcv: u32,
+    ) -> Result<(), VboxError> {
+        get_function_result_unit!(
+            self.object,
+            SetNetworkSettings,
+            mtu,
+            sock_snd,
+            sock_rcv,
+            tcp_wnd_snd,
+            tcp_wnd_rcv
+        )
+    }
+
+    /// Returns network configuration of the NAT engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns (mtu, sock_snd, sock_rcv, tcp_wnd_snd, tcp_wnd_rcv), or a [`VboxError`] on failure.
+    /// - mtu: MTU (maximum transmission unit).
+    /// - sock_snd: Socket send buffer size in KB.
+    /// - sock_rcv: Socket receive buffer size in KB.
+    /// - tcp_wnd_snd: Initial size of the NAT engine's sending TCP window in KB.
+    /// - tcp_wnd_rcv: Initial size of the NAT engine's receiving TCP window in KB.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// let (mtu, sock_snd, sock_rcv, tcp_wnd_snd, tcp_wnd_rcv) =
+    ///     nat_engine.get_network_settings().unwrap();
+    pub fn get_network_settings(&self) -> Result<(u32, u32, u32, u32, u32), VboxError> {
+        let mut mtu = 0;
+        let mut sock_snd = 0;
+        let mut sock_rcv = 0;
+        let mut tcp_wnd_snd = 0;
+        let mut tcp_wnd_rcv = 0;
+        get_function_result_unit!(
+            self.object,
+            GetNetworkSettings,
+            &mut mtu,
+            &mut sock_snd,
+            &mut sock_rcv,
+            &mut tcp_wnd_snd,
+            &mut tcp_wnd_rcv
+        )?;
+        Ok((mtu, sock_snd, sock_rcv, tcp_wnd_snd, tcp_wnd_rcv))
+    }
+
+    /// Adds a new NAT port-forwarding rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The name of the rule. An empty name is acceptable, in which case the NAT engine auto-generates one using the other parameters.
+    /// * `proto` - [`NATProtocol`]. Protocol handled with the rule.
+    /// * `host_ip` - &str. IP of the host interface to which the rule should apply. An empty ip address is acceptable, in which case the NAT engine binds the handling socket to any interface.
+    /// * `host_port` - u16. The port number to listen on.
+    /// * `guest_ip` - &str. The IP address of the guest which the NAT engine will forward matching packets to. An empty IP address is acceptable, in which case the NAT engine will forward packets to the first DHCP lease (x.x.x.15).
+    /// * `guest_port` - u16. The port number to forward.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{NATProtocol, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine
+    ///     .add_redirect("ssh", NATProtocol::TCP, "127.0.0.1", 2222, "", 22)
+    ///     .unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn add_redirect(
+        &self,
+        name: &str,
+        proto: NATProtocol,
+        host_ip: &str,
+        host_port: u16,
+        guest_ip: &str,
+        guest_port: u16,
+    ) -> Result<(), VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        let proto: u32 = proto.into();
+        let host_ip = string_to_c_u64_str(host_ip)?;
+        let guest_ip = string_to_c_u64_str(guest_ip)?;
+        get_function_result_unit!(
+            self.object,
+            AddRedirect,
+            name,
+            proto,
+            host_ip,
+            host_port,
+            guest_ip,
+            guest_port
+        )
+    }
+
+    /// Removes a port-forwarding rule that was previously registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - &str. The name of the rule to delete.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let network_adapter = machine_mut.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    /// nat_engine.remove_redirect("ssh").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn remove_redirect(&self, name: &str) -> Result<(), VboxError> {
+        let name = string_to_c_u64_str(name)?;
+        get_function_result_unit!(self.object, RemoveRedirect, name)
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl NATEngine {
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_localhost_reachable(&self) -> Result<bool, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "NATEngine::get_localhost_reachable",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_localhost_reachable(&self, _localhost_reachable: bool) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "NATEngine::set_localhost_reachable",
+            "v7_0",
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::INATEngine;
+
+/// Interface for managing the NAT engine of a network adapter attached to NAT.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_n_a_t_engine.html](https://www.virtualbox.org/sdkref/interface_i_n_a_t_engine.html)
+#[derive(Debug)]
+pub struct NATEngine {
+    object: *mut INATEngine,
+}
+
+impl NATEngine {
+    pub(crate) fn new(object: *mut INATEngine) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for NATEngine {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("NATEngine refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop NATEngine. Error: {:?}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,608 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_pointer,
+    get_function_result_str, get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{EventSource, NATNetwork, PortForwardRule, VboxError};
+use vbox_raw::sys_lib::IEventSource;
+
+impl NATNetwork {
+    /// The name of the NAT network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let network_name = network.get_network_name().unwrap();
+    pub fn get_network_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetworkName)
+    }
+
+    /// The name of the NAT network.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_name` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_network_name("NatNetwork").unwrap();
+    pub fn set_network_name(&self, network_name: &str) -> Result<(), VboxError> {
+        let network_name = string_to_c_u64_str(network_name)?;
+        get_function_result_unit!(self.object, SetNetworkName, network_name)
+    }
+
+    /// Whether the NAT network is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let enabled = network.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Whether the NAT network is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_enabled(true).unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// The IPv4 network of the NAT network in CIDR notation.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let network = network.get_network().unwrap();
+    pub fn get_network(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetNetwork)
+    }
+
+    /// The IPv4 network of the NAT network in CIDR notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_network("10.0.2.0/24").unwrap();
+    pub fn set_network(&self, network: &str) -> Result<(), VboxError> {
+        let network = string_to_c_u64_str(network)?;
+        get_function_result_unit!(self.object, SetNetwork, network)
+    }
+
+    /// The IPv4 address of the gateway, always the first address of the network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let gateway = network.get_gateway().unwrap();
+    pub fn get_gateway(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetGateway)
+    }
+
+    /// Whether IPv6 is enabled in the NAT network.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let ipv6_enabled = network.get_ipv6_enabled().unwrap();
+    pub fn get_ipv6_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetIPv6Enabled)
+    }
+
+    /// Whether IPv6 is enabled in the NAT network.
+    ///
+    /// # Arguments
+    ///
+    /// * `ipv6_enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_ipv6_enabled(true).unwrap();
+    pub fn set_ipv6_enabled(&self, ipv6_enabled: bool) -> Result<(), VboxError> {
+        let ipv6_enabled = if ipv6_enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetIPv6Enabled, ipv6_enabled)
+    }
+
+    /// The IPv6 prefix of the NAT network.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let ipv6_prefix = network.get_ipv6_prefix().unwrap();
+    pub fn get_ipv6_prefix(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetIPv6Prefix)
+    }
+
+    /// The IPv6 prefix of the NAT network.
+    ///
+    /// # Arguments
+    ///
+    /// * `ipv6_prefix` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_ipv6_prefix("fd17:625c:f037:2::/64").unwrap();
+    pub fn set_ipv6_prefix(&self, ipv6_prefix: &str) -> Result<(), VboxError> {
+        let ipv6_prefix = string_to_c_u64_str(ipv6_prefix)?;
+        get_function_result_unit!(self.object, SetIPv6Prefix, ipv6_prefix)
+    }
+
+    /// Whether the default IPv6 route is advertised to the guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let advertise_default_ipv6_route_enabled = network.get_advertise_default_ipv6_route_enabled().unwrap();
+    pub fn get_advertise_default_ipv6_route_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetAdvertiseDefaultIPv6RouteEnabled)
+    }
+
+    /// Whether the default IPv6 route is advertised to the guests.
+    ///
+    /// # Arguments
+    ///
+    /// * `advertise_default_ipv6_route_enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_advertise_default_ipv6_route_enabled(true).unwrap();
+    pub fn set_advertise_default_ipv6_route_enabled(
+        &self,
+        advertise_default_ipv6_route_enabled: bool,
+    ) -> Result<(), VboxError> {
+        let advertise_default_ipv6_route_enabled = if advertise_default_ipv6_route_enabled {
+            1
+        } else {
+            0
+        };
+        get_function_result_unit!(
+            self.object,
+            SetAdvertiseDefaultIPv6RouteEnabled,
+            advertise_default_ipv6_route_enabled
+        )
+    }
+
+    /// Whether the NAT network needs a DHCP server.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let need_dhcp_server = network.get_need_dhcp_server().unwrap();
+    pub fn get_need_dhcp_server(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetNeedDhcpServer)
+    }
+
+    /// Whether the NAT network needs a DHCP server.
+    ///
+    /// # Arguments
+    ///
+    /// * `need_dhcp_server` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_need_dhcp_server(true).unwrap();
+    pub fn set_need_dhcp_server(&self, need_dhcp_server: bool) -> Result<(), VboxError> {
+        let need_dhcp_server = if need_dhcp_server { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetNeedDhcpServer, need_dhcp_server)
+    }
+
+    /// Offset in the IPv6 network of the address mapped to the host loopback interface.
+    ///
+    /// # Returns
+    ///
+    /// Returns i32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let loopback_ip6 = network.get_loopback_ip6().unwrap();
+    pub fn get_loopback_ip6(&self) -> Result<i32, VboxError> {
+        get_function_result_number!(self.object, GetLoopbackIp6, i32)
+    }
+
+    /// Offset in the IPv6 network of the address mapped to the host loopback interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `loopback_ip6` - i32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.set_loopback_ip6(2).unwrap();
+    pub fn set_loopback_ip6(&self, loopback_ip6: i32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetLoopbackIp6, loopback_ip6)
+    }
+
+    /// Event source for NAT network events.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`EventSource`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let event_source = network.get_event_source().unwrap();
+    pub fn get_event_source(&self) -> Result<EventSource, VboxError> {
+        let event_source =
+            get_function_result_pointer!(self.object, GetEventSource, *mut IEventSource)?;
+        Ok(EventSource::new(event_source))
+    }
+
+    /// IPv4 port-forwarding rules of the NAT network.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PortForwardRule>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let rules = network.get_port_forward_rules4().unwrap();
+    pub fn get_port_forward_rules4(&self) -> Result<Vec<PortForwardRule>, VboxError> {
+        let rules: Vec<&str> = get_function_result_str_vec!(self.object, GetPortForwardRules4)?;
+        rules.into_iter().map(PortForwardRule::parse).collect()
+    }
+
+    /// IPv6 port-forwarding rules of the NAT network.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PortForwardRule>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let rules = network.get_port_forward_rules6().unwrap();
+    pub fn get_port_forward_rules6(&self) -> Result<Vec<PortForwardRule>, VboxError> {
+        let rules: Vec<&str> = get_function_result_str_vec!(self.object, GetPortForwardRules6)?;
+        rules.into_iter().map(PortForwardRule::parse).collect()
+    }
+
+    /// Mappings of host loopback addresses to offsets in the IPv4 network,
+    /// in the form `127.0.0.2=2`.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let local_mappings = network.get_local_mappings().unwrap();
+    pub fn get_local_mappings(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetLocalMappings)
+    }
+
+    /// Maps a host loopback address to an offset in the IPv4 network.
+    ///
+    /// # Arguments
+    ///
+    /// * `host_id` - &str. Host loopback address.
+    /// * `offset` - i32. Offset in the network. 0 removes the mapping.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.add_local_mapping("127.0.0.2", 2).unwrap();
+    pub fn add_local_mapping(&self, host_id: &str, offset: i32) -> Result<(), VboxError> {
+        let host_id = string_to_c_u64_str(host_id)?;
+        get_function_result_unit!(self.object, AddLocalMapping, host_id, offset)
+    }
+
+    /// Adds a port-forwarding rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_ipv6` - bool. Whether the rule is an IPv6 rule.
+    /// * `rule` - &[`PortForwardRule`]. Rule to add.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::enums::NATProtocol;
+    /// use virtualbox_rs::{PortForwardRule, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// let rule = PortForwardRule {
+    ///     name: "ssh".to_string(),
+    ///     proto: NATProtocol::TCP,
+    ///     host_ip: String::new(),
+    ///     host_port: 2222,
+    ///     guest_ip: "10.0.2.15".to_string(),
+    ///     guest_port: 22,
+    /// };
+    /// network.add_port_forward_rule(false, &rule).unwrap();
+    pub fn add_port_forward_rule(
+        &self,
+        is_ipv6: bool,
+        rule: &PortForwardRule,
+    ) -> Result<(), VboxError> {
+        let is_ipv6 = if is_ipv6 { 1 } else { 0 };
+        let rule_name = string_to_c_u64_str(&rule.name)?;
+        let proto: u32 = rule.proto.into();
+        let host_ip = string_to_c_u64_str(&rule.host_ip)?;
+        let guest_ip = string_to_c_u64_str(&rule.guest_ip)?;
+        get_function_result_unit!(
+            self.object,
+            AddPortForwardRule,
+            is_ipv6,
+            rule_name,
+            proto,
+            host_ip,
+            rule.host_port,
+            guest_ip,
+            rule.guest_port
+        )
+    }
+
+    /// Removes a port-forwarding rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_ipv6` - bool. Whether the rule is an IPv6 rule.
+    /// * `rule_name` - &str. Name of the rule.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.remove_port_forward_rule(false, "ssh").unwrap();
+    pub fn remove_port_forward_rule(
+        &self,
+        is_ipv6: bool,
+        rule_name: &str,
+    ) -> Result<(), VboxError> {
+        let is_ipv6 = if is_ipv6 { 1 } else { 0 };
+        let rule_name = string_to_c_u64_str(rule_name)?;
+        get_function_result_unit!(self.object, RemovePortForwardRule, is_ipv6, rule_name)
+    }
+
+    /// Starts the NAT network service.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.start().unwrap();
+    pub fn start(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Start)
+    }
+
+    /// Stops the NAT network service.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let network = vbox.find_nat_network_by_name("NatNetwork").unwrap();
+    /// network.stop().unwrap();
+    pub fn stop(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Stop)
+    }
+}
@@ -1,8 +1,13 @@
+mod implementation;
+mod port_forward_rule;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::INATNetwork;
 
+pub use port_forward_rule::PortForwardRule;
+
 /// **Reference to the official documentation:**
 ///
 /// [https://www.virtualbox.org/sdkref/interface_i_n_a_t_network.html](https://www.virtualbox.org/sdkref/interface_i_n_a_t_network.html)
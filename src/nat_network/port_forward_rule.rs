@@ -0,0 +1,148 @@
+use crate::enums::NATProtocol;
+use crate::VboxError;
+use std::fmt::Display;
+
+/// Port-forwarding rule of a NAT network or of the NAT engine of a network adapter.
+///
+/// NAT networks report the rules as strings of the form
+/// `name:proto:[host-ip]:host-port:[guest-ip]:guest-port`, NAT engines as
+/// `name,proto,host-ip,host-port,guest-ip,guest-port` with a numeric protocol.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PortForwardRule {
+    /// Name of the rule.
+    pub name: String,
+    /// Protocol of the forwarded port.
+    pub proto: NATProtocol,
+    /// Host address the rule listens on. Empty to listen on all addresses.
+    pub host_ip: String,
+    /// Host port the rule listens on.
+    pub host_port: u16,
+    /// Guest address the connections are forwarded to.
+    pub guest_ip: String,
+    /// Guest port the connections are forwarded to.
+    pub guest_port: u16,
+}
+
+impl PortForwardRule {
+    /// Parses a rule in the format reported by [`crate::NATNetwork::get_port_forward_rules4`].
+    pub(crate) fn parse(rule: &str) -> Result<Self, VboxError> {
+        let invalid = || {
+            VboxError::invalid_data(
+                "PortForwardRule::parse",
+                format!("Invalid port-forwarding rule: {}", rule),
+            )
+        };
+
+        let (rest, guest_port) = rule.rsplit_once(':').ok_or_else(invalid)?;
+        let (rest, guest_ip) = split_bracketed(rest).ok_or_else(invalid)?;
+        let (rest, host_port) = rest.rsplit_once(':').ok_or_else(invalid)?;
+        let (rest, host_ip) = split_bracketed(rest).ok_or_else(invalid)?;
+        let (name, proto) = rest.rsplit_once(':').ok_or_else(invalid)?;
+        let proto = match proto.to_ascii_lowercase().as_str() {
+            "tcp" => NATProtocol::TCP,
+            "udp" => NATProtocol::UDP,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            proto,
+            host_ip: host_ip.to_string(),
+            host_port: host_port.parse().map_err(|_| invalid())?,
+            guest_ip: guest_ip.to_string(),
+            guest_port: guest_port.parse().map_err(|_| invalid())?,
+        })
+    }
+
+    /// Parses a redirect in the format reported by [`crate::NATEngine::get_redirects`].
+    pub(crate) fn parse_redirect(redirect: &str) -> Result<Self, VboxError> {
+        let invalid = || {
+            VboxError::invalid_data(
+                "PortForwardRule::parse_redirect",
+                format!("Invalid NAT redirect: {}", redirect),
+            )
+        };
+
+        let fields: Vec<&str> = redirect.rsplitn(6, ',').collect();
+        if fields.len() != 6 {
+            return Err(invalid());
+        }
+        let proto: u32 = fields[4].parse().map_err(|_| invalid())?;
+
+        Ok(Self {
+            name: fields[5].to_string(),
+            proto: NATProtocol::from(proto),
+            host_ip: fields[3].to_string(),
+            host_port: fields[2].parse().map_err(|_| invalid())?,
+            guest_ip: fields[1].to_string(),
+            guest_port: fields[0].parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Splits `prefix:[value]` into `(prefix, value)`.
+fn split_bracketed(s: &str) -> Option<(&str, &str)> {
+    let s = s.strip_suffix(']')?;
+    let (prefix, value) = s.rsplit_once('[')?;
+    Some((prefix.strip_suffix(':')?, value))
+}
+
+impl Display for PortForwardRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let proto = match self.proto {
+            NATProtocol::TCP => "tcp",
+            NATProtocol::UDP => "udp",
+        };
+        write!(
+            f,
+            "{}:{}:[{}]:{}:[{}]:{}",
+            self.name, proto, self.host_ip, self.host_port, self.guest_ip, self.guest_port
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PortForwardRule;
+    use crate::enums::NATProtocol;
+
+    #[test]
+    fn parse_ipv4_rule() {
+        let rule = PortForwardRule::parse("ssh:tcp:[]:2222:[10.0.2.15]:22").unwrap();
+        assert_eq!(rule.name, "ssh");
+        assert_eq!(rule.proto, NATProtocol::TCP);
+        assert_eq!(rule.host_ip, "");
+        assert_eq!(rule.host_port, 2222);
+        assert_eq!(rule.guest_ip, "10.0.2.15");
+        assert_eq!(rule.guest_port, 22);
+        assert_eq!(rule.to_string(), "ssh:tcp:[]:2222:[10.0.2.15]:22");
+    }
+
+    #[test]
+    fn parse_ipv6_rule() {
+        let rule = PortForwardRule::parse("dns:udp:[::1]:5353:[fd17:625c:f037:2::5]:53").unwrap();
+        assert_eq!(rule.proto, NATProtocol::UDP);
+        assert_eq!(rule.host_ip, "::1");
+        assert_eq!(rule.guest_ip, "fd17:625c:f037:2::5");
+        assert_eq!(rule.guest_port, 53);
+    }
+
+    #[test]
+    fn parse_engine_redirect() {
+        let rule = PortForwardRule::parse_redirect("guest ssh,1,127.0.0.1,2222,,22").unwrap();
+        assert_eq!(rule.name, "guest ssh");
+        assert_eq!(rule.proto, NATProtocol::TCP);
+        assert_eq!(rule.host_ip, "127.0.0.1");
+        assert_eq!(rule.host_port, 2222);
+        assert_eq!(rule.guest_ip, "");
+        assert_eq!(rule.guest_port, 22);
+        assert!(PortForwardRule::parse_redirect("ssh,1,,2222,22").is_err());
+    }
+
+    #[test]
+    fn parse_invalid_rule() {
+        assert!(PortForwardRule::parse("ssh:tcp:2222:22").is_err());
+        assert!(PortForwardRule::parse("ssh:icmp:[]:1:[]:2").is_err());
+        assert!(PortForwardRule::parse("ssh:tcp:[]:70000:[]:22").is_err());
+    }
+}
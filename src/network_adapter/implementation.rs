@@ -4,8 +4,8 @@ use crate::utility::macros::macros::{
     get_function_result_str, get_function_result_unit,
 };
 use crate::utility::string_to_c_u64_str;
-use crate::{BandwidthGroup, NetworkAdapter, VboxError};
-use vbox_raw::sys_lib::IBandwidthGroup;
+use crate::{BandwidthGroup, NATEngine, NetworkAdapter, VboxError};
+use vbox_raw::sys_lib::{IBandwidthGroup, INATEngine};
 
 impl NetworkAdapter {
     /// Type of the virtual network adapter.
@@ -845,7 +845,29 @@ impl NetworkAdapter {
         get_function_result_unit!(self.object, SetTraceFile, trace_file_ptr)
     }
 
-    // TODO GetNATEngine
+    /// Points to the NAT engine which handles the network address translation for this interface.
+    ///
+    /// This is active only when the interface actually uses NAT.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`NATEngine`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let network_adapter = machine.get_network_adapter(0).unwrap();
+    /// let nat_engine = network_adapter.get_nat_engine().unwrap();
+    pub fn get_nat_engine(&self) -> Result<NATEngine, VboxError> {
+        let nat_engine = get_function_result_pointer!(self.object, GetNATEngine, *mut INATEngine)?;
+        Ok(NATEngine::new(nat_engine))
+    }
 
     /// Network boot priority of the adapter.
     ///
@@ -1,3 +1,5 @@
+use crate::VboxError;
+
 /// Placeholder Struct
 ///
 /// This struct serves as a placeholder for versions of the API where the actual struct is not available.
@@ -7,3 +9,200 @@
 /// Supported from API version: v7_0
 #[derive(Debug)]
 pub struct HostOnlyNetwork {}
+
+impl HostOnlyNetwork {
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_network_name(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_network_name",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_network_name(&self, _network_name: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_network_name",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_enabled",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_enabled(&self, _enabled: bool) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_enabled",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_network_mask(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_network_mask",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_network_mask(&self, _network_mask: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_network_mask",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_host_ip(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_host_ip",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_lower_ip(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_lower_ip",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_lower_ip(&self, _lower_ip: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_lower_ip",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_upper_ip(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_upper_ip",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_upper_ip(&self, _upper_ip: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_upper_ip",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_id(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::get_id",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_id(&self, _id: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "HostOnlyNetwork::set_id",
+            "v7_0",
+        ))
+    }
+}
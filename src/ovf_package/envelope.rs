@@ -0,0 +1,600 @@
+use crate::VboxError;
+use xmltree::{Element, XMLNode};
+
+const VBOX_NAMESPACE: &str = "http://www.virtualbox.org/ovf/machine";
+
+/// A file listed in the `References` section of an OVF descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfFile {
+    /// Identifier the disks refer to.
+    pub id: String,
+    /// File name, relative to the descriptor.
+    pub href: String,
+    /// Size in bytes, if the descriptor states it.
+    pub size: Option<u64>,
+}
+
+/// A virtual disk from the `DiskSection` of an OVF descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfDisk {
+    /// Identifier the hardware items refer to as `/disk/<disk_id>` or `ovf:/disk/<disk_id>`.
+    pub disk_id: String,
+    /// Identifier of the [`OvfFile`] holding the disk image. Empty disks have none.
+    pub file_ref: Option<String>,
+    /// Virtual size in bytes.
+    pub capacity: u64,
+    /// Estimated amount of data in bytes.
+    pub populated_size: Option<u64>,
+    /// Disk format URI, for example `http://www.vmware.com/interfaces/specifications/vmdk.html#streamOptimized`.
+    pub format: String,
+    /// UUID VirtualBox assigned to the disk, from the `vbox:uuid` attribute.
+    pub uuid: Option<String>,
+}
+
+/// A logical network from the `NetworkSection` of an OVF descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfNetwork {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// CIM resource type of a virtual hardware item.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OvfResourceType {
+    Other,
+    Processor,
+    Memory,
+    IdeController,
+    ParallelScsiHba,
+    EthernetAdapter,
+    FloppyDrive,
+    CdDrive,
+    DvdDrive,
+    DiskDrive,
+    OtherStorageDevice,
+    UsbController,
+    SoundCard,
+    Unknown(u32),
+}
+
+impl From<u32> for OvfResourceType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => OvfResourceType::Other,
+            3 => OvfResourceType::Processor,
+            4 => OvfResourceType::Memory,
+            5 => OvfResourceType::IdeController,
+            6 => OvfResourceType::ParallelScsiHba,
+            10 => OvfResourceType::EthernetAdapter,
+            14 => OvfResourceType::FloppyDrive,
+            15 => OvfResourceType::CdDrive,
+            16 => OvfResourceType::DvdDrive,
+            17 => OvfResourceType::DiskDrive,
+            20 => OvfResourceType::OtherStorageDevice,
+            23 => OvfResourceType::UsbController,
+            35 => OvfResourceType::SoundCard,
+            _ => OvfResourceType::Unknown(value),
+        }
+    }
+}
+
+/// An `Item` (or OVF 2.0 `StorageItem`/`EthernetPortItem`) of a `VirtualHardwareSection`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfHardwareItem {
+    pub instance_id: String,
+    pub resource_type: OvfResourceType,
+    /// Model of the device, for example `E1000`, `AHCI` or `PIIX4`.
+    pub resource_sub_type: Option<String>,
+    pub element_name: Option<String>,
+    pub virtual_quantity: Option<u64>,
+    /// Units of `virtual_quantity`, for example `MegaBytes` or `byte * 2^20`.
+    pub allocation_units: Option<String>,
+    /// Disk reference of disk drives (`/disk/vmdisk1`) or image of CD drives.
+    pub host_resource: Option<String>,
+    /// Logical network of network adapters.
+    pub connection: Option<String>,
+    /// Instance ID of the controller the device is attached to.
+    pub parent: Option<String>,
+    pub address_on_parent: Option<String>,
+}
+
+/// Machine settings from the VirtualBox specific `vbox:Machine` section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfVBoxMachine {
+    pub uuid: Option<String>,
+    pub name: Option<String>,
+    pub os_type: Option<String>,
+    /// Settings file format version, for example `1.19-linux`.
+    pub version: Option<String>,
+}
+
+/// A virtual machine of an OVF descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfVirtualSystem {
+    /// The `ovf:id` attribute.
+    pub id: String,
+    /// The `Name` element, or the name of the `vbox:Machine` section, or `id`.
+    pub name: String,
+    /// CIM operating system ID from the `OperatingSystemSection`.
+    pub os_id: Option<u32>,
+    /// VirtualBox guest OS type, or the description of the `OperatingSystemSection`.
+    pub os_type: Option<String>,
+    pub product: Option<String>,
+    pub vendor: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub cpu_count: Option<u32>,
+    pub memory_bytes: Option<u64>,
+    /// Identifiers of the [`OvfDisk`]s attached to the machine.
+    pub disk_ids: Vec<String>,
+    /// Logical networks the network adapters are connected to.
+    pub networks: Vec<String>,
+    pub hardware: Vec<OvfHardwareItem>,
+    pub vbox_machine: Option<OvfVBoxMachine>,
+}
+
+/// The parsed content of an OVF descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfEnvelope {
+    /// OVF version, `1.0` or `2.0`.
+    pub version: String,
+    pub files: Vec<OvfFile>,
+    pub disks: Vec<OvfDisk>,
+    pub networks: Vec<OvfNetwork>,
+    pub virtual_systems: Vec<OvfVirtualSystem>,
+}
+
+impl OvfEnvelope {
+    pub(crate) fn parse(descriptor: &[u8]) -> Result<Self, VboxError> {
+        let envelope = Element::parse(descriptor).map_err(|err| {
+            VboxError::invalid_data("OvfEnvelope::parse", format!("Invalid XML: {}", err))
+        })?;
+        if envelope.name != "Envelope" {
+            return Err(VboxError::invalid_data(
+                "OvfEnvelope::parse",
+                format!("Root element is {}, expected Envelope", envelope.name),
+            ));
+        }
+        let version = match envelope.attributes.get("version") {
+            Some(version) => version.clone(),
+            None if envelope.namespace.as_deref()
+                == Some("http://schemas.dmtf.org/ovf/envelope/2") =>
+            {
+                "2.0".to_string()
+            }
+            None => "1.0".to_string(),
+        };
+        let files = match envelope.get_child("References") {
+            Some(references) => child_elements(references, "File")
+                .map(|file| {
+                    Ok(OvfFile {
+                        id: required_attribute(file, "id")?,
+                        href: required_attribute(file, "href")?,
+                        size: optional_number(file, "size")?,
+                    })
+                })
+                .collect::<Result<_, VboxError>>()?,
+            None => Vec::new(),
+        };
+        let disks = match envelope.get_child("DiskSection") {
+            Some(disk_section) => child_elements(disk_section, "Disk")
+                .map(parse_disk)
+                .collect::<Result<_, VboxError>>()?,
+            None => Vec::new(),
+        };
+        let networks = match envelope.get_child("NetworkSection") {
+            Some(network_section) => child_elements(network_section, "Network")
+                .map(|network| {
+                    Ok(OvfNetwork {
+                        name: required_attribute(network, "name")?,
+                        description: child_text(network, "Description"),
+                    })
+                })
+                .collect::<Result<_, VboxError>>()?,
+            None => Vec::new(),
+        };
+        let mut virtual_systems = Vec::new();
+        collect_virtual_systems(&envelope, &mut virtual_systems)?;
+        if virtual_systems.is_empty() {
+            return Err(VboxError::invalid_data(
+                "OvfEnvelope::parse",
+                "No VirtualSystem found".to_string(),
+            ));
+        }
+        Ok(Self {
+            version,
+            files,
+            disks,
+            networks,
+            virtual_systems,
+        })
+    }
+}
+
+fn collect_virtual_systems(
+    parent: &Element,
+    virtual_systems: &mut Vec<OvfVirtualSystem>,
+) -> Result<(), VboxError> {
+    for element in parent.children.iter().filter_map(XMLNode::as_element) {
+        match element.name.as_str() {
+            "VirtualSystem" => virtual_systems.push(parse_virtual_system(element)?),
+            "VirtualSystemCollection" => collect_virtual_systems(element, virtual_systems)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn parse_disk(disk: &Element) -> Result<OvfDisk, VboxError> {
+    let capacity = required_attribute(disk, "capacity")?;
+    let capacity: u64 = capacity.trim().parse().map_err(|_| {
+        VboxError::invalid_data(
+            "OvfEnvelope::parse",
+            format!("Invalid disk capacity: {}", capacity),
+        )
+    })?;
+    let multiplier = match disk.attributes.get("capacityAllocationUnits") {
+        Some(units) => allocation_units_multiplier(units).ok_or(VboxError::invalid_data(
+            "OvfEnvelope::parse",
+            format!("Unsupported capacityAllocationUnits: {}", units),
+        ))?,
+        None => 1,
+    };
+    Ok(OvfDisk {
+        disk_id: required_attribute(disk, "diskId")?,
+        file_ref: disk.attributes.get("fileRef").cloned(),
+        capacity: capacity.saturating_mul(multiplier),
+        populated_size: optional_number(disk, "populatedSize")?,
+        format: disk.attributes.get("format").cloned().unwrap_or_default(),
+        uuid: disk.attributes.get("uuid").cloned(),
+    })
+}
+
+fn parse_virtual_system(system: &Element) -> Result<OvfVirtualSystem, VboxError> {
+    let id = required_attribute(system, "id")?;
+    let vbox_machine = system
+        .children
+        .iter()
+        .filter_map(XMLNode::as_element)
+        .find(|element| {
+            element.name == "Machine" && element.namespace.as_deref() == Some(VBOX_NAMESPACE)
+        })
+        .map(|machine| OvfVBoxMachine {
+            uuid: machine.attributes.get("uuid").cloned(),
+            name: machine.attributes.get("name").cloned(),
+            os_type: machine.attributes.get("OSType").cloned(),
+            version: machine.attributes.get("version").cloned(),
+        });
+    let name = child_text(system, "Name")
+        .or_else(|| {
+            vbox_machine
+                .as_ref()
+                .and_then(|machine| machine.name.clone())
+        })
+        .unwrap_or_else(|| id.clone());
+    let os_section = system.get_child("OperatingSystemSection");
+    let os_id = os_section.and_then(|section| section.attributes.get("id")?.parse().ok());
+    let os_type = os_section
+        .and_then(|section| child_text(section, "OSType").or(child_text(section, "Description")))
+        .or_else(|| {
+            vbox_machine
+                .as_ref()
+                .and_then(|machine| machine.os_type.clone())
+        });
+    let product_section = system.get_child("ProductSection");
+    let product_text = |name: &str| product_section.and_then(|section| child_text(section, name));
+    let description = system
+        .get_child("AnnotationSection")
+        .and_then(|section| child_text(section, "Annotation"));
+    let hardware = match system.get_child("VirtualHardwareSection") {
+        Some(section) => section
+            .children
+            .iter()
+            .filter_map(XMLNode::as_element)
+            .filter(|element| {
+                matches!(
+                    element.name.as_str(),
+                    "Item" | "StorageItem" | "EthernetPortItem"
+                )
+            })
+            .map(parse_hardware_item)
+            .collect::<Result<Vec<_>, VboxError>>()?,
+        None => Vec::new(),
+    };
+    let cpu_count = hardware
+        .iter()
+        .find(|item| item.resource_type == OvfResourceType::Processor)
+        .and_then(|item| item.virtual_quantity)
+        .map(|count| count as u32);
+    let memory_bytes = match hardware
+        .iter()
+        .find(|item| item.resource_type == OvfResourceType::Memory)
+    {
+        Some(item) => {
+            let units = item.allocation_units.as_deref().unwrap_or("MegaBytes");
+            let multiplier = allocation_units_multiplier(units).ok_or(VboxError::invalid_data(
+                "OvfEnvelope::parse",
+                format!("Unsupported memory AllocationUnits: {}", units),
+            ))?;
+            item.virtual_quantity
+                .map(|quantity| quantity.saturating_mul(multiplier))
+        }
+        None => None,
+    };
+    let disk_ids = hardware
+        .iter()
+        .filter(|item| item.resource_type == OvfResourceType::DiskDrive)
+        .filter_map(|item| item.host_resource.as_deref())
+        .filter_map(disk_reference)
+        .collect();
+    let networks = hardware
+        .iter()
+        .filter(|item| item.resource_type == OvfResourceType::EthernetAdapter)
+        .filter_map(|item| item.connection.clone())
+        .collect();
+    Ok(OvfVirtualSystem {
+        id,
+        name,
+        os_id,
+        os_type,
+        product: product_text("Product"),
+        vendor: product_text("Vendor"),
+        version: product_text("Version"),
+        description,
+        cpu_count,
+        memory_bytes,
+        disk_ids,
+        networks,
+        hardware,
+        vbox_machine,
+    })
+}
+
+fn parse_hardware_item(item: &Element) -> Result<OvfHardwareItem, VboxError> {
+    let resource_type = child_text(item, "ResourceType").ok_or(VboxError::invalid_data(
+        "OvfEnvelope::parse",
+        "Hardware item without ResourceType".to_string(),
+    ))?;
+    let resource_type: u32 = resource_type.parse().map_err(|_| {
+        VboxError::invalid_data(
+            "OvfEnvelope::parse",
+            format!("Invalid ResourceType: {}", resource_type),
+        )
+    })?;
+    let virtual_quantity = match child_text(item, "VirtualQuantity") {
+        Some(quantity) => Some(quantity.parse().map_err(|_| {
+            VboxError::invalid_data(
+                "OvfEnvelope::parse",
+                format!("Invalid VirtualQuantity: {}", quantity),
+            )
+        })?),
+        None => None,
+    };
+    Ok(OvfHardwareItem {
+        instance_id: child_text(item, "InstanceID")
+            .or_else(|| child_text(item, "InstanceId"))
+            .unwrap_or_default(),
+        resource_type: OvfResourceType::from(resource_type),
+        resource_sub_type: child_text(item, "ResourceSubType"),
+        element_name: child_text(item, "ElementName"),
+        virtual_quantity,
+        allocation_units: child_text(item, "AllocationUnits"),
+        host_resource: child_text(item, "HostResource"),
+        connection: child_text(item, "Connection"),
+        parent: child_text(item, "Parent"),
+        address_on_parent: child_text(item, "AddressOnParent"),
+    })
+}
+
+/// Extracts the disk ID from a host resource such as `/disk/vmdisk1` or `ovf:/disk/vmdisk1`.
+fn disk_reference(host_resource: &str) -> Option<String> {
+    let reference = host_resource.strip_prefix("ovf:").unwrap_or(host_resource);
+    reference
+        .strip_prefix("/disk/")
+        .map(|disk_id| disk_id.to_string())
+}
+
+/// Parses units like `MegaBytes`, `byte * 2^30` or `byte*10^9` into a byte multiplier.
+pub(crate) fn allocation_units_multiplier(units: &str) -> Option<u64> {
+    let units: String = units.chars().filter(|c| !c.is_whitespace()).collect();
+    match units.to_ascii_lowercase().as_str() {
+        "byte" | "bytes" => return Some(1),
+        "kilobytes" | "kb" => return Some(1 << 10),
+        "megabytes" | "mb" => return Some(1 << 20),
+        "gigabytes" | "gb" => return Some(1 << 30),
+        "terabytes" | "tb" => return Some(1 << 40),
+        _ => {}
+    }
+    let (base_unit, exponent) = units.split_once('*')?;
+    if !base_unit.eq_ignore_ascii_case("byte") {
+        return None;
+    }
+    let (base, exponent) = exponent.split_once('^')?;
+    let base: u64 = base.parse().ok()?;
+    let exponent: u32 = exponent.parse().ok()?;
+    base.checked_pow(exponent)
+}
+
+fn child_elements<'a>(parent: &'a Element, name: &'a str) -> impl Iterator<Item = &'a Element> {
+    parent
+        .children
+        .iter()
+        .filter_map(XMLNode::as_element)
+        .filter(move |element| element.name == name)
+}
+
+fn child_text(parent: &Element, name: &str) -> Option<String> {
+    child_elements(parent, name)
+        .next()
+        .and_then(|element| element.get_text())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+fn required_attribute(element: &Element, name: &str) -> Result<String, VboxError> {
+    element
+        .attributes
+        .get(name)
+        .cloned()
+        .ok_or(VboxError::invalid_data(
+            "OvfEnvelope::parse",
+            format!("{} element without {} attribute", element.name, name),
+        ))
+}
+
+fn optional_number(element: &Element, name: &str) -> Result<Option<u64>, VboxError> {
+    match element.attributes.get(name) {
+        Some(value) => value.trim().parse().map(Some).map_err(|_| {
+            VboxError::invalid_data(
+                "OvfEnvelope::parse",
+                format!("Invalid {} attribute: {}", name, value),
+            )
+        }),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{allocation_units_multiplier, OvfEnvelope, OvfResourceType};
+
+    pub(crate) const DESCRIPTOR: &str = r#"<?xml version="1.0"?>
+<Envelope ovf:version="1.0" xml:lang="en-US" xmlns="http://schemas.dmtf.org/ovf/envelope/1" xmlns:ovf="http://schemas.dmtf.org/ovf/envelope/1" xmlns:rasd="http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/CIM_ResourceAllocationSettingData" xmlns:vssd="http://schemas.dmtf.org/wbem/wscim/1/cim-schema/2/CIM_VirtualSystemSettingData" xmlns:vbox="http://www.virtualbox.org/ovf/machine">
+  <References>
+    <File ovf:id="file1" ovf:href="Freebsd_14-disk001.vmdk"/>
+  </References>
+  <DiskSection>
+    <Info>List of the virtual disks used in the package</Info>
+    <Disk ovf:capacity="20" ovf:capacityAllocationUnits="byte * 2^30" ovf:diskId="vmdisk1" ovf:fileRef="file1" ovf:format="http://www.vmware.com/interfaces/specifications/vmdk.html#streamOptimized" vbox:uuid="8a3f4c8e-1b2d-4e5f-9a6b-7c8d9e0f1a2b"/>
+  </DiskSection>
+  <NetworkSection>
+    <Info>Logical networks used in the package</Info>
+    <Network ovf:name="NAT">
+      <Description>Logical network used by this appliance.</Description>
+    </Network>
+  </NetworkSection>
+  <VirtualSystem ovf:id="Freebsd_14">
+    <Info>A virtual machine</Info>
+    <ProductSection>
+      <Info>Meta-information about the installed software</Info>
+      <Product>FreeBSD appliance</Product>
+      <Vendor>Example Inc.</Vendor>
+      <Version>14.1</Version>
+    </ProductSection>
+    <OperatingSystemSection ovf:id="78">
+      <Info>The kind of installed guest operating system</Info>
+      <Description>FreeBSD_64</Description>
+      <vbox:OSType ovf:required="false">FreeBSD_64</vbox:OSType>
+    </OperatingSystemSection>
+    <VirtualHardwareSection>
+      <Info>Virtual hardware requirements for a virtual machine</Info>
+      <System>
+        <vssd:ElementName>Virtual Hardware Family</vssd:ElementName>
+        <vssd:InstanceID>0</vssd:InstanceID>
+        <vssd:VirtualSystemType>virtualbox-2.2</vssd:VirtualSystemType>
+      </System>
+      <Item>
+        <rasd:ElementName>2 virtual CPU</rasd:ElementName>
+        <rasd:InstanceID>1</rasd:InstanceID>
+        <rasd:ResourceType>3</rasd:ResourceType>
+        <rasd:VirtualQuantity>2</rasd:VirtualQuantity>
+      </Item>
+      <Item>
+        <rasd:AllocationUnits>MegaBytes</rasd:AllocationUnits>
+        <rasd:ElementName>2048 MB of memory</rasd:ElementName>
+        <rasd:InstanceID>2</rasd:InstanceID>
+        <rasd:ResourceType>4</rasd:ResourceType>
+        <rasd:VirtualQuantity>2048</rasd:VirtualQuantity>
+      </Item>
+      <Item>
+        <rasd:ElementName>sataController0</rasd:ElementName>
+        <rasd:InstanceID>3</rasd:InstanceID>
+        <rasd:ResourceSubType>AHCI</rasd:ResourceSubType>
+        <rasd:ResourceType>20</rasd:ResourceType>
+      </Item>
+      <Item>
+        <rasd:AddressOnParent>0</rasd:AddressOnParent>
+        <rasd:ElementName>disk1</rasd:ElementName>
+        <rasd:HostResource>/disk/vmdisk1</rasd:HostResource>
+        <rasd:InstanceID>4</rasd:InstanceID>
+        <rasd:Parent>3</rasd:Parent>
+        <rasd:ResourceType>17</rasd:ResourceType>
+      </Item>
+      <Item>
+        <rasd:AutomaticAllocation>true</rasd:AutomaticAllocation>
+        <rasd:Connection>NAT</rasd:Connection>
+        <rasd:ElementName>Ethernet adapter on 'NAT'</rasd:ElementName>
+        <rasd:InstanceID>5</rasd:InstanceID>
+        <rasd:ResourceSubType>E1000</rasd:ResourceSubType>
+        <rasd:ResourceType>10</rasd:ResourceType>
+      </Item>
+    </VirtualHardwareSection>
+    <vbox:Machine ovf:required="false" version="1.19-linux" uuid="{5c7e0b9a-3f1d-4a2b-8c6e-9d0f1a2b3c4d}" name="Freebsd_14" OSType="FreeBSD_64">
+      <ovf:Info>Complete VirtualBox machine configuration in VirtualBox format</ovf:Info>
+    </vbox:Machine>
+  </VirtualSystem>
+</Envelope>
+"#;
+
+    #[test]
+    fn parse_virtualbox_descriptor() {
+        let envelope = OvfEnvelope::parse(DESCRIPTOR.as_bytes()).unwrap();
+        assert_eq!(envelope.version, "1.0");
+        assert_eq!(envelope.files.len(), 1);
+        assert_eq!(envelope.files[0].href, "Freebsd_14-disk001.vmdk");
+        assert_eq!(envelope.files[0].size, None);
+        assert_eq!(envelope.disks.len(), 1);
+        assert_eq!(envelope.disks[0].disk_id, "vmdisk1");
+        assert_eq!(envelope.disks[0].file_ref.as_deref(), Some("file1"));
+        assert_eq!(envelope.disks[0].capacity, 20 << 30);
+        assert_eq!(envelope.networks.len(), 1);
+        assert_eq!(envelope.networks[0].name, "NAT");
+
+        let system = &envelope.virtual_systems[0];
+        assert_eq!(system.id, "Freebsd_14");
+        assert_eq!(system.name, "Freebsd_14");
+        assert_eq!(system.os_id, Some(78));
+        assert_eq!(system.os_type.as_deref(), Some("FreeBSD_64"));
+        assert_eq!(system.product.as_deref(), Some("FreeBSD appliance"));
+        assert_eq!(system.vendor.as_deref(), Some("Example Inc."));
+        assert_eq!(system.version.as_deref(), Some("14.1"));
+        assert_eq!(system.cpu_count, Some(2));
+        assert_eq!(system.memory_bytes, Some(2048 << 20));
+        assert_eq!(system.disk_ids, vec!["vmdisk1"]);
+        assert_eq!(system.networks, vec!["NAT"]);
+        assert_eq!(system.hardware.len(), 5);
+        assert_eq!(
+            system.hardware[2].resource_type,
+            OvfResourceType::OtherStorageDevice
+        );
+        assert_eq!(
+            system.hardware[2].resource_sub_type.as_deref(),
+            Some("AHCI")
+        );
+        let machine = system.vbox_machine.as_ref().unwrap();
+        assert_eq!(machine.name.as_deref(), Some("Freebsd_14"));
+        assert_eq!(
+            machine.uuid.as_deref(),
+            Some("{5c7e0b9a-3f1d-4a2b-8c6e-9d0f1a2b3c4d}")
+        );
+        assert_eq!(machine.version.as_deref(), Some("1.19-linux"));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_descriptors() {
+        assert!(OvfEnvelope::parse(b"<Envelope>").is_err());
+        assert!(OvfEnvelope::parse(b"<Machine/>").is_err());
+        assert!(OvfEnvelope::parse(b"<Envelope><References/></Envelope>").is_err());
+    }
+
+    #[test]
+    fn allocation_units() {
+        assert_eq!(allocation_units_multiplier("MegaBytes"), Some(1 << 20));
+        assert_eq!(allocation_units_multiplier("byte * 2^20"), Some(1 << 20));
+        assert_eq!(
+            allocation_units_multiplier("byte*10^9"),
+            Some(1_000_000_000)
+        );
+        assert_eq!(allocation_units_multiplier("hertz * 10^6"), None);
+    }
+}
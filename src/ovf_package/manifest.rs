@@ -0,0 +1,166 @@
+use crate::VboxError;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::fmt::Display;
+use std::io::Read;
+
+/// Digest algorithm of a manifest entry.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DigestAlgorithm {
+    /// SHA-1, used by OVF 1.x appliances.
+    Sha1,
+    /// SHA-256, used by OVF 2.0 appliances.
+    Sha256,
+}
+
+impl Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DigestAlgorithm::Sha1 => write!(f, "SHA1"),
+            DigestAlgorithm::Sha256 => write!(f, "SHA256"),
+        }
+    }
+}
+
+/// One line of an OVF manifest (`.mf`) file, for example `SHA256(vm.ovf)= 3b1f...`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub algorithm: DigestAlgorithm,
+    pub file_name: String,
+    /// Lowercase hex digest.
+    pub digest: String,
+}
+
+pub(crate) fn parse_manifest(content: &str) -> Result<Vec<ManifestEntry>, VboxError> {
+    let mut entries = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let invalid_line = || {
+            VboxError::invalid_data(
+                "OvfPackage::parse_manifest",
+                format!("Invalid manifest line {}: {}", i + 1, line),
+            )
+        };
+        let (algorithm, rest) = line.split_once('(').ok_or_else(invalid_line)?;
+        let (file_name, digest) = rest.rsplit_once(")=").ok_or_else(invalid_line)?;
+        let algorithm = match algorithm.trim().to_ascii_uppercase().as_str() {
+            "SHA1" => DigestAlgorithm::Sha1,
+            "SHA256" => DigestAlgorithm::Sha256,
+            other => {
+                return Err(VboxError::invalid_data(
+                    "OvfPackage::parse_manifest",
+                    format!("Unsupported digest algorithm {} on line {}", other, i + 1),
+                ))
+            }
+        };
+        let digest = digest.trim().to_ascii_lowercase();
+        let digest_len = match algorithm {
+            DigestAlgorithm::Sha1 => 40,
+            DigestAlgorithm::Sha256 => 64,
+        };
+        if digest.len() != digest_len || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid_line());
+        }
+        entries.push(ManifestEntry {
+            algorithm,
+            file_name: file_name.to_string(),
+            digest,
+        });
+    }
+    Ok(entries)
+}
+
+/// Hashes a stream with the requested algorithms in one pass.
+///
+/// Returns the number of bytes read and the lowercase hex digests, in the order of `algorithms`.
+pub(crate) fn digest_stream<R: Read>(
+    reader: &mut R,
+    algorithms: &[DigestAlgorithm],
+) -> Result<(u64, Vec<String>), VboxError> {
+    let mut sha1 = algorithms.contains(&DigestAlgorithm::Sha1).then(Sha1::new);
+    let mut sha256 = algorithms
+        .contains(&DigestAlgorithm::Sha256)
+        .then(Sha256::new);
+    let mut buffer = vec![0u8; 1024 * 1024];
+    let mut size = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(VboxError::io_error("OvfPackage::digest", err)),
+        };
+        size += read as u64;
+        if let Some(sha1) = &mut sha1 {
+            sha1.update(&buffer[..read]);
+        }
+        if let Some(sha256) = &mut sha256 {
+            sha256.update(&buffer[..read]);
+        }
+    }
+    let sha1 = sha1.map(|sha1| to_hex(&sha1.finalize()));
+    let sha256 = sha256.map(|sha256| to_hex(&sha256.finalize()));
+    let digests = algorithms
+        .iter()
+        .map(|algorithm| match algorithm {
+            DigestAlgorithm::Sha1 => sha1.clone().unwrap_or_default(),
+            DigestAlgorithm::Sha256 => sha256.clone().unwrap_or_default(),
+        })
+        .collect();
+    Ok((size, digests))
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digest_stream, parse_manifest, DigestAlgorithm};
+    use std::io::Cursor;
+
+    #[test]
+    fn parse_manifest_lines() {
+        let entries = parse_manifest(
+            "SHA1(vm.ovf)= A9993E364706816ABA3E25717850C26C9CD0D89D\n\
+             SHA256 (vm-disk (1).vmdk)=ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\n\n",
+        )
+        .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].algorithm, DigestAlgorithm::Sha1);
+        assert_eq!(entries[0].file_name, "vm.ovf");
+        assert_eq!(
+            entries[0].digest,
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(entries[1].algorithm, DigestAlgorithm::Sha256);
+        assert_eq!(entries[1].file_name, "vm-disk (1).vmdk");
+    }
+
+    #[test]
+    fn parse_manifest_rejects_invalid_lines() {
+        assert!(parse_manifest("SHA1(vm.ovf) a9993e36").is_err());
+        assert!(parse_manifest("SHA1(vm.ovf)= a9993e36").is_err());
+        assert!(parse_manifest("MD5(vm.ovf)= 900150983cd24fb0d6963f7d28e17f72").is_err());
+    }
+
+    #[test]
+    fn digest_stream_computes_both_algorithms() {
+        let (size, digests) = digest_stream(
+            &mut Cursor::new(b"abc"),
+            &[DigestAlgorithm::Sha256, DigestAlgorithm::Sha1],
+        )
+        .unwrap();
+        assert_eq!(size, 3);
+        assert_eq!(
+            digests,
+            vec![
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "a9993e364706816aba3e25717850c26c9cd0d89d"
+            ]
+        );
+    }
+}
@@ -0,0 +1,543 @@
+mod envelope;
+mod manifest;
+mod signer;
+mod tar;
+
+use crate::VboxError;
+pub use envelope::{
+    OvfDisk, OvfEnvelope, OvfFile, OvfHardwareItem, OvfNetwork, OvfResourceType, OvfVBoxMachine,
+    OvfVirtualSystem,
+};
+pub(crate) use manifest::digest_stream;
+pub use manifest::{DigestAlgorithm, ManifestEntry};
+pub use signer::OvfSigner;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+
+/// Offline reader and validator of OVF appliances.
+///
+/// Opens an `.ova` archive, an `.ovf` descriptor or a directory holding one, without a VirtualBox
+/// installation. The descriptor is parsed into an [`OvfEnvelope`] summarizing the virtual
+/// systems, disks and networks, and [`OvfPackage::verify`] checks every file against the SHA-1 or
+/// SHA-256 digests of the `.mf` manifest.
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::OvfPackage;
+///
+/// let package = OvfPackage::open("/home/user/iso/FreeBSD_14.ova").unwrap();
+/// for system in &package.get_envelope().virtual_systems {
+///     println!("{} {:?} {:?}", system.name, system.cpu_count, system.memory_bytes);
+/// }
+/// package.validate().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct OvfPackage {
+    source: PackageSource,
+    descriptor_name: String,
+    envelope: OvfEnvelope,
+    manifest: Option<Vec<ManifestEntry>>,
+}
+
+#[derive(Debug)]
+enum PackageSource {
+    Archive {
+        path: PathBuf,
+        entries: Vec<tar::TarEntry>,
+    },
+    Directory(PathBuf),
+}
+
+/// Result of checking one file of an [`OvfPackage`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvfFileCheck {
+    pub file_name: String,
+    /// Size stated in the descriptor `References`.
+    pub expected_size: Option<u64>,
+    /// Size of the file in the package, `None` if the file is missing.
+    pub actual_size: Option<u64>,
+    pub algorithm: Option<DigestAlgorithm>,
+    /// Digest from the manifest, `None` if the file is not listed in the manifest.
+    pub expected_digest: Option<String>,
+    pub actual_digest: Option<String>,
+    /// The package has a manifest which does not list the file.
+    pub missing_from_manifest: bool,
+}
+
+impl OvfFileCheck {
+    /// Whether the file is present and its size and digest match the descriptor and manifest.
+    ///
+    /// When the package has a manifest, a file it does not list is never valid.
+    pub fn is_valid(&self) -> bool {
+        if self.missing_from_manifest {
+            return false;
+        }
+        let size_matches = match (self.expected_size, self.actual_size) {
+            (_, None) => false,
+            (Some(expected), Some(actual)) => expected == actual,
+            (None, Some(_)) => true,
+        };
+        size_matches && self.expected_digest == self.actual_digest
+    }
+}
+
+impl OvfPackage {
+    /// Opens an appliance.
+    ///
+    /// Only the descriptor and the manifest are read, disk images are not touched until
+    /// [`OvfPackage::verify`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Path to an `.ova` archive, an `.ovf` descriptor, or a directory containing exactly one `.ovf` descriptor.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`OvfPackage`] on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::OvfPackage;
+    ///
+    /// let package = OvfPackage::open("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// ```
+    pub fn open(path: &str) -> Result<Self, VboxError> {
+        let path = Path::new(path);
+        if path.is_dir() {
+            let descriptor = find_descriptor(path)?;
+            return Self::open_directory(path, &descriptor);
+        }
+        let is_ovf = path
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("ovf"))
+            .unwrap_or(false);
+        if is_ovf {
+            let directory = path.parent().unwrap_or(Path::new("."));
+            let descriptor = file_name(path)?;
+            return Self::open_directory(directory, &descriptor);
+        }
+        Self::open_archive(path)
+    }
+
+    fn open_directory(directory: &Path, descriptor_name: &str) -> Result<Self, VboxError> {
+        let descriptor = std::fs::read(directory.join(descriptor_name))
+            .map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+        let envelope = OvfEnvelope::parse(&descriptor)?;
+        let manifest_path = directory.join(manifest_name(descriptor_name));
+        let manifest = if manifest_path.is_file() {
+            let manifest = std::fs::read(manifest_path)
+                .map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+            Some(manifest::parse_manifest(&String::from_utf8_lossy(
+                &manifest,
+            ))?)
+        } else {
+            None
+        };
+        Ok(Self {
+            source: PackageSource::Directory(directory.to_path_buf()),
+            descriptor_name: descriptor_name.to_string(),
+            envelope,
+            manifest,
+        })
+    }
+
+    fn open_archive(path: &Path) -> Result<Self, VboxError> {
+        let mut file =
+            File::open(path).map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+        let entries = tar::read_index(&mut file)?;
+        let descriptor_entry = entries
+            .iter()
+            .find(|entry| entry.name.to_ascii_lowercase().ends_with(".ovf"))
+            .ok_or(VboxError::invalid_data(
+                "OvfPackage::open",
+                "The archive does not contain an OVF descriptor".to_string(),
+            ))?;
+        let descriptor = read_entry(&mut file, descriptor_entry)?;
+        let envelope = OvfEnvelope::parse(&descriptor)?;
+        let manifest_name = manifest_name(&descriptor_entry.name);
+        let manifest = match entries.iter().find(|entry| entry.name == manifest_name) {
+            Some(entry) => {
+                let manifest = read_entry(&mut file, entry)?;
+                Some(manifest::parse_manifest(&String::from_utf8_lossy(
+                    &manifest,
+                ))?)
+            }
+            None => None,
+        };
+        Ok(Self {
+            descriptor_name: descriptor_entry.name.clone(),
+            source: PackageSource::Archive {
+                path: path.to_path_buf(),
+                entries,
+            },
+            envelope,
+            manifest,
+        })
+    }
+
+    /// The parsed OVF descriptor.
+    pub fn get_envelope(&self) -> &OvfEnvelope {
+        &self.envelope
+    }
+
+    /// File name of the OVF descriptor inside the package.
+    pub fn get_descriptor_name(&self) -> &str {
+        &self.descriptor_name
+    }
+
+    /// Entries of the `.mf` manifest, `None` if the package has no manifest.
+    pub fn get_manifest(&self) -> Option<&[ManifestEntry]> {
+        self.manifest.as_deref()
+    }
+
+    /// Checks the descriptor, every file listed in the descriptor `References` and every file
+    /// listed in the manifest.
+    ///
+    /// Files are streamed, so large disk images are not loaded into memory.
+    ///
+    /// # Returns
+    ///
+    /// Returns one [`OvfFileCheck`] per file on success, or a [`VboxError`] if the package cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::OvfPackage;
+    ///
+    /// let package = OvfPackage::open("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// for check in package.verify().unwrap() {
+    ///     println!("{} {}", check.file_name, check.is_valid());
+    /// }
+    /// ```
+    pub fn verify(&self) -> Result<Vec<OvfFileCheck>, VboxError> {
+        let mut file_names = vec![self.descriptor_name.clone()];
+        for file in &self.envelope.files {
+            if !file_names.contains(&file.href) {
+                file_names.push(file.href.clone());
+            }
+        }
+        for entry in self.manifest.iter().flatten() {
+            if !file_names.contains(&entry.file_name) {
+                file_names.push(entry.file_name.clone());
+            }
+        }
+        let mut archive = match &self.source {
+            PackageSource::Archive { path, .. } => Some(
+                File::open(path).map_err(|err| VboxError::io_error("OvfPackage::verify", err))?,
+            ),
+            PackageSource::Directory(_) => None,
+        };
+        let mut checks = Vec::with_capacity(file_names.len());
+        for file_name in file_names {
+            let expected_size = self
+                .envelope
+                .files
+                .iter()
+                .find(|file| file.href == file_name)
+                .and_then(|file| file.size);
+            let manifest_entry = self
+                .manifest
+                .iter()
+                .flatten()
+                .find(|entry| entry.file_name == file_name);
+            let algorithms: Vec<DigestAlgorithm> = manifest_entry
+                .map(|entry| entry.algorithm)
+                .into_iter()
+                .collect();
+            let digest = match (&self.source, &mut archive) {
+                (PackageSource::Archive { entries, .. }, Some(archive)) => {
+                    match entries.iter().find(|entry| entry.name == file_name) {
+                        Some(entry) => {
+                            archive
+                                .seek(SeekFrom::Start(entry.offset))
+                                .map_err(|err| VboxError::io_error("OvfPackage::verify", err))?;
+                            Some(manifest::digest_stream(
+                                &mut archive.take(entry.size),
+                                &algorithms,
+                            )?)
+                        }
+                        None => None,
+                    }
+                }
+                (PackageSource::Directory(directory), _) => {
+                    match open_package_file(directory, &file_name)? {
+                        Some(mut file) => Some(manifest::digest_stream(&mut file, &algorithms)?),
+                        None => None,
+                    }
+                }
+                _ => None,
+            };
+            let (actual_size, actual_digest) = match digest {
+                Some((size, digests)) => (Some(size), digests.into_iter().next()),
+                None => (None, None),
+            };
+            checks.push(OvfFileCheck {
+                file_name,
+                expected_size,
+                actual_size,
+                algorithm: manifest_entry.map(|entry| entry.algorithm),
+                expected_digest: manifest_entry.map(|entry| entry.digest.clone()),
+                actual_digest,
+                missing_from_manifest: self.manifest.is_some() && manifest_entry.is_none(),
+            });
+        }
+        Ok(checks)
+    }
+
+    /// Verifies the package and fails on the first missing or corrupt file.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::OvfPackage;
+    ///
+    /// let package = OvfPackage::open("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// package.validate().unwrap();
+    /// ```
+    pub fn validate(&self) -> Result<(), VboxError> {
+        for disk in &self.envelope.disks {
+            if let Some(file_ref) = &disk.file_ref {
+                if !self.envelope.files.iter().any(|file| &file.id == file_ref) {
+                    return Err(VboxError::invalid_data(
+                        "OvfPackage::validate",
+                        format!("Disk {} refers to unknown file {}", disk.disk_id, file_ref),
+                    ));
+                }
+            }
+        }
+        for check in self.verify()? {
+            if check.is_valid() {
+                continue;
+            }
+            let msg = if check.actual_size.is_none() {
+                format!("{} is missing", check.file_name)
+            } else if check.missing_from_manifest {
+                format!("{} is not listed in the manifest", check.file_name)
+            } else if check.expected_digest != check.actual_digest {
+                format!(
+                    "{} digest mismatch, {} expected: {}, actual: {}",
+                    check.file_name,
+                    check.algorithm.map(|a| a.to_string()).unwrap_or_default(),
+                    check.expected_digest.unwrap_or_default(),
+                    check.actual_digest.unwrap_or_default()
+                )
+            } else {
+                format!(
+                    "{} size mismatch, expected: {}, actual: {}",
+                    check.file_name,
+                    check.expected_size.unwrap_or_default(),
+                    check.actual_size.unwrap_or_default()
+                )
+            };
+            return Err(VboxError::invalid_data("OvfPackage::validate", msg));
+        }
+        Ok(())
+    }
+}
+
+fn find_descriptor(directory: &Path) -> Result<String, VboxError> {
+    let mut descriptors = Vec::new();
+    for entry in
+        std::fs::read_dir(directory).map_err(|err| VboxError::io_error("OvfPackage::open", err))?
+    {
+        let entry = entry.map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.to_ascii_lowercase().ends_with(".ovf") {
+            descriptors.push(name);
+        }
+    }
+    match descriptors.len() {
+        1 => Ok(descriptors.remove(0)),
+        0 => Err(VboxError::invalid_data(
+            "OvfPackage::open",
+            format!("No OVF descriptor in {}", directory.display()),
+        )),
+        _ => Err(VboxError::invalid_data(
+            "OvfPackage::open",
+            format!(
+                "More than one OVF descriptor in {}: {}",
+                directory.display(),
+                descriptors.join(", ")
+            ),
+        )),
+    }
+}
+
+/// Opens a file of a package directory, `None` if it does not exist.
+///
+/// File names come from the descriptor and the manifest, so names leaving the directory and
+/// anything but regular files, like devices, are rejected.
+fn open_package_file(directory: &Path, file_name: &str) -> Result<Option<File>, VboxError> {
+    let is_relative = Path::new(file_name)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if file_name.is_empty() || !is_relative {
+        return Err(VboxError::invalid_data(
+            "OvfPackage::verify",
+            format!("Invalid file name in the package: {}", file_name),
+        ));
+    }
+    let path = directory.join(file_name);
+    match std::fs::metadata(&path) {
+        Ok(metadata) if metadata.is_file() => {}
+        Ok(_) => {
+            return Err(VboxError::invalid_data(
+                "OvfPackage::verify",
+                format!("{} is not a regular file", path.display()),
+            ))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(VboxError::io_error("OvfPackage::verify", err)),
+    }
+    File::open(&path)
+        .map(Some)
+        .map_err(|err| VboxError::io_error("OvfPackage::verify", err))
+}
+
+fn file_name(path: &Path) -> Result<String, VboxError> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or(VboxError::invalid_data(
+            "OvfPackage::open",
+            format!("Invalid path: {}", path.display()),
+        ))
+}
+
+/// The manifest has the name of the descriptor with the `.mf` extension.
+fn manifest_name(descriptor_name: &str) -> String {
+    let stem = &descriptor_name[..descriptor_name.len() - ".ovf".len()];
+    format!("{}.mf", stem)
+}
+
+fn read_entry(file: &mut File, entry: &tar::TarEntry) -> Result<Vec<u8>, VboxError> {
+    file.seek(SeekFrom::Start(entry.offset))
+        .map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+    let mut data = Vec::new();
+    file.take(entry.size)
+        .read_to_end(&mut data)
+        .map_err(|err| VboxError::io_error("OvfPackage::open", err))?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::envelope::tests::DESCRIPTOR;
+    use super::manifest::{digest_stream, DigestAlgorithm};
+    use super::tar::tests::build_tar;
+    use super::OvfPackage;
+    use std::io::Cursor;
+
+    fn sha256(data: &[u8]) -> String {
+        digest_stream(&mut Cursor::new(data), &[DigestAlgorithm::Sha256])
+            .unwrap()
+            .1
+            .remove(0)
+    }
+
+    fn write_ova(name: &str, disk_in_manifest: &[u8], disk: &[u8]) -> String {
+        let manifest = format!(
+            "SHA256(Freebsd_14.ovf)= {}\nSHA256(Freebsd_14-disk001.vmdk)= {}\n",
+            sha256(DESCRIPTOR.as_bytes()),
+            sha256(disk_in_manifest)
+        );
+        let tar = build_tar(&[
+            ("Freebsd_14.ovf", DESCRIPTOR.as_bytes()),
+            ("Freebsd_14.mf", manifest.as_bytes()),
+            ("Freebsd_14-disk001.vmdk", disk),
+        ]);
+        let path =
+            std::env::temp_dir().join(format!("virtualbox_rs_{}_{}.ova", name, std::process::id()));
+        std::fs::write(&path, tar).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn open_and_validate_archive() {
+        let path = write_ova("valid", b"disk data", b"disk data");
+        let package = OvfPackage::open(&path).unwrap();
+        assert_eq!(package.get_descriptor_name(), "Freebsd_14.ovf");
+        assert_eq!(package.get_manifest().unwrap().len(), 2);
+        assert_eq!(package.get_envelope().virtual_systems[0].name, "Freebsd_14");
+        let checks = package.verify().unwrap();
+        assert_eq!(checks.len(), 2);
+        assert!(checks.iter().all(|check| check.is_valid()));
+        assert_eq!(checks[1].actual_size, Some(9));
+        package.validate().unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_corrupt_disk() {
+        let path = write_ova("corrupt", b"disk data", b"disk dat4");
+        let package = OvfPackage::open(&path).unwrap();
+        let checks = package.verify().unwrap();
+        assert!(checks[0].is_valid());
+        assert!(!checks[1].is_valid());
+        assert!(package.validate().is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_files_missing_from_manifest() {
+        let manifest = format!(
+            "SHA256(Freebsd_14.ovf)= {}\n",
+            sha256(DESCRIPTOR.as_bytes())
+        );
+        let tar = build_tar(&[
+            ("Freebsd_14.ovf", DESCRIPTOR.as_bytes()),
+            ("Freebsd_14.mf", manifest.as_bytes()),
+            ("Freebsd_14-disk001.vmdk", b"disk data"),
+        ]);
+        let path =
+            std::env::temp_dir().join(format!("virtualbox_rs_unlisted_{}.ova", std::process::id()));
+        std::fs::write(&path, tar).unwrap();
+        let package = OvfPackage::open(path.to_str().unwrap()).unwrap();
+        let checks = package.verify().unwrap();
+        assert!(checks[0].is_valid());
+        assert!(checks[1].missing_from_manifest);
+        assert!(!checks[1].is_valid());
+        let err = package.validate().unwrap_err();
+        assert!(
+            err.msg.contains("not listed in the manifest"),
+            "{}",
+            err.msg
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn verify_rejects_names_outside_the_directory() {
+        let directory =
+            std::env::temp_dir().join(format!("virtualbox_rs_ovf_names_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        for href in ["/dev/zero", "../../etc/shadow"] {
+            let descriptor = DESCRIPTOR.replace("Freebsd_14-disk001.vmdk", href);
+            std::fs::write(directory.join("Freebsd_14.ovf"), descriptor).unwrap();
+            let package = OvfPackage::open(directory.to_str().unwrap()).unwrap();
+            let err = package.verify().unwrap_err();
+            assert!(err.msg.contains(href), "{}", err.msg);
+        }
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn open_directory_without_manifest() {
+        let directory =
+            std::env::temp_dir().join(format!("virtualbox_rs_ovf_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("Freebsd_14.ovf"), DESCRIPTOR).unwrap();
+        let package = OvfPackage::open(directory.to_str().unwrap()).unwrap();
+        assert!(package.get_manifest().is_none());
+        assert!(package.validate().is_err());
+        std::fs::write(directory.join("Freebsd_14-disk001.vmdk"), b"disk").unwrap();
+        package.validate().unwrap();
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,363 @@
+use crate::VboxError;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const BLOCK_SIZE: u64 = 512;
+
+/// A regular file stored in a tar archive.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TarEntry {
+    pub(crate) name: String,
+    /// Offset of the file data from the start of the archive.
+    pub(crate) offset: u64,
+    pub(crate) size: u64,
+}
+
+/// Lists the regular files of a ustar, GNU or pax tar archive without reading their data.
+///
+/// Header checksums are verified and every entry has to end within the archive, so a truncated
+/// or corrupted archive is rejected.
+pub(crate) fn read_index<R: Read + Seek>(reader: &mut R) -> Result<Vec<TarEntry>, VboxError> {
+    let mut entries = Vec::new();
+    let mut long_name: Option<String> = None;
+    let archive_size = reader
+        .seek(SeekFrom::End(0))
+        .map_err(|err| VboxError::io_error("tar::read_index", err))?;
+    let mut position = reader
+        .seek(SeekFrom::Start(0))
+        .map_err(|err| VboxError::io_error("tar::read_index", err))?;
+    loop {
+        let mut header = [0u8; BLOCK_SIZE as usize];
+        match read_block(reader, &mut header)? {
+            false if entries.is_empty() && position == 0 => {
+                return Err(VboxError::invalid_data(
+                    "tar::read_index",
+                    "Archive is empty".to_string(),
+                ))
+            }
+            false => break,
+            true => {}
+        }
+        if header.iter().all(|byte| *byte == 0) {
+            break;
+        }
+        verify_checksum(&header, position)?;
+        let size = parse_number(&header[124..136]).ok_or(VboxError::invalid_data(
+            "tar::read_index",
+            format!("Invalid entry size at offset {}", position),
+        ))?;
+        let offset = position + BLOCK_SIZE;
+        let next_position = offset
+            .checked_add(size)
+            .filter(|end| *end <= archive_size)
+            .and_then(|_| size.div_ceil(BLOCK_SIZE).checked_mul(BLOCK_SIZE))
+            .and_then(|padded_size| offset.checked_add(padded_size))
+            .ok_or(VboxError::invalid_data(
+                "tar::read_index",
+                format!(
+                    "Entry of {} bytes at offset {} exceeds the archive of {} bytes",
+                    size, position, archive_size
+                ),
+            ))?;
+        match header[156] {
+            b'L' => {
+                let data = read_data(reader, size)?;
+                long_name = Some(parse_string(&data));
+            }
+            b'x' => {
+                let data = read_data(reader, size)?;
+                if let Some(path) = parse_pax_path(&data) {
+                    long_name = Some(path);
+                }
+            }
+            b'0' | 0 | b'7' => {
+                let name = match long_name.take() {
+                    Some(name) => name,
+                    None => header_name(&header),
+                };
+                entries.push(TarEntry { name, offset, size });
+            }
+            _ => {
+                long_name = None;
+            }
+        }
+        position = next_position;
+        reader
+            .seek(SeekFrom::Start(position))
+            .map_err(|err| VboxError::io_error("tar::read_index", err))?;
+    }
+    Ok(entries)
+}
+
+fn read_block<R: Read>(reader: &mut R, block: &mut [u8]) -> Result<bool, VboxError> {
+    let mut read = 0;
+    while read < block.len() {
+        match reader.read(&mut block[read..]) {
+            Ok(0) if read == 0 => return Ok(false),
+            Ok(0) => {
+                return Err(VboxError::invalid_data(
+                    "tar::read_index",
+                    "Archive is truncated".to_string(),
+                ))
+            }
+            Ok(n) => read += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(VboxError::io_error("tar::read_index", err)),
+        }
+    }
+    Ok(true)
+}
+
+fn read_data<R: Read>(reader: &mut R, size: u64) -> Result<Vec<u8>, VboxError> {
+    let mut data = Vec::new();
+    reader
+        .take(size)
+        .read_to_end(&mut data)
+        .map_err(|err| VboxError::io_error("tar::read_index", err))?;
+    if data.len() as u64 != size {
+        return Err(VboxError::invalid_data(
+            "tar::read_index",
+            "Archive is truncated".to_string(),
+        ));
+    }
+    Ok(data)
+}
+
+fn verify_checksum(header: &[u8], position: u64) -> Result<(), VboxError> {
+    let expected = parse_number(&header[148..156]);
+    let actual: u64 = header
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| {
+            if (148..156).contains(&i) {
+                b' ' as u64
+            } else {
+                *byte as u64
+            }
+        })
+        .sum();
+    if expected != Some(actual) {
+        return Err(VboxError::invalid_data(
+            "tar::read_index",
+            format!("Header checksum mismatch at offset {}", position),
+        ));
+    }
+    Ok(())
+}
+
+fn header_name(header: &[u8]) -> String {
+    let name = parse_string(&header[0..100]);
+    let prefix = if &header[257..262] == b"ustar" {
+        parse_string(&header[345..500])
+    } else {
+        String::new()
+    };
+    if prefix.is_empty() {
+        name
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
+
+fn parse_string(field: &[u8]) -> String {
+    let end = field
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).to_string()
+}
+
+/// Parses an octal number field, or a base-256 one as used by GNU tar for large files.
+fn parse_number(field: &[u8]) -> Option<u64> {
+    if field[0] & 0x80 != 0 {
+        let mut value = (field[0] & 0x7f) as u64;
+        for byte in &field[1..] {
+            value = value.checked_mul(256)?.checked_add(*byte as u64)?;
+        }
+        return Some(value);
+    }
+    let text = parse_string(field);
+    let text = text.trim_matches(|c: char| c == ' ' || c == '\0');
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+/// Extracts the `path` record of a pax extended header. Records have the form `<length> <key>=<value>\n`.
+fn parse_pax_path(data: &[u8]) -> Option<String> {
+    let mut rest = data;
+    while !rest.is_empty() {
+        let space = rest.iter().position(|byte| *byte == b' ')?;
+        let length: usize = std::str::from_utf8(&rest[..space]).ok()?.parse().ok()?;
+        if length <= space || length > rest.len() {
+            return None;
+        }
+        let record = &rest[space + 1..length];
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+        if let Some(path) = record.strip_prefix(b"path=") {
+            return Some(String::from_utf8_lossy(path).to_string());
+        }
+        rest = &rest[length..];
+    }
+    None
+}
+
+/// Writes the header of a regular file. Names longer than the ustar limit get a pax header.
+pub(crate) fn write_header<W: Write>(
+    writer: &mut W,
+    name: &str,
+    size: u64,
+    mtime: u64,
+) -> Result<(), VboxError> {
+    let short_name = if name.len() > 100 {
+        let record = format!("path={}\n", name);
+        // The record length includes its own digits.
+        let mut length = record.len() + 2;
+        while format!("{} {}", length, record).len() != length {
+            length += 1;
+        }
+        let record = format!("{} {}", length, record);
+        write_block(
+            writer,
+            &header("PaxHeader", record.len() as u64, mtime, b'x'),
+        )?;
+        write_data(writer, record.as_bytes())?;
+        let start = name.len() - 100;
+        let start = (start..name.len())
+            .find(|i| name.is_char_boundary(*i))
+            .unwrap_or(start);
+        &name[start..]
+    } else {
+        name
+    };
+    write_block(writer, &header(short_name, size, mtime, b'0'))
+}
+
+/// Writes file data followed by the padding up to the next block.
+pub(crate) fn write_data<W: Write>(writer: &mut W, data: &[u8]) -> Result<(), VboxError> {
+    write_block(writer, data)?;
+    write_padding(writer, data.len() as u64)
+}
+
+/// Pads file data of `size` bytes up to the next block.
+pub(crate) fn write_padding<W: Write>(writer: &mut W, size: u64) -> Result<(), VboxError> {
+    let padding = (size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE - size) as usize;
+    write_block(writer, &[0u8; BLOCK_SIZE as usize][..padding])
+}
+
+/// Writes the two zero blocks terminating an archive.
+pub(crate) fn write_end<W: Write>(writer: &mut W) -> Result<(), VboxError> {
+    write_block(writer, &[0u8; 2 * BLOCK_SIZE as usize])
+}
+
+fn write_block<W: Write>(writer: &mut W, data: &[u8]) -> Result<(), VboxError> {
+    writer
+        .write_all(data)
+        .map_err(|err| VboxError::io_error("tar::write", err))
+}
+
+fn header(name: &str, size: u64, mtime: u64, type_flag: u8) -> [u8; BLOCK_SIZE as usize] {
+    let mut header = [0u8; BLOCK_SIZE as usize];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..107].copy_from_slice(b"0000644");
+    header[108..115].copy_from_slice(b"0000000");
+    header[116..123].copy_from_slice(b"0000000");
+    if size < 0o77777777777 {
+        header[124..135].copy_from_slice(format!("{:011o}", size).as_bytes());
+    } else {
+        header[124] = 0x80;
+        header[128..136].copy_from_slice(&size.to_be_bytes());
+    }
+    header[136..147].copy_from_slice(format!("{:011o}", mtime.min(0o77777777777)).as_bytes());
+    header[156] = type_flag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[148..156].copy_from_slice(b"        ");
+    let checksum: u32 = header.iter().map(|byte| *byte as u32).sum();
+    header[148..155].copy_from_slice(format!("{:06o}\0", checksum).as_bytes());
+    header
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{header, read_index, write_data, write_end, write_header};
+    use std::io::Cursor;
+
+    /// Builds a ustar archive holding the given files.
+    pub(crate) fn build_tar(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut tar = Vec::new();
+        for (name, data) in files {
+            tar.extend_from_slice(&header(name, data.len() as u64, 0, b'0'));
+            tar.extend_from_slice(data);
+            tar.resize(tar.len().div_ceil(512) * 512, 0);
+        }
+        tar.resize(tar.len() + 1024, 0);
+        tar
+    }
+
+    #[test]
+    fn read_index_lists_files() {
+        let tar = build_tar(&[("vm.ovf", b"<Envelope/>"), ("vm.mf", b"")]);
+        let entries = read_index(&mut Cursor::new(&tar)).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "vm.ovf");
+        assert_eq!(entries[0].offset, 512);
+        assert_eq!(entries[0].size, 11);
+        assert_eq!(entries[1].name, "vm.mf");
+        assert_eq!(entries[1].offset, 1536);
+        assert_eq!(entries[1].size, 0);
+    }
+
+    #[test]
+    fn read_index_uses_pax_path() {
+        let long_name = format!("{}.vmdk", "d".repeat(120));
+        let record = format!("path={}\n", long_name);
+        let record = format!("{} {}", record.len() + 4, record);
+        let mut tar = Vec::new();
+        tar.extend_from_slice(&header("PaxHeader", record.len() as u64, 0, b'x'));
+        tar.extend_from_slice(record.as_bytes());
+        tar.resize(1024, 0);
+        tar.extend_from_slice(&header("short.vmdk", 3, 0, b'0'));
+        tar.extend_from_slice(b"abc");
+        tar.resize(2048 + 1024, 0);
+        let entries = read_index(&mut Cursor::new(&tar)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, long_name);
+        assert_eq!(entries[0].offset, 1536);
+    }
+
+    #[test]
+    fn read_index_rejects_corrupt_header() {
+        let mut tar = build_tar(&[("vm.ovf", b"<Envelope/>")]);
+        tar[0] = b'x';
+        assert!(read_index(&mut Cursor::new(&tar)).is_err());
+        assert!(read_index(&mut Cursor::new(&tar[..100])).is_err());
+        assert!(read_index(&mut Cursor::new(Vec::new())).is_err());
+    }
+
+    #[test]
+    fn read_index_rejects_oversized_entry() {
+        for size in [u64::MAX, 1 << 40, 513] {
+            let mut tar = header("vm.ovf", size, 0, b'0').to_vec();
+            tar.resize(1024, 0);
+            let err = read_index(&mut Cursor::new(&tar)).unwrap_err();
+            assert!(err.msg.contains("exceeds the archive"), "{}", err.msg);
+        }
+    }
+
+    #[test]
+    fn write_long_name_round_trip() {
+        let long_name = format!("{}.vmdk", "d".repeat(150));
+        let mut tar = Vec::new();
+        write_header(&mut tar, &long_name, 3, 1700000000).unwrap();
+        write_data(&mut tar, b"abc").unwrap();
+        write_header(&mut tar, "vm.cert", 0, 1700000000).unwrap();
+        write_end(&mut tar).unwrap();
+        let entries = read_index(&mut Cursor::new(&tar)).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, long_name);
+        assert_eq!(entries[0].size, 3);
+        assert_eq!(&tar[entries[0].offset as usize..][..3], b"abc");
+        assert_eq!(entries[1].name, "vm.cert");
+    }
+}
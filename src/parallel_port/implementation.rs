@@ -0,0 +1,249 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{ParallelPort, VboxError};
+
+impl ParallelPort {
+    /// Slot number this parallel port is plugged into.
+    ///
+    /// Corresponds to the value you pass to [`crate::Machine::get_parallel_port`] to obtain this instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let parallel_port = machine.get_parallel_port(0).unwrap();
+    /// let slot = parallel_port.get_slot().unwrap();
+    pub fn get_slot(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetSlot, u32)
+    }
+
+    /// Flag whether the parallel port is enabled. If disabled, the parallel port will not be reported to the guest OS.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let parallel_port = machine.get_parallel_port(0).unwrap();
+    /// let enabled = parallel_port.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Flag whether the parallel port is enabled. If disabled, the parallel port will not be reported to the guest OS.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let parallel_port = machine_mut.get_parallel_port(0).unwrap();
+    /// parallel_port.set_enabled(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// Base I/O address of the parallel port.
+    ///
+    /// The default value is 0x378 for the first port.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let parallel_port = machine.get_parallel_port(0).unwrap();
+    /// let io_base = parallel_port.get_io_base().unwrap();
+    pub fn get_io_base(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetIOBase, u32)
+    }
+
+    /// Base I/O address of the parallel port.
+    ///
+    /// The default value is 0x378 for the first port.
+    ///
+    /// # Arguments
+    ///
+    /// * `io_base` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let parallel_port = machine_mut.get_parallel_port(0).unwrap();
+    /// parallel_port.set_io_base(0x378).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_io_base(&self, io_base: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetIOBase, io_base)
+    }
+
+    /// IRQ number of the parallel port.
+    ///
+    /// The default value is 7 for the first port.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let parallel_port = machine.get_parallel_port(0).unwrap();
+    /// let irq = parallel_port.get_irq().unwrap();
+    pub fn get_irq(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetIRQ, u32)
+    }
+
+    /// IRQ number of the parallel port.
+    ///
+    /// The default value is 7 for the first port.
+    ///
+    /// # Arguments
+    ///
+    /// * `irq` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let parallel_port = machine_mut.get_parallel_port(0).unwrap();
+    /// parallel_port.set_irq(7).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_irq(&self, irq: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetIRQ, irq)
+    }
+
+    /// Host parallel device name.
+    ///
+    /// If this parallel port is enabled, setting a null or an empty string as this attribute's value will result in the parallel port behaving as if not connected to any device.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let parallel_port = machine.get_parallel_port(0).unwrap();
+    /// let path = parallel_port.get_path().unwrap();
+    pub fn get_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPath)
+    }
+
+    /// Host parallel device name.
+    ///
+    /// If this parallel port is enabled, setting a null or an empty string as this attribute's value will result in the parallel port behaving as if not connected to any device.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let parallel_port = machine_mut.get_parallel_port(0).unwrap();
+    /// parallel_port.set_path("/dev/lp0").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_path(&self, path: &str) -> Result<(), VboxError> {
+        let path = string_to_c_u64_str(path)?;
+        get_function_result_unit!(self.object, SetPath, path)
+    }
+}
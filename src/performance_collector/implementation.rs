@@ -0,0 +1,407 @@
+use crate::performance_collector::performance_series::split_data;
+use crate::utility::macros::macros::{
+    get_function_result_pointer_vec, get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::{c_u64_str_to_string, str_vec_to_ptr};
+use crate::{
+    PerformanceCollector, PerformanceMetric, PerformanceObject, PerformanceSeries, VboxError,
+};
+use std::collections::HashMap;
+use vbox_raw::sys_lib::{nsISupports, IPerformanceMetric};
+
+impl PerformanceCollector {
+    /// Array of unique names of metrics.
+    ///
+    /// This array represents all metrics supported by the performance collector. Individual objects do not necessarily support all of them.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metric_names = performance_collector.get_metric_names().unwrap();
+    pub fn get_metric_names(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetMetricNames)
+    }
+
+    /// Returns parameters of specified metrics for a set of objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_names` - Vec<&str>. Metric name filter. Wildcards `*` and `?` are supported; an empty vector matches all metrics.
+    /// * `objects` - &[[`PerformanceObject`]]. Set of objects to filter by; an empty slice matches all objects.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PerformanceMetric>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{PerformanceObject, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let host = vbox.get_host().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User", "RAM/Usage/Used"], &[PerformanceObject::Host(host)])
+    ///     .unwrap();
+    pub fn get_metrics(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+    ) -> Result<Vec<PerformanceMetric>, VboxError> {
+        let (metric_names_size, metric_names_ptr) = str_vec_to_ptr(metric_names)?;
+        let mut objects: Vec<*mut nsISupports> =
+            objects.iter().map(|object| object.as_raw()).collect();
+        let metrics = get_function_result_pointer_vec!(
+            self.object,
+            GetMetrics,
+            *mut IPerformanceMetric,
+            metric_names_size,
+            metric_names_ptr,
+            objects.len() as u32,
+            objects.as_mut_ptr()
+        )?;
+        Ok(metrics.into_iter().map(PerformanceMetric::new).collect())
+    }
+
+    /// Sets parameters of specified base metrics for a set of objects.
+    ///
+    /// Returns an array of [`PerformanceMetric`] describing parameters of the metrics that were affected. Setting the period to zero disables collection of the metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_names` - Vec<&str>. Metric name filter. Wildcards `*` and `?` are supported; an empty vector matches all metrics.
+    /// * `objects` - &[[`PerformanceObject`]]. Set of objects to filter by; an empty slice matches all objects.
+    /// * `period` - u32. Time interval in seconds between two consecutive samples of performance data.
+    /// * `count` - u32. Number of samples to retain in performance data history. Older samples get discarded.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PerformanceMetric>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{PerformanceObject, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let host = vbox.get_host().unwrap();
+    /// let metrics = performance_collector
+    ///     .setup_metrics(vec!["CPU/Load/User", "RAM/Usage/Used"], &[PerformanceObject::Host(host)], 1, 60)
+    ///     .unwrap();
+    pub fn setup_metrics(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+        period: u32,
+        count: u32,
+    ) -> Result<Vec<PerformanceMetric>, VboxError> {
+        let (metric_names_size, metric_names_ptr) = str_vec_to_ptr(metric_names)?;
+        let mut objects: Vec<*mut nsISupports> =
+            objects.iter().map(|object| object.as_raw()).collect();
+        let metrics = get_function_result_pointer_vec!(
+            self.object,
+            SetupMetrics,
+            *mut IPerformanceMetric,
+            metric_names_size,
+            metric_names_ptr,
+            objects.len() as u32,
+            objects.as_mut_ptr(),
+            period,
+            count
+        )?;
+        Ok(metrics.into_iter().map(PerformanceMetric::new).collect())
+    }
+
+    /// Turns on collecting specified base metrics.
+    ///
+    /// Returns an array of [`PerformanceMetric`] describing the metrics that were affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_names` - Vec<&str>. Metric name filter. Wildcards `*` and `?` are supported; an empty vector matches all metrics.
+    /// * `objects` - &[[`PerformanceObject`]]. Set of objects to filter by; an empty slice matches all objects.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PerformanceMetric>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{PerformanceObject, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let host = vbox.get_host().unwrap();
+    /// let metrics = performance_collector
+    ///     .enable_metrics(vec!["CPU/Load/User", "RAM/Usage/Used"], &[PerformanceObject::Host(host)])
+    ///     .unwrap();
+    pub fn enable_metrics(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+    ) -> Result<Vec<PerformanceMetric>, VboxError> {
+        let (metric_names_size, metric_names_ptr) = str_vec_to_ptr(metric_names)?;
+        let mut objects: Vec<*mut nsISupports> =
+            objects.iter().map(|object| object.as_raw()).collect();
+        let metrics = get_function_result_pointer_vec!(
+            self.object,
+            EnableMetrics,
+            *mut IPerformanceMetric,
+            metric_names_size,
+            metric_names_ptr,
+            objects.len() as u32,
+            objects.as_mut_ptr()
+        )?;
+        Ok(metrics.into_iter().map(PerformanceMetric::new).collect())
+    }
+
+    /// Turns off collecting specified base metrics.
+    ///
+    /// Returns an array of [`PerformanceMetric`] describing the metrics that were affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_names` - Vec<&str>. Metric name filter. Wildcards `*` and `?` are supported; an empty vector matches all metrics.
+    /// * `objects` - &[[`PerformanceObject`]]. Set of objects to filter by; an empty slice matches all objects.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PerformanceMetric>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{PerformanceObject, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let host = vbox.get_host().unwrap();
+    /// let metrics = performance_collector
+    ///     .disable_metrics(vec!["CPU/Load/User", "RAM/Usage/Used"], &[PerformanceObject::Host(host)])
+    ///     .unwrap();
+    pub fn disable_metrics(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+    ) -> Result<Vec<PerformanceMetric>, VboxError> {
+        let (metric_names_size, metric_names_ptr) = str_vec_to_ptr(metric_names)?;
+        let mut objects: Vec<*mut nsISupports> =
+            objects.iter().map(|object| object.as_raw()).collect();
+        let metrics = get_function_result_pointer_vec!(
+            self.object,
+            DisableMetrics,
+            *mut IPerformanceMetric,
+            metric_names_size,
+            metric_names_ptr,
+            objects.len() as u32,
+            objects.as_mut_ptr()
+        )?;
+        Ok(metrics.into_iter().map(PerformanceMetric::new).collect())
+    }
+
+    /// Queries collected metrics data for a set of objects.
+    ///
+    /// The raw arrays returned by the API are decoded into one [`PerformanceSeries`] per metric and object,
+    /// with the sampling period read from the matching [`PerformanceMetric`].
+    /// Base metrics return the retained samples; the `:avg`, `:min` and `:max` sub-metrics return a single value.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric_names` - Vec<&str>. Metric name filter. Wildcards `*` and `?` are supported; an empty vector matches all metrics.
+    /// * `objects` - &[[`PerformanceObject`]]. Set of objects to filter by; an empty slice matches all objects.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<PerformanceSeries>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{PerformanceObject, VirtualBox};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let host = vbox.get_host().unwrap();
+    /// performance_collector
+    ///     .setup_metrics(vec!["CPU/Load/User"], &[], 1, 10)
+    ///     .unwrap();
+    /// std::thread::sleep(std::time::Duration::from_secs(3));
+    /// let series = performance_collector
+    ///     .query_metrics_data(vec!["CPU/Load/User"], &[PerformanceObject::Host(host)])
+    ///     .unwrap();
+    /// for item in series {
+    ///     println!("{} {}: {:?} {}", item.object, item.metric_name, item.scaled_values(), item.unit);
+    /// }
+    pub fn query_metrics_data(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+    ) -> Result<Vec<PerformanceSeries>, VboxError> {
+        let periods = self.metric_periods(metric_names.clone(), objects)?;
+        let (metric_names_size, metric_names_ptr) = str_vec_to_ptr(metric_names)?;
+        let mut objects: Vec<*mut nsISupports> =
+            objects.iter().map(|object| object.as_raw()).collect();
+        let mut names_size = 0;
+        let mut names: *mut *mut u16 = std::ptr::null_mut();
+        let mut return_objects_size = 0;
+        let mut return_objects: *mut *mut nsISupports = std::ptr::null_mut();
+        let mut units_size = 0;
+        let mut units: *mut *mut u16 = std::ptr::null_mut();
+        let mut scales_size = 0;
+        let mut scales: *mut u32 = std::ptr::null_mut();
+        let mut sequence_numbers_size = 0;
+        let mut sequence_numbers: *mut u32 = std::ptr::null_mut();
+        let mut indices_size = 0;
+        let mut indices: *mut u32 = std::ptr::null_mut();
+        let mut lengths_size = 0;
+        let mut lengths: *mut u32 = std::ptr::null_mut();
+        let mut data_size = 0;
+        let mut data: *mut i32 = std::ptr::null_mut();
+        get_function_result_unit!(
+            self.object,
+            QueryMetricsData,
+            metric_names_size,
+            metric_names_ptr,
+            objects.len() as u32,
+            objects.as_mut_ptr(),
+            &mut names_size,
+            &mut names,
+            &mut return_objects_size,
+            &mut return_objects,
+            &mut units_size,
+            &mut units,
+            &mut scales_size,
+            &mut scales,
+            &mut sequence_numbers_size,
+            &mut sequence_numbers,
+            &mut indices_size,
+            &mut indices,
+            &mut lengths_size,
+            &mut lengths,
+            &mut data_size,
+            &mut data
+        )?;
+        if names_size == 0 {
+            return Ok(Vec::new());
+        }
+        if names.is_null()
+            || return_objects.is_null()
+            || units.is_null()
+            || scales.is_null()
+            || sequence_numbers.is_null()
+            || indices.is_null()
+            || lengths.is_null()
+        {
+            return Err(VboxError::null_pointer_error("QueryMetricsData"));
+        }
+        let names = unsafe { Vec::from_raw_parts(names, names_size as usize, names_size as usize) };
+        let return_objects = unsafe {
+            Vec::from_raw_parts(
+                return_objects,
+                return_objects_size as usize,
+                return_objects_size as usize,
+            )
+        };
+        // Every reference is wrapped before the first error is returned, so none of them leaks.
+        let mut series_objects = Vec::with_capacity(return_objects.len());
+        let mut objects_error = None;
+        for object in return_objects {
+            match PerformanceObject::from_raw(object) {
+                Ok(object) => series_objects.push(object),
+                Err(err) => {
+                    objects_error.get_or_insert(err);
+                }
+            }
+        }
+        if let Some(err) = objects_error {
+            return Err(err);
+        }
+        let units = unsafe { Vec::from_raw_parts(units, units_size as usize, units_size as usize) };
+        let scales =
+            unsafe { Vec::from_raw_parts(scales, scales_size as usize, scales_size as usize) };
+        let sequence_numbers = unsafe {
+            Vec::from_raw_parts(
+                sequence_numbers,
+                sequence_numbers_size as usize,
+                sequence_numbers_size as usize,
+            )
+        };
+        let indices =
+            unsafe { Vec::from_raw_parts(indices, indices_size as usize, indices_size as usize) };
+        let lengths =
+            unsafe { Vec::from_raw_parts(lengths, lengths_size as usize, lengths_size as usize) };
+        let data = if data.is_null() {
+            Vec::new()
+        } else {
+            unsafe { Vec::from_raw_parts(data, data_size as usize, data_size as usize) }
+        };
+        let sizes = [
+            series_objects.len(),
+            units.len(),
+            scales.len(),
+            sequence_numbers.len(),
+            indices.len(),
+        ];
+        if sizes.iter().any(|size| *size != names.len()) {
+            return Err(VboxError::vectors_length_mismatch("QueryMetricsData"));
+        }
+        let values = split_data(&indices, &lengths, &data)?;
+        let mut series = Vec::with_capacity(names.len());
+        for ((i, values), object) in values.into_iter().enumerate().zip(series_objects) {
+            let metric_name = c_u64_str_to_string(names[i])?;
+            let object_key = object.key()?;
+            // Sub-metrics such as `CPU/Load/User:avg` are sampled with the period of the base metric.
+            let base_name = metric_name.split(':').next().unwrap_or(metric_name);
+            let period = periods
+                .get(&(metric_name.to_string(), object_key.clone()))
+                .or_else(|| periods.get(&(base_name.to_string(), object_key)))
+                .copied()
+                .unwrap_or(0);
+            series.push(PerformanceSeries {
+                period,
+                metric_name: metric_name.to_string(),
+                object,
+                unit: c_u64_str_to_string(units[i])?.to_string(),
+                scale: scales[i],
+                sequence_number: sequence_numbers[i],
+                values,
+            });
+        }
+        Ok(series)
+    }
+
+    /// Sampling periods of the metrics matching the filters, keyed by metric name and
+    /// [`PerformanceObject::key`].
+    fn metric_periods(
+        &self,
+        metric_names: Vec<&str>,
+        objects: &[PerformanceObject],
+    ) -> Result<HashMap<(String, String), u32>, VboxError> {
+        let mut periods = HashMap::new();
+        for metric in self.get_metrics(metric_names, objects)? {
+            let key = (
+                metric.get_metric_name()?.to_string(),
+                metric.get_object()?.key()?,
+            );
+            periods.insert(key, metric.get_period()?);
+        }
+        Ok(periods)
+    }
+}
@@ -1,8 +1,15 @@
+mod implementation;
+mod performance_object;
+mod performance_series;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IPerformanceCollector;
 
+pub use performance_object::PerformanceObject;
+pub use performance_series::PerformanceSeries;
+
 /// The IPerformanceCollector interface represents a service that collects and stores performance metrics data.
 ///
 /// **Reference to the official documentation:**
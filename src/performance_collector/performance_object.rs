@@ -0,0 +1,88 @@
+use crate::event_detail::utility::create_ns_id_from_str;
+use crate::{Host, Machine, VboxError};
+use log::error;
+use std::fmt::Display;
+use vbox_raw::sys_lib::{nsID, nsISupports, IHOST_IID_STR, IMACHINE_IID_STR};
+
+/// Object the performance metrics are collected for.
+#[derive(Debug)]
+pub enum PerformanceObject {
+    /// The host computer.
+    Host(Host),
+    /// A virtual machine.
+    Machine(Machine),
+}
+
+impl PerformanceObject {
+    pub(crate) fn as_raw(&self) -> *mut nsISupports {
+        match self {
+            PerformanceObject::Host(host) => host.object.cast(),
+            PerformanceObject::Machine(machine) => machine.object.cast(),
+        }
+    }
+
+    /// Takes over the reference held by `object` and wraps it into [`PerformanceObject`].
+    pub(crate) fn from_raw(object: *mut nsISupports) -> Result<Self, VboxError> {
+        if object.is_null() {
+            return Err(VboxError::null_pointer_error("PerformanceObject::from_raw"));
+        }
+        let result = if let Some(machine) = query_interface(object, IMACHINE_IID_STR) {
+            Ok(PerformanceObject::Machine(Machine::new(machine.cast())))
+        } else if let Some(host) = query_interface(object, IHOST_IID_STR) {
+            Ok(PerformanceObject::Host(Host::new(host.cast())))
+        } else {
+            Err(VboxError::invalid_data(
+                "PerformanceObject::from_raw",
+                "The object is neither a host nor a machine".to_string(),
+            ))
+        };
+        release(object);
+        result
+    }
+
+    /// Identifies the object independently of the interface pointer: `host` for the host, the
+    /// UUID for a machine.
+    pub(crate) fn key(&self) -> Result<String, VboxError> {
+        match self {
+            PerformanceObject::Host(_) => Ok("host".to_string()),
+            PerformanceObject::Machine(machine) => Ok(machine.get_id()?.to_string()),
+        }
+    }
+}
+
+fn query_interface(
+    object: *mut nsISupports,
+    iid_str: &[u8; 37],
+) -> Option<*mut std::os::raw::c_void> {
+    let mut result: *mut std::os::raw::c_void = std::ptr::null_mut();
+    let iid = create_ns_id_from_str(iid_str);
+    let lp_vtbl = unsafe { (*object).lpVtbl };
+    let query_fn = unsafe { (*lp_vtbl).QueryInterface }?;
+    let result_code = unsafe { query_fn(object, &iid as *const nsID, &mut result) };
+    if result_code != 0 || result.is_null() {
+        return None;
+    }
+    Some(result)
+}
+
+fn release(object: *mut nsISupports) {
+    let lp_vtbl = unsafe { (*object).lpVtbl };
+    match unsafe { (*lp_vtbl).Release } {
+        Some(release_fn) => {
+            unsafe { release_fn(object) };
+        }
+        None => error!("Failed release PerformanceObject. Release is missing"),
+    }
+}
+
+impl Display for PerformanceObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PerformanceObject::Host(_) => write!(f, "host"),
+            PerformanceObject::Machine(machine) => match machine.get_name() {
+                Ok(name) => write!(f, "{}", name),
+                Err(_) => write!(f, "machine"),
+            },
+        }
+    }
+}
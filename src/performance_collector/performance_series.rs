@@ -0,0 +1,93 @@
+use crate::{PerformanceObject, VboxError};
+
+/// Collected samples of one metric for one object, as returned by
+/// [`crate::PerformanceCollector::query_metrics_data`].
+#[derive(Debug)]
+pub struct PerformanceSeries {
+    /// Name of the metric, for example `CPU/Load/User:avg`.
+    pub metric_name: String,
+    /// Object the samples were collected for.
+    pub object: PerformanceObject,
+    /// Unit of measurement, for example `%`, `kB` or `B/s`.
+    pub unit: String,
+    /// Divisor to apply to the raw values to get the values in `unit`.
+    pub scale: u32,
+    /// Sequence number of the first sample. Together with [`PerformanceSeries::period`] it
+    /// tells when the samples were taken.
+    pub sequence_number: u32,
+    /// Time interval in seconds between two consecutive samples, as returned by
+    /// [`crate::PerformanceMetric::get_period`]. 0 if the metric is not set up.
+    pub period: u32,
+    /// Raw sample values, oldest first.
+    pub values: Vec<i32>,
+}
+
+impl PerformanceSeries {
+    /// Sample values divided by [`PerformanceSeries::scale`], oldest first.
+    pub fn scaled_values(&self) -> Vec<f64> {
+        let scale = if self.scale == 0 { 1 } else { self.scale };
+        self.values
+            .iter()
+            .map(|value| *value as f64 / scale as f64)
+            .collect()
+    }
+
+    /// The most recent sample divided by [`PerformanceSeries::scale`].
+    pub fn last_value(&self) -> Option<f64> {
+        self.scaled_values().last().copied()
+    }
+}
+
+/// Splits the flat data array of `queryMetricsData` into one slice per returned metric.
+pub(crate) fn split_data(
+    indices: &[u32],
+    lengths: &[u32],
+    data: &[i32],
+) -> Result<Vec<Vec<i32>>, VboxError> {
+    if indices.len() != lengths.len() {
+        return Err(VboxError::vectors_length_mismatch("QueryMetricsData"));
+    }
+    indices
+        .iter()
+        .zip(lengths)
+        .map(|(index, length)| {
+            let start = *index as usize;
+            let end = start + *length as usize;
+            data.get(start..end)
+                .map(|values| values.to_vec())
+                .ok_or(VboxError::invalid_data(
+                    "QueryMetricsData",
+                    format!(
+                        "Data range {}..{} is out of bounds, data length: {}",
+                        start,
+                        end,
+                        data.len()
+                    ),
+                ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_data;
+
+    #[test]
+    fn split_data_by_indices_and_lengths() {
+        let data = [10, 20, 30, 40, 50, 60];
+        let series = split_data(&[0, 2, 5], &[2, 3, 1], &data).unwrap();
+        assert_eq!(series, vec![vec![10, 20], vec![30, 40, 50], vec![60]]);
+    }
+
+    #[test]
+    fn split_data_allows_empty_series() {
+        let series = split_data(&[0, 0], &[0, 1], &[7]).unwrap();
+        assert_eq!(series, vec![vec![], vec![7]]);
+    }
+
+    #[test]
+    fn split_data_rejects_out_of_bounds() {
+        assert!(split_data(&[1], &[2], &[1, 2]).is_err());
+        assert!(split_data(&[0, 1], &[1], &[1, 2]).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_pointer, get_function_result_str,
+};
+use crate::{PerformanceMetric, PerformanceObject, VboxError};
+use vbox_raw::sys_lib::nsISupports;
+
+impl PerformanceMetric {
+    /// Name of the metric.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let metric_name = metric.get_metric_name().unwrap();
+    pub fn get_metric_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetMetricName)
+    }
+
+    /// Object this metric belongs to.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`PerformanceObject`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let object = metric.get_object().unwrap();
+    pub fn get_object(&self) -> Result<PerformanceObject, VboxError> {
+        let object = get_function_result_pointer!(self.object, GetObject, *mut nsISupports)?;
+        PerformanceObject::from_raw(object)
+    }
+
+    /// Textual description of the metric.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let description = metric.get_description().unwrap();
+    pub fn get_description(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDescription)
+    }
+
+    /// Time interval between samples, measured in seconds.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let period = metric.get_period().unwrap();
+    pub fn get_period(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetPeriod, u32)
+    }
+
+    /// Number of recent samples retained by the performance collector for this metric.
+    ///
+    /// When the collected sample count exceeds this number, older samples are discarded.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let count = metric.get_count().unwrap();
+    pub fn get_count(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetCount, u32)
+    }
+
+    /// Unit of measurement.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let unit = metric.get_unit().unwrap();
+    pub fn get_unit(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUnit)
+    }
+
+    /// Minimum possible value of this metric.
+    ///
+    /// # Returns
+    ///
+    /// Returns i32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let minimum_value = metric.get_minimum_value().unwrap();
+    pub fn get_minimum_value(&self) -> Result<i32, VboxError> {
+        get_function_result_number!(self.object, GetMinimumValue, i32)
+    }
+
+    /// Maximum possible value of this metric.
+    ///
+    /// # Returns
+    ///
+    /// Returns i32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let performance_collector = vbox.get_performance_collector().unwrap();
+    /// let metrics = performance_collector
+    ///     .get_metrics(vec!["CPU/Load/User"], &[])
+    ///     .unwrap();
+    /// let metric = &metrics[0];
+    /// let maximum_value = metric.get_maximum_value().unwrap();
+    pub fn get_maximum_value(&self) -> Result<i32, VboxError> {
+        get_function_result_number!(self.object, GetMaximumValue, i32)
+    }
+}
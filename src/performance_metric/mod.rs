@@ -0,0 +1,41 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IPerformanceMetric;
+
+/// The IPerformanceMetric interface represents parameters of the specified performance metric.
+///
+/// It is returned by [`crate::PerformanceCollector::get_metrics`], [`crate::PerformanceCollector::setup_metrics`], [`crate::PerformanceCollector::enable_metrics`] and [`crate::PerformanceCollector::disable_metrics`].
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_performance_metric.html](https://www.virtualbox.org/sdkref/interface_i_performance_metric.html)
+#[derive(Debug)]
+pub struct PerformanceMetric {
+    object: *mut IPerformanceMetric,
+}
+
+impl PerformanceMetric {
+    pub(crate) fn new(object: *mut IPerformanceMetric) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for PerformanceMetric {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("PerformanceMetric refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop PerformanceMetric. Error: {:?}", err)
+            }
+        }
+    }
+}
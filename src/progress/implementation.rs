@@ -577,4 +577,23 @@ impl Progress {
     pub fn cancel(&self) -> Result<(), VboxError> {
         get_function_result_unit!(self.object, Cancel)
     }
+
+    /// Waits for the task to finish and turns a failed result code into a [`VboxError`].
+    pub(crate) fn wait_for_success(&self, fn_name: &str) -> Result<(), VboxError> {
+        self.wait_for_completion(-1)?;
+        let result_code = self.get_result_code()?;
+        if result_code == 0 {
+            return Ok(());
+        }
+        let text = self
+            .get_error_info()
+            .and_then(|error_info| error_info.get_text())
+            .unwrap_or("");
+        Err(VboxError::new(
+            result_code as u32,
+            fn_name,
+            text.to_string(),
+            None,
+        ))
+    }
 }
@@ -0,0 +1,570 @@
+pub(crate) mod partition_table;
+
+use crate::enums::PartitionTableType;
+use crate::utility::{random_bytes, random_uuid};
+use crate::VboxError;
+use log::debug;
+pub use partition_table::HostPartition;
+use partition_table::{read_layout, read_sectors, SECTOR_SIZE};
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Generator of VMDK descriptors which give a virtual machine access to a physical host disk.
+///
+/// This is the pure Rust counterpart of `VBoxManage createmedium disk --variant RawDisk`.
+/// Either the whole device is mapped (`createType="fullDevice"`), or only the selected partitions
+/// are (`createType="partitionedDevice"`). In the latter case the partition tables (MBR, EBRs or
+/// the primary and backup GPT) and the boot area in front of the first partition are copied into
+/// a `<name>-pt.vmdk` shadow file next to the descriptor, so the guest can never overwrite them on
+/// the host, and every sector not covered by a selected partition reads as zeros.
+///
+/// The resulting descriptor can be registered with [`crate::VirtualBox::open_medium`].
+///
+/// # Example
+///
+/// ```no_run
+/// use virtualbox_rs::{RawDiskVmdk, VirtualBox};
+/// use virtualbox_rs::enums::{AccessMode, DeviceType};
+///
+/// let raw_disk = RawDiskVmdk::new("/dev/sdb").partitions(vec![1, 3]);
+/// let (table_type, partitions) = raw_disk.read_partitions().unwrap();
+/// println!("{:?} {:?}", table_type, partitions);
+/// raw_disk.create("/home/user/VirtualBox VMs/sdb.vmdk").unwrap();
+///
+/// let vbox = VirtualBox::init().unwrap();
+/// let medium = vbox.open_medium(
+///     "/home/user/VirtualBox VMs/sdb.vmdk",
+///     DeviceType::HardDisk,
+///     AccessMode::ReadWrite,
+///     false
+/// ).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RawDiskVmdk {
+    device: String,
+    partitions: Vec<u32>,
+    read_only: bool,
+    adapter_type: String,
+    uuid: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum ExtentKind {
+    Device,
+    Shadow,
+    Zero,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Extent {
+    start: u64,
+    sectors: u64,
+    kind: ExtentKind,
+}
+
+impl RawDiskVmdk {
+    /// Creates a generator for the given host device, for example `/dev/sdb`.
+    ///
+    /// By default, the whole device is mapped in read/write mode for an IDE adapter.
+    pub fn new(device: &str) -> Self {
+        Self {
+            device: device.to_string(),
+            partitions: Vec::new(),
+            read_only: false,
+            adapter_type: "ide".to_string(),
+            uuid: None,
+        }
+    }
+
+    /// Restricts access to the given partitions.
+    ///
+    /// Partition numbers are the ones the host uses: 1-4 for MBR primary partitions,
+    /// 5 and above for MBR logical partitions and the 1-based entry index for GPT.
+    /// An empty list maps the whole device.
+    pub fn partitions(mut self, partitions: Vec<u32>) -> Self {
+        self.partitions = partitions;
+        self
+    }
+
+    /// Marks every extent as read-only.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets `ddb.adapterType`. One of `ide`, `buslogic`, `lsilogic` or `legacyESX`.
+    pub fn adapter_type(mut self, adapter_type: &str) -> Self {
+        self.adapter_type = adapter_type.to_string();
+        self
+    }
+
+    /// Sets the image UUID instead of a randomly generated one.
+    pub fn uuid(mut self, uuid: &str) -> Self {
+        self.uuid = Some(uuid.to_string());
+        self
+    }
+
+    /// Reads the partition table of the device.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`PartitionTableType`] and the partitions sorted by start sector on success,
+    /// or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::RawDiskVmdk;
+    ///
+    /// let (table_type, partitions) = RawDiskVmdk::new("/dev/sdb").read_partitions().unwrap();
+    /// for partition in partitions {
+    ///     println!("{:?} {} {} {}", table_type, partition.number, partition.start, partition.sectors);
+    /// }
+    /// ```
+    pub fn read_partitions(&self) -> Result<(PartitionTableType, Vec<HostPartition>), VboxError> {
+        let (mut disk, total_sectors) = self.open_device()?;
+        let layout = read_layout(&mut disk, total_sectors)?;
+        Ok((layout.table_type, layout.partitions))
+    }
+
+    /// Writes the VMDK descriptor to `path`.
+    ///
+    /// When partitions are selected, the shadow file `<name>-pt.vmdk` is written to the same directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Location of the descriptor, usually with a `.vmdk` extension.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use virtualbox_rs::RawDiskVmdk;
+    ///
+    /// RawDiskVmdk::new("/dev/sdb")
+    ///     .partitions(vec![2])
+    ///     .create("/home/user/VirtualBox VMs/sdb2.vmdk")
+    ///     .unwrap();
+    /// ```
+    pub fn create(&self, path: &str) -> Result<(), VboxError> {
+        if self.device.contains('"') {
+            return Err(VboxError::invalid_data(
+                "RawDiskVmdk::create",
+                format!("Device path contains a quote: {}", self.device),
+            ));
+        }
+        let (mut disk, total_sectors) = self.open_device()?;
+        let access = if self.read_only { "RDONLY" } else { "RW" };
+
+        let mut extent_lines = Vec::new();
+        let create_type = if self.partitions.is_empty() {
+            extent_lines.push(format!(
+                "{} {} FLAT \"{}\" 0",
+                access, total_sectors, self.device
+            ));
+            "fullDevice"
+        } else {
+            let extents = self.partitioned_extents(&mut disk, total_sectors)?;
+            let path = Path::new(path);
+            let stem = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let shadow_name = format!("{}-pt.vmdk", stem);
+            let shadow_path = path.with_file_name(&shadow_name);
+            let mut shadow = File::create(&shadow_path)
+                .map_err(|err| VboxError::io_error("RawDiskVmdk::create", err))?;
+            let mut shadow_offset = 0;
+
+            for extent in extents {
+                match extent.kind {
+                    ExtentKind::Device => extent_lines.push(format!(
+                        "{} {} FLAT \"{}\" {}",
+                        access, extent.sectors, self.device, extent.start
+                    )),
+                    ExtentKind::Shadow => {
+                        let data = read_sectors(&mut disk, extent.start, extent.sectors)?;
+                        shadow
+                            .write_all(&data)
+                            .map_err(|err| VboxError::io_error("RawDiskVmdk::create", err))?;
+                        extent_lines.push(format!(
+                            "{} {} FLAT \"{}\" {}",
+                            access, extent.sectors, shadow_name, shadow_offset
+                        ));
+                        shadow_offset += extent.sectors;
+                    }
+                    ExtentKind::Zero => {
+                        extent_lines.push(format!("{} {} ZERO", access, extent.sectors))
+                    }
+                }
+            }
+            shadow
+                .sync_all()
+                .map_err(|err| VboxError::io_error("RawDiskVmdk::create", err))?;
+            "partitionedDevice"
+        };
+
+        let descriptor = self.descriptor(create_type, &extent_lines, total_sectors);
+        debug!("RawDiskVmdk descriptor:\n{}", descriptor);
+        std::fs::write(path, descriptor)
+            .map_err(|err| VboxError::io_error("RawDiskVmdk::create", err))
+    }
+
+    fn open_device(&self) -> Result<(File, u64), VboxError> {
+        let mut disk = File::open(&self.device)
+            .map_err(|err| VboxError::io_error("RawDiskVmdk::open_device", err))?;
+        let size = disk
+            .seek(SeekFrom::End(0))
+            .map_err(|err| VboxError::io_error("RawDiskVmdk::open_device", err))?;
+        if size < SECTOR_SIZE {
+            return Err(VboxError::invalid_data(
+                "RawDiskVmdk::open_device",
+                format!("{} is smaller than one sector", self.device),
+            ));
+        }
+        Ok((disk, size / SECTOR_SIZE))
+    }
+
+    fn partitioned_extents(
+        &self,
+        disk: &mut File,
+        total_sectors: u64,
+    ) -> Result<Vec<Extent>, VboxError> {
+        let layout = read_layout(disk, total_sectors)?;
+
+        let mut extents = Vec::new();
+        for number in &self.partitions {
+            let partition = layout
+                .partitions
+                .iter()
+                .find(|partition| partition.number == *number)
+                .ok_or(VboxError::invalid_data(
+                    "RawDiskVmdk::partitioned_extents",
+                    format!("Partition {} not found on {}", number, self.device),
+                ))?;
+            extents.push(Extent {
+                start: partition.start,
+                sectors: partition.sectors,
+                kind: ExtentKind::Device,
+            });
+        }
+
+        // The boot area in front of the first partition holds the MBR boot code and often a
+        // second-stage boot loader, so it is shadowed together with the partition tables.
+        let boot_area_end = layout
+            .partitions
+            .first()
+            .map(|partition| partition.start)
+            .unwrap_or(1);
+        let mut shadowed = vec![(0, boot_area_end)];
+        shadowed.extend(layout.table_areas.iter().cloned());
+        for (start, sectors) in shadowed {
+            extents.push(Extent {
+                start,
+                sectors,
+                kind: ExtentKind::Shadow,
+            });
+        }
+        extents.sort_by_key(|extent| (extent.start, extent.kind == ExtentKind::Device));
+
+        // Merge overlapping shadow areas and reject shadow areas inside selected partitions.
+        let mut merged: Vec<Extent> = Vec::new();
+        for extent in extents {
+            if let Some(last) = merged.last_mut() {
+                let last_end = last.start + last.sectors;
+                if extent.start < last_end {
+                    if last.kind != ExtentKind::Shadow || extent.kind != ExtentKind::Shadow {
+                        return Err(VboxError::invalid_data(
+                            "RawDiskVmdk::partitioned_extents",
+                            format!(
+                                "Sectors {}..{} overlap partition table data or another partition",
+                                extent.start,
+                                extent.start + extent.sectors
+                            ),
+                        ));
+                    }
+                    last.sectors = last_end.max(extent.start + extent.sectors) - last.start;
+                    continue;
+                }
+                if extent.start == last_end
+                    && last.kind == ExtentKind::Shadow
+                    && extent.kind == ExtentKind::Shadow
+                {
+                    last.sectors += extent.sectors;
+                    continue;
+                }
+            }
+            merged.push(extent);
+        }
+
+        let mut result = Vec::new();
+        let mut position = 0;
+        for extent in merged {
+            if extent.start > position {
+                result.push(Extent {
+                    start: position,
+                    sectors: extent.start - position,
+                    kind: ExtentKind::Zero,
+                });
+            }
+            position = extent.start + extent.sectors;
+            result.push(extent);
+        }
+        if position < total_sectors {
+            result.push(Extent {
+                start: position,
+                sectors: total_sectors - position,
+                kind: ExtentKind::Zero,
+            });
+        }
+        Ok(result)
+    }
+
+    fn descriptor(&self, create_type: &str, extent_lines: &[String], total_sectors: u64) -> String {
+        let cid = u32::from_le_bytes(random_bytes());
+        let uuid = self.uuid.clone().unwrap_or_else(random_uuid);
+        let cylinders = (total_sectors / (16 * 63)).clamp(1, 16383);
+        let null_uuid = "00000000-0000-0000-0000-000000000000";
+
+        let mut descriptor = String::new();
+        descriptor.push_str("# Disk DescriptorFile\n");
+        descriptor.push_str("version=1\n");
+        descriptor.push_str(&format!("CID={:08x}\n", cid));
+        descriptor.push_str("parentCID=ffffffff\n");
+        descriptor.push_str(&format!("createType=\"{}\"\n", create_type));
+        descriptor.push_str("\n# Extent description\n");
+        for line in extent_lines {
+            descriptor.push_str(line);
+            descriptor.push('\n');
+        }
+        descriptor.push_str("\n# The disk Data Base\n#DDB\n\n");
+        descriptor.push_str("ddb.virtualHWVersion=\"4\"\n");
+        descriptor.push_str(&format!("ddb.adapterType=\"{}\"\n", self.adapter_type));
+        descriptor.push_str(&format!("ddb.geometry.cylinders=\"{}\"\n", cylinders));
+        descriptor.push_str("ddb.geometry.heads=\"16\"\n");
+        descriptor.push_str("ddb.geometry.sectors=\"63\"\n");
+        descriptor.push_str(&format!("ddb.uuid.image=\"{}\"\n", uuid));
+        descriptor.push_str(&format!("ddb.uuid.parent=\"{}\"\n", null_uuid));
+        descriptor.push_str(&format!("ddb.uuid.modification=\"{}\"\n", null_uuid));
+        descriptor.push_str(&format!("ddb.uuid.parentmodification=\"{}\"\n", null_uuid));
+        descriptor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Seek, SeekFrom, Write};
+
+    const DISK_SECTORS: u64 = 32768;
+
+    fn fixture_dir(test: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("raw_disk_vmdk_{}_{}", test, std::process::id()))
+    }
+
+    fn fixture_path(test: &str, name: &str) -> String {
+        let dir = fixture_dir(test);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name).to_string_lossy().to_string()
+    }
+
+    fn remove_fixtures(test: &str) {
+        std::fs::remove_dir_all(fixture_dir(test)).unwrap();
+    }
+
+    fn mbr_entry(sector: &mut [u8], index: usize, mbr_type: u8, start: u32, sectors: u32) {
+        let entry = &mut sector[446 + index * 16..446 + (index + 1) * 16];
+        entry[4] = mbr_type;
+        entry[8..12].copy_from_slice(&start.to_le_bytes());
+        entry[12..16].copy_from_slice(&sectors.to_le_bytes());
+    }
+
+    fn write_sector(file: &mut File, lba: u64, data: &[u8]) {
+        file.seek(SeekFrom::Start(lba * SECTOR_SIZE)).unwrap();
+        file.write_all(data).unwrap();
+    }
+
+    /// Primary partition 1 at 2048, extended partition at 8192 with logical partition 5 at 10240.
+    fn mbr_fixture(test: &str) -> String {
+        let path = fixture_path(test, "disk.img");
+        let mut file = File::create(&path).unwrap();
+        file.set_len(DISK_SECTORS * SECTOR_SIZE).unwrap();
+
+        let mut mbr = [0u8; 512];
+        mbr[0] = 0xEB;
+        mbr_entry(&mut mbr, 0, 0x83, 2048, 4096);
+        mbr_entry(&mut mbr, 1, 0x05, 8192, 8192);
+        mbr[510] = 0x55;
+        mbr[511] = 0xAA;
+        write_sector(&mut file, 0, &mbr);
+
+        let mut ebr = [0u8; 512];
+        mbr_entry(&mut ebr, 0, 0x83, 2048, 4096);
+        ebr[510] = 0x55;
+        ebr[511] = 0xAA;
+        write_sector(&mut file, 8192, &ebr);
+        path
+    }
+
+    fn gpt_fixture(test: &str) -> String {
+        let path = fixture_path(test, "disk.img");
+        let mut file = File::create(&path).unwrap();
+        file.set_len(DISK_SECTORS * SECTOR_SIZE).unwrap();
+
+        let mut mbr = [0u8; 512];
+        mbr_entry(&mut mbr, 0, 0xEE, 1, (DISK_SECTORS - 1) as u32);
+        mbr[510] = 0x55;
+        mbr[511] = 0xAA;
+        write_sector(&mut file, 0, &mbr);
+
+        let mut header = [0u8; 512];
+        header[0..8].copy_from_slice(b"EFI PART");
+        header[32..40].copy_from_slice(&(DISK_SECTORS - 1).to_le_bytes());
+        header[72..80].copy_from_slice(&2u64.to_le_bytes());
+        header[80..84].copy_from_slice(&128u32.to_le_bytes());
+        header[84..88].copy_from_slice(&128u32.to_le_bytes());
+        write_sector(&mut file, 1, &header);
+
+        let mut entry = [0u8; 128];
+        entry[0..16].copy_from_slice(&[
+            0xAF, 0x3D, 0xC6, 0x0F, 0x83, 0x84, 0x72, 0x47, 0x8E, 0x79, 0x3D, 0x69, 0xD8, 0x47,
+            0x7D, 0xE4,
+        ]);
+        entry[16] = 1;
+        entry[32..40].copy_from_slice(&2048u64.to_le_bytes());
+        entry[40..48].copy_from_slice(&6143u64.to_le_bytes());
+        for (i, c) in "root".encode_utf16().enumerate() {
+            entry[56 + i * 2..58 + i * 2].copy_from_slice(&c.to_le_bytes());
+        }
+        write_sector(&mut file, 2, &entry);
+        path
+    }
+
+    fn extent_lines(descriptor: &str) -> Vec<&str> {
+        descriptor
+            .lines()
+            .filter(|line| line.starts_with("RW ") || line.starts_with("RDONLY "))
+            .collect()
+    }
+
+    #[test]
+    fn reads_mbr_with_logical_partitions() {
+        let device = mbr_fixture("mbr_read");
+        let (table_type, partitions) = RawDiskVmdk::new(&device).read_partitions().unwrap();
+        assert_eq!(table_type, PartitionTableType::MBR);
+        let numbers: Vec<(u32, u64, u64)> = partitions
+            .iter()
+            .map(|partition| (partition.number, partition.start, partition.sectors))
+            .collect();
+        assert_eq!(numbers, vec![(1, 2048, 4096), (5, 10240, 4096)]);
+        remove_fixtures("mbr_read");
+    }
+
+    #[test]
+    fn full_device_descriptor() {
+        let device = mbr_fixture("mbr_full");
+        let vmdk = fixture_path("mbr_full", "mbr_full.vmdk");
+        RawDiskVmdk::new(&device)
+            .uuid("d3b7b4b2-8c0e-4a35-9a29-9f6c9a4f0a11")
+            .create(&vmdk)
+            .unwrap();
+        let descriptor = std::fs::read_to_string(&vmdk).unwrap();
+        assert!(descriptor.contains("createType=\"fullDevice\""));
+        assert!(descriptor.contains("ddb.uuid.image=\"d3b7b4b2-8c0e-4a35-9a29-9f6c9a4f0a11\""));
+        assert_eq!(
+            extent_lines(&descriptor),
+            vec![format!("RW {} FLAT \"{}\" 0", DISK_SECTORS, device)]
+        );
+        remove_fixtures("mbr_full");
+    }
+
+    #[test]
+    fn partitioned_mbr_descriptor_shadows_tables() {
+        let device = mbr_fixture("mbr_part");
+        let vmdk = fixture_path("mbr_part", "mbr_part.vmdk");
+        RawDiskVmdk::new(&device)
+            .partitions(vec![5])
+            .create(&vmdk)
+            .unwrap();
+        let descriptor = std::fs::read_to_string(&vmdk).unwrap();
+        assert!(descriptor.contains("createType=\"partitionedDevice\""));
+        assert_eq!(
+            extent_lines(&descriptor),
+            vec![
+                "RW 2048 FLAT \"mbr_part-pt.vmdk\" 0".to_string(),
+                "RW 6144 ZERO".to_string(),
+                "RW 1 FLAT \"mbr_part-pt.vmdk\" 2048".to_string(),
+                "RW 2047 ZERO".to_string(),
+                format!("RW 4096 FLAT \"{}\" 10240", device),
+                "RW 18432 ZERO".to_string(),
+            ]
+        );
+
+        let shadow = std::fs::read(fixture_path("mbr_part", "mbr_part-pt.vmdk")).unwrap();
+        assert_eq!(shadow.len() as u64, 2049 * SECTOR_SIZE);
+        assert_eq!(shadow[0], 0xEB);
+        assert_eq!(&shadow[510..512], &[0x55, 0xAA]);
+        assert_eq!(&shadow[2048 * 512 + 510..2048 * 512 + 512], &[0x55, 0xAA]);
+        remove_fixtures("mbr_part");
+    }
+
+    #[test]
+    fn partitioned_gpt_descriptor_shadows_both_tables() {
+        let device = gpt_fixture("gpt_part");
+        let (table_type, partitions) = RawDiskVmdk::new(&device).read_partitions().unwrap();
+        assert_eq!(table_type, PartitionTableType::GPT);
+        assert_eq!(partitions[0].name, "root");
+        assert_eq!(
+            partitions[0].gpt_type,
+            "0fc63daf-8483-4772-8e79-3d69d8477de4"
+        );
+
+        let vmdk = fixture_path("gpt_part", "gpt_part.vmdk");
+        RawDiskVmdk::new(&device)
+            .partitions(vec![1])
+            .read_only(true)
+            .create(&vmdk)
+            .unwrap();
+        let descriptor = std::fs::read_to_string(&vmdk).unwrap();
+        assert_eq!(
+            extent_lines(&descriptor),
+            vec![
+                "RDONLY 2048 FLAT \"gpt_part-pt.vmdk\" 0".to_string(),
+                format!("RDONLY 4096 FLAT \"{}\" 2048", device),
+                format!("RDONLY {} ZERO", DISK_SECTORS - 33 - 6144),
+                "RDONLY 33 FLAT \"gpt_part-pt.vmdk\" 2048".to_string(),
+            ]
+        );
+        remove_fixtures("gpt_part");
+    }
+
+    #[test]
+    fn overflowing_gpt_entry_is_rejected() {
+        let device = gpt_fixture("gpt_overflow");
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&device)
+            .unwrap();
+        let mut bounds = [0u8; 16];
+        bounds[..8].copy_from_slice(&(u64::MAX - 1).to_le_bytes());
+        bounds[8..].copy_from_slice(&u64::MAX.to_le_bytes());
+        file.seek(SeekFrom::Start(2 * SECTOR_SIZE + 32)).unwrap();
+        file.write_all(&bounds).unwrap();
+        assert!(RawDiskVmdk::new(&device).read_partitions().is_err());
+        remove_fixtures("gpt_overflow");
+    }
+
+    #[test]
+    fn unknown_partition_is_rejected() {
+        let device = mbr_fixture("mbr_unknown");
+        let result = RawDiskVmdk::new(&device)
+            .partitions(vec![3])
+            .create(&fixture_path("mbr_unknown", "mbr_unknown.vmdk"));
+        assert!(result.is_err());
+        remove_fixtures("mbr_unknown");
+    }
+}
@@ -0,0 +1,278 @@
+use crate::enums::PartitionTableType;
+use crate::VboxError;
+use std::io::{Read, Seek, SeekFrom};
+
+pub(crate) const SECTOR_SIZE: u64 = 512;
+
+const MBR_SIGNATURE: [u8; 2] = [0x55, 0xAA];
+const MBR_TYPE_GPT_PROTECTIVE: u8 = 0xEE;
+const MBR_EXTENDED_TYPES: [u8; 3] = [0x05, 0x0F, 0x85];
+const GPT_SIGNATURE: &[u8; 8] = b"EFI PART";
+const MAX_LOGICAL_PARTITIONS: u32 = 128;
+
+/// A partition found in the partition table of a host disk.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HostPartition {
+    /// Partition number as the host uses it: 1-4 for MBR primary partitions, 5 and above for
+    /// MBR logical partitions, the 1-based entry index for GPT.
+    pub number: u32,
+    /// First sector of the partition.
+    pub start: u64,
+    /// Size of the partition in sectors.
+    pub sectors: u64,
+    /// MBR partition type. 0 for GPT partitions.
+    pub mbr_type: u8,
+    /// GPT partition type GUID. Empty for MBR partitions.
+    pub gpt_type: String,
+    /// GPT partition name. Empty for MBR partitions.
+    pub name: String,
+}
+
+/// Partition layout of a host disk.
+#[derive(Debug)]
+pub(crate) struct PartitionLayout {
+    pub table_type: PartitionTableType,
+    pub partitions: Vec<HostPartition>,
+    /// Sector ranges `(start, count)` which contain partition table data.
+    pub table_areas: Vec<(u64, u64)>,
+}
+
+pub(crate) fn read_sectors<R: Read + Seek>(
+    disk: &mut R,
+    start: u64,
+    count: u64,
+) -> Result<Vec<u8>, VboxError> {
+    let mut buf = vec![0u8; (count * SECTOR_SIZE) as usize];
+    disk.seek(SeekFrom::Start(start * SECTOR_SIZE))
+        .map_err(|err| VboxError::io_error("read_sectors", err))?;
+    disk.read_exact(&mut buf)
+        .map_err(|err| VboxError::io_error("read_sectors", err))?;
+    Ok(buf)
+}
+
+pub(crate) fn read_layout<R: Read + Seek>(
+    disk: &mut R,
+    total_sectors: u64,
+) -> Result<PartitionLayout, VboxError> {
+    let mbr = read_sectors(disk, 0, 1)?;
+    if mbr[510..512] != MBR_SIGNATURE {
+        return Err(VboxError::invalid_data(
+            "read_layout",
+            "No MBR signature found in sector 0".to_string(),
+        ));
+    }
+    let is_gpt = (0..4).any(|i| mbr[446 + i * 16 + 4] == MBR_TYPE_GPT_PROTECTIVE);
+    if is_gpt {
+        read_gpt(disk, total_sectors)
+    } else {
+        read_mbr(disk, &mbr, total_sectors)
+    }
+}
+
+fn read_mbr<R: Read + Seek>(
+    disk: &mut R,
+    mbr: &[u8],
+    total_sectors: u64,
+) -> Result<PartitionLayout, VboxError> {
+    let mut partitions = Vec::new();
+    let mut table_areas = vec![(0, 1)];
+    let mut extended = None;
+
+    for i in 0..4 {
+        let entry = &mbr[446 + i * 16..446 + (i + 1) * 16];
+        let mbr_type = entry[4];
+        let start = le_u32(entry, 8) as u64;
+        let sectors = le_u32(entry, 12) as u64;
+        if mbr_type == 0 || sectors == 0 {
+            continue;
+        }
+        check_bounds(start, sectors, total_sectors)?;
+        if MBR_EXTENDED_TYPES.contains(&mbr_type) {
+            extended = Some(start);
+            continue;
+        }
+        partitions.push(HostPartition {
+            number: i as u32 + 1,
+            start,
+            sectors,
+            mbr_type,
+            gpt_type: String::new(),
+            name: String::new(),
+        });
+    }
+
+    if let Some(extended_start) = extended {
+        let mut ebr_lba = extended_start;
+        let mut number = 5;
+        while number < 5 + MAX_LOGICAL_PARTITIONS {
+            let ebr = read_sectors(disk, ebr_lba, 1)?;
+            if ebr[510..512] != MBR_SIGNATURE {
+                return Err(VboxError::invalid_data(
+                    "read_mbr",
+                    format!("No EBR signature found in sector {}", ebr_lba),
+                ));
+            }
+            table_areas.push((ebr_lba, 1));
+
+            let mbr_type = ebr[446 + 4];
+            let sectors = le_u32(&ebr, 446 + 12) as u64;
+            if mbr_type != 0 && sectors != 0 {
+                let start = area_end(ebr_lba, le_u32(&ebr, 446 + 8) as u64)?;
+                check_bounds(start, sectors, total_sectors)?;
+                partitions.push(HostPartition {
+                    number,
+                    start,
+                    sectors,
+                    mbr_type,
+                    gpt_type: String::new(),
+                    name: String::new(),
+                });
+                number += 1;
+            }
+
+            let next_type = ebr[462 + 4];
+            let next = le_u32(&ebr, 462 + 8) as u64;
+            if next_type == 0 || next == 0 {
+                break;
+            }
+            let next_lba = extended_start + next;
+            if next_lba <= ebr_lba {
+                return Err(VboxError::invalid_data(
+                    "read_mbr",
+                    format!("EBR chain loops back at sector {}", next_lba),
+                ));
+            }
+            ebr_lba = next_lba;
+        }
+    }
+
+    partitions.sort_by_key(|partition| partition.start);
+    Ok(PartitionLayout {
+        table_type: PartitionTableType::MBR,
+        partitions,
+        table_areas,
+    })
+}
+
+fn read_gpt<R: Read + Seek>(
+    disk: &mut R,
+    total_sectors: u64,
+) -> Result<PartitionLayout, VboxError> {
+    let header = read_sectors(disk, 1, 1)?;
+    if &header[0..8] != GPT_SIGNATURE {
+        return Err(VboxError::invalid_data(
+            "read_gpt",
+            "No GPT header found in sector 1".to_string(),
+        ));
+    }
+    let alternate_lba = le_u64(&header, 32);
+    let entries_lba = le_u64(&header, 72);
+    let entries_count = le_u32(&header, 80) as u64;
+    let entry_size = le_u32(&header, 84) as u64;
+    if entry_size < 128 || entries_count == 0 || entries_count > 4096 {
+        return Err(VboxError::invalid_data(
+            "read_gpt",
+            format!(
+                "Unsupported GPT entry layout: {} entries of {} bytes",
+                entries_count, entry_size
+            ),
+        ));
+    }
+    let entries_sectors = (entries_count * entry_size).div_ceil(SECTOR_SIZE);
+    check_bounds(entries_lba, entries_sectors, total_sectors)?;
+
+    let entries = read_sectors(disk, entries_lba, entries_sectors)?;
+    let mut partitions = Vec::new();
+    for i in 0..entries_count {
+        let entry = &entries[(i * entry_size) as usize..((i + 1) * entry_size) as usize];
+        if entry[0..16].iter().all(|b| *b == 0) {
+            continue;
+        }
+        let start = le_u64(entry, 32);
+        let last = le_u64(entry, 40);
+        if last < start {
+            continue;
+        }
+        let sectors = area_end(last - start, 1)?;
+        check_bounds(start, sectors, total_sectors)?;
+        let name: Vec<u16> = entry[56..128]
+            .chunks(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .take_while(|c| *c != 0)
+            .collect();
+        partitions.push(HostPartition {
+            number: i as u32 + 1,
+            start,
+            sectors,
+            mbr_type: 0,
+            gpt_type: format_guid(&entry[0..16]),
+            name: String::from_utf16_lossy(&name),
+        });
+    }
+    partitions.sort_by_key(|partition| partition.start);
+
+    let mut table_areas = vec![(0, area_end(entries_lba, entries_sectors)?)];
+    if alternate_lba < total_sectors && alternate_lba > entries_sectors {
+        table_areas.push((alternate_lba - entries_sectors, entries_sectors + 1));
+    }
+    Ok(PartitionLayout {
+        table_type: PartitionTableType::GPT,
+        partitions,
+        table_areas,
+    })
+}
+
+fn check_bounds(start: u64, sectors: u64, total_sectors: u64) -> Result<(), VboxError> {
+    if area_end(start, sectors)? > total_sectors {
+        return Err(VboxError::invalid_data(
+            "check_bounds",
+            format!(
+                "Area {}+{} is beyond the end of the disk ({} sectors)",
+                start, sectors, total_sectors
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the sector after an area, failing on values which do not fit in a sector number.
+fn area_end(start: u64, sectors: u64) -> Result<u64, VboxError> {
+    start.checked_add(sectors).ok_or_else(|| {
+        VboxError::invalid_data(
+            "area_end",
+            format!("Area {}+{} overflows the sector number", start, sectors),
+        )
+    })
+}
+
+pub(crate) fn format_guid(bytes: &[u8]) -> String {
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        le_u32(bytes, 0),
+        u16::from_le_bytes([bytes[4], bytes[5]]),
+        u16::from_le_bytes([bytes[6], bytes[7]]),
+        bytes[8],
+        bytes[9],
+        bytes[10],
+        bytes[11],
+        bytes[12],
+        bytes[13],
+        bytes[14],
+        bytes[15]
+    )
+}
+
+fn le_u32(buf: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ])
+}
+
+fn le_u64(buf: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
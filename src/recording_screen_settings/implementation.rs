@@ -0,0 +1,1408 @@
+use crate::enums::{
+    RecordingAudioCodec, RecordingCodecDeadline, RecordingDestination, RecordingFeature,
+    RecordingRateControlMode, RecordingVideoCodec, RecordingVideoScalingMode,
+};
+#[cfg(not(is_v_6_1))]
+use crate::utility::macros::macros::get_function_result_pointer_vec;
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{RecordingScreenSettings, VboxError};
+
+impl RecordingScreenSettings {
+    /// This attribute contains the screen ID bound to these settings.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let id = screen_settings.get_id().unwrap();
+    pub fn get_id(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetId, u32)
+    }
+
+    /// This setting determines whether this screen is enabled while recording.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let enabled = screen_settings.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// This setting determines whether this screen is enabled while recording.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_enabled(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// This setting determines all enabled recording features for this screen.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<RecordingFeature>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let features = screen_settings.get_features().unwrap();
+    pub fn get_features(&self) -> Result<Vec<RecordingFeature>, VboxError> {
+        #[cfg(not(is_v_6_1))]
+        {
+            let features = get_function_result_pointer_vec!(self.object, GetFeatures, u32)?;
+            Ok(features.into_iter().map(RecordingFeature::from).collect())
+        }
+        #[cfg(is_v_6_1)]
+        {
+            let features = get_function_result_number!(self.object, GetFeatures, u32)?;
+            Ok([RecordingFeature::Video, RecordingFeature::Audio]
+                .into_iter()
+                .filter(|feature| {
+                    let feature: u32 = (*feature).into();
+                    features & feature != 0
+                })
+                .collect())
+        }
+    }
+
+    /// This setting determines all enabled recording features for this screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - Vec<[`RecordingFeature`]>.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingFeature, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings
+    ///     .set_features(vec![RecordingFeature::Video, RecordingFeature::Audio])
+    ///     .unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_features(&self, features: Vec<RecordingFeature>) -> Result<(), VboxError> {
+        #[cfg(not(is_v_6_1))]
+        {
+            let mut features: Vec<u32> =
+                features.into_iter().map(|feature| feature.into()).collect();
+            get_function_result_unit!(
+                self.object,
+                SetFeatures,
+                features.len() as u32,
+                features.as_mut_ptr()
+            )
+        }
+        #[cfg(is_v_6_1)]
+        {
+            let features = features.into_iter().fold(0, |mask, feature| {
+                let feature: u32 = feature.into();
+                mask | feature
+            });
+            get_function_result_unit!(self.object, SetFeatures, features)
+        }
+    }
+
+    /// This setting determines the recording destination for this screen.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingDestination`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let destination = screen_settings.get_destination().unwrap();
+    pub fn get_destination(&self) -> Result<RecordingDestination, VboxError> {
+        let destination = get_function_result_number!(self.object, GetDestination, u32)?;
+        Ok(RecordingDestination::from(destination))
+    }
+
+    /// This setting determines the recording destination for this screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - [`RecordingDestination`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingDestination, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_destination(RecordingDestination::File).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_destination(&self, destination: RecordingDestination) -> Result<(), VboxError> {
+        let destination: u32 = destination.into();
+        get_function_result_unit!(self.object, SetDestination, destination)
+    }
+
+    /// This setting determines the filename VirtualBox uses to save the recorded content.
+    ///
+    /// This setting cannot be changed while video recording is enabled.
+    ///
+    /// When setting this attribute, the specified path has to be absolute (full path). When reading this attribute, a full path is always returned.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let filename = screen_settings.get_filename().unwrap();
+    pub fn get_filename(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetFilename)
+    }
+
+    /// This setting determines the filename VirtualBox uses to save the recorded content.
+    ///
+    /// This setting cannot be changed while video recording is enabled.
+    ///
+    /// When setting this attribute, the specified path has to be absolute (full path). When reading this attribute, a full path is always returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_filename("/tmp/freebsd_14_screen0.webm").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_filename(&self, filename: &str) -> Result<(), VboxError> {
+        let filename = string_to_c_u64_str(filename)?;
+        get_function_result_unit!(self.object, SetFilename, filename)
+    }
+
+    /// This setting defines the maximum amount of time in seconds to record. Recording will stop as soon as the defined time interval has elapsed.
+    ///
+    /// If this value is zero, recording will not be limited by time.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let max_time = screen_settings.get_max_time().unwrap();
+    pub fn get_max_time(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetMaxTime, u32)
+    }
+
+    /// This setting defines the maximum amount of time in seconds to record. Recording will stop as soon as the defined time interval has elapsed.
+    ///
+    /// If this value is zero, recording will not be limited by time.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_time` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_max_time(600).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_max_time(&self, max_time: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetMaxTime, max_time)
+    }
+
+    /// This setting determines the maximal number of recording file size in MB. Recording will stop as soon as the file size has reached the defined value.
+    ///
+    /// If this value is zero, recording will not be limited by the file size.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let max_file_size = screen_settings.get_max_file_size().unwrap();
+    pub fn get_max_file_size(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetMaxFileSize, u32)
+    }
+
+    /// This setting determines the maximal number of recording file size in MB. Recording will stop as soon as the file size has reached the defined value.
+    ///
+    /// If this value is zero, recording will not be limited by the file size.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_file_size` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_max_file_size(100).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_max_file_size(&self, max_file_size: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetMaxFileSize, max_file_size)
+    }
+
+    /// This setting contains any additional recording options required in comma-separated key=value format, which are currently not represented via own attributes.
+    ///
+    /// Consider these options as experimental and mainly for testing purposes and thus can change in the future.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let options = screen_settings.get_options().unwrap();
+    pub fn get_options(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetOptions)
+    }
+
+    /// This setting contains any additional recording options required in comma-separated key=value format, which are currently not represented via own attributes.
+    ///
+    /// Consider these options as experimental and mainly for testing purposes and thus can change in the future.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_options("ac_enabled=false").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_options(&self, options: &str) -> Result<(), VboxError> {
+        let options = string_to_c_u64_str(options)?;
+        get_function_result_unit!(self.object, SetOptions, options)
+    }
+
+    /// Determines the audio codec to use for encoding the recorded audio data.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingAudioCodec`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_codec = screen_settings.get_audio_codec().unwrap();
+    pub fn get_audio_codec(&self) -> Result<RecordingAudioCodec, VboxError> {
+        let audio_codec = get_function_result_number!(self.object, GetAudioCodec, u32)?;
+        Ok(RecordingAudioCodec::from(audio_codec))
+    }
+
+    /// Determines the audio codec to use for encoding the recorded audio data.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_codec` - [`RecordingAudioCodec`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingAudioCodec, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_codec(RecordingAudioCodec::Opus).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_codec(&self, audio_codec: RecordingAudioCodec) -> Result<(), VboxError> {
+        let audio_codec: u32 = audio_codec.into();
+        get_function_result_unit!(self.object, SetAudioCodec, audio_codec)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the rate control mode for audio encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingRateControlMode`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_rate_control_mode = screen_settings.get_audio_rate_control_mode().unwrap();
+    pub fn get_audio_rate_control_mode(&self) -> Result<RecordingRateControlMode, VboxError> {
+        let audio_rate_control_mode =
+            get_function_result_number!(self.object, GetAudioRateControlMode, u32)?;
+        Ok(RecordingRateControlMode::from(audio_rate_control_mode))
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the rate control mode for audio encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_rate_control_mode` - [`RecordingRateControlMode`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingRateControlMode, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_rate_control_mode(RecordingRateControlMode::VBR).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_rate_control_mode(
+        &self,
+        audio_rate_control_mode: RecordingRateControlMode,
+    ) -> Result<(), VboxError> {
+        let audio_rate_control_mode: u32 = audio_rate_control_mode.into();
+        get_function_result_unit!(
+            self.object,
+            SetAudioRateControlMode,
+            audio_rate_control_mode
+        )
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the encoding deadline to use for audio encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingCodecDeadline`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_deadline = screen_settings.get_audio_deadline().unwrap();
+    pub fn get_audio_deadline(&self) -> Result<RecordingCodecDeadline, VboxError> {
+        let audio_deadline = get_function_result_number!(self.object, GetAudioDeadline, u32)?;
+        Ok(RecordingCodecDeadline::from(audio_deadline))
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the encoding deadline to use for audio encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_deadline` - [`RecordingCodecDeadline`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingCodecDeadline, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_deadline(RecordingCodecDeadline::Realtime).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_deadline(
+        &self,
+        audio_deadline: RecordingCodecDeadline,
+    ) -> Result<(), VboxError> {
+        let audio_deadline: u32 = audio_deadline.into();
+        get_function_result_unit!(self.object, SetAudioDeadline, audio_deadline)
+    }
+
+    /// Determines the Hertz (Hz) rate of the recorded audio data.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_hz = screen_settings.get_audio_hz().unwrap();
+    pub fn get_audio_hz(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetAudioHz, u32)
+    }
+
+    /// Determines the Hertz (Hz) rate of the recorded audio data.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_hz` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_hz(48000).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_hz(&self, audio_hz: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetAudioHz, audio_hz)
+    }
+
+    /// Determines the bits per sample of the recorded audio data.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_bits = screen_settings.get_audio_bits().unwrap();
+    pub fn get_audio_bits(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetAudioBits, u32)
+    }
+
+    /// Determines the bits per sample of the recorded audio data.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_bits` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_bits(16).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_bits(&self, audio_bits: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetAudioBits, audio_bits)
+    }
+
+    /// Determines the audio channels of the recorded audio data.
+    ///
+    /// Specify 2 for stereo or 1 for mono. More than stereo (2) channels are not supported at the moment.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let audio_channels = screen_settings.get_audio_channels().unwrap();
+    pub fn get_audio_channels(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetAudioChannels, u32)
+    }
+
+    /// Determines the audio channels of the recorded audio data.
+    ///
+    /// Specify 2 for stereo or 1 for mono. More than stereo (2) channels are not supported at the moment.
+    ///
+    /// # Arguments
+    ///
+    /// * `audio_channels` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_audio_channels(2).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_audio_channels(&self, audio_channels: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetAudioChannels, audio_channels)
+    }
+
+    /// Determines the video codec to use for encoding the recorded video data.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingVideoCodec`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_codec = screen_settings.get_video_codec().unwrap();
+    pub fn get_video_codec(&self) -> Result<RecordingVideoCodec, VboxError> {
+        let video_codec = get_function_result_number!(self.object, GetVideoCodec, u32)?;
+        Ok(RecordingVideoCodec::from(video_codec))
+    }
+
+    /// Determines the video codec to use for encoding the recorded video data.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_codec` - [`RecordingVideoCodec`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingVideoCodec, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_codec(RecordingVideoCodec::VP8).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_codec(&self, video_codec: RecordingVideoCodec) -> Result<(), VboxError> {
+        let video_codec: u32 = video_codec.into();
+        get_function_result_unit!(self.object, SetVideoCodec, video_codec)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the encoding deadline to use for video encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingCodecDeadline`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_deadline = screen_settings.get_video_deadline().unwrap();
+    pub fn get_video_deadline(&self) -> Result<RecordingCodecDeadline, VboxError> {
+        let video_deadline = get_function_result_number!(self.object, GetVideoDeadline, u32)?;
+        Ok(RecordingCodecDeadline::from(video_deadline))
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// Determines the encoding deadline to use for video encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_deadline` - [`RecordingCodecDeadline`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingCodecDeadline, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_deadline(RecordingCodecDeadline::Good).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_deadline(
+        &self,
+        video_deadline: RecordingCodecDeadline,
+    ) -> Result<(), VboxError> {
+        let video_deadline: u32 = video_deadline.into();
+        get_function_result_unit!(self.object, SetVideoDeadline, video_deadline)
+    }
+
+    /// This setting determines the horizontal resolution of the recorded video.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_width = screen_settings.get_video_width().unwrap();
+    pub fn get_video_width(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetVideoWidth, u32)
+    }
+
+    /// This setting determines the horizontal resolution of the recorded video.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_width` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_width(1024).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_width(&self, video_width: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetVideoWidth, video_width)
+    }
+
+    /// This setting determines the vertical resolution of the recorded video.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_height = screen_settings.get_video_height().unwrap();
+    pub fn get_video_height(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetVideoHeight, u32)
+    }
+
+    /// This setting determines the vertical resolution of the recorded video.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_height` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_height(768).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_height(&self, video_height: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetVideoHeight, video_height)
+    }
+
+    /// This setting determines the bitrate in kilobits per second.
+    ///
+    /// Increasing this value makes the video look better for the cost of an increased file size or transfer rate.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_rate = screen_settings.get_video_rate().unwrap();
+    pub fn get_video_rate(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetVideoRate, u32)
+    }
+
+    /// This setting determines the bitrate in kilobits per second.
+    ///
+    /// Increasing this value makes the video look better for the cost of an increased file size or transfer rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_rate` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_rate(512).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_rate(&self, video_rate: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetVideoRate, video_rate)
+    }
+
+    /// Determines the rate control mode for video encoding.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingRateControlMode`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_rate_control_mode = screen_settings.get_video_rate_control_mode().unwrap();
+    pub fn get_video_rate_control_mode(&self) -> Result<RecordingRateControlMode, VboxError> {
+        let video_rate_control_mode =
+            get_function_result_number!(self.object, GetVideoRateControlMode, u32)?;
+        Ok(RecordingRateControlMode::from(video_rate_control_mode))
+    }
+
+    /// Determines the rate control mode for video encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_rate_control_mode` - [`RecordingRateControlMode`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingRateControlMode, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_rate_control_mode(RecordingRateControlMode::CBR).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_rate_control_mode(
+        &self,
+        video_rate_control_mode: RecordingRateControlMode,
+    ) -> Result<(), VboxError> {
+        let video_rate_control_mode: u32 = video_rate_control_mode.into();
+        get_function_result_unit!(
+            self.object,
+            SetVideoRateControlMode,
+            video_rate_control_mode
+        )
+    }
+
+    /// This setting determines the maximum number of frames per second (FPS).
+    ///
+    /// Frames with a higher frequency will be skipped. Reducing this value increases the number of skipped frames and reduces the file size or transfer rate.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_fps = screen_settings.get_video_fps().unwrap();
+    pub fn get_video_fps(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetVideoFPS, u32)
+    }
+
+    /// This setting determines the maximum number of frames per second (FPS).
+    ///
+    /// Frames with a higher frequency will be skipped. Reducing this value increases the number of skipped frames and reduces the file size or transfer rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_fps` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_fps(25).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_fps(&self, video_fps: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetVideoFPS, video_fps)
+    }
+
+    /// Determines the scaling mode to use when the video resolution differs from the guest screen resolution.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingVideoScalingMode`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video_scaling_mode = screen_settings.get_video_scaling_mode().unwrap();
+    pub fn get_video_scaling_mode(&self) -> Result<RecordingVideoScalingMode, VboxError> {
+        #[cfg(not(is_v_6_1))]
+        let video_scaling_mode =
+            get_function_result_number!(self.object, GetVideoScalingMode, u32)?;
+        #[cfg(is_v_6_1)]
+        let video_scaling_mode =
+            get_function_result_number!(self.object, GetVideoScalingMethod, u32)?;
+        Ok(RecordingVideoScalingMode::from(video_scaling_mode))
+    }
+
+    /// Determines the scaling mode to use when the video resolution differs from the guest screen resolution.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_scaling_mode` - [`RecordingVideoScalingMode`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{RecordingVideoScalingMode, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// screen_settings.set_video_scaling_mode(RecordingVideoScalingMode::Bilinear).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_video_scaling_mode(
+        &self,
+        video_scaling_mode: RecordingVideoScalingMode,
+    ) -> Result<(), VboxError> {
+        let video_scaling_mode: u32 = video_scaling_mode.into();
+        #[cfg(not(is_v_6_1))]
+        {
+            get_function_result_unit!(self.object, SetVideoScalingMode, video_scaling_mode)
+        }
+        #[cfg(is_v_6_1)]
+        {
+            get_function_result_unit!(self.object, SetVideoScalingMethod, video_scaling_mode)
+        }
+    }
+
+    /// Returns whether a particular recording feature is enabled for this screen or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - [`RecordingFeature`]. Feature to check for.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    /// use virtualbox_rs::enums::RecordingFeature;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    /// let video = screen_settings.is_feature_enabled(RecordingFeature::Video).unwrap();
+    pub fn is_feature_enabled(&self, feature: RecordingFeature) -> Result<bool, VboxError> {
+        let feature: u32 = feature.into();
+        get_function_result_bool!(self.object, IsFeatureEnabled, feature)
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl RecordingScreenSettings {
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_audio_rate_control_mode(&self) -> Result<RecordingRateControlMode, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::get_audio_rate_control_mode",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_audio_rate_control_mode(
+        &self,
+        _audio_rate_control_mode: RecordingRateControlMode,
+    ) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::set_audio_rate_control_mode",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_audio_deadline(&self) -> Result<RecordingCodecDeadline, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::get_audio_deadline",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_audio_deadline(
+        &self,
+        _audio_deadline: RecordingCodecDeadline,
+    ) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::set_audio_deadline",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn get_video_deadline(&self) -> Result<RecordingCodecDeadline, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::get_video_deadline",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_0
+    pub fn set_video_deadline(
+        &self,
+        _video_deadline: RecordingCodecDeadline,
+    ) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingScreenSettings::set_video_deadline",
+            "v7_0",
+        ))
+    }
+}
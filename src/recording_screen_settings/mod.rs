@@ -0,0 +1,41 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IRecordingScreenSettings;
+
+/// The [`RecordingScreenSettings`] interface represents recording settings of a single virtual screen.
+///
+/// It is used in conjunction with [`crate::RecordingSettings`].
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_recording_screen_settings.html](https://www.virtualbox.org/sdkref/interface_i_recording_screen_settings.html)
+#[derive(Debug)]
+pub struct RecordingScreenSettings {
+    object: *mut IRecordingScreenSettings,
+}
+
+impl RecordingScreenSettings {
+    pub(crate) fn new(object: *mut IRecordingScreenSettings) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for RecordingScreenSettings {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("RecordingScreenSettings refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop RecordingScreenSettings. Error: {:?}", err)
+            }
+        }
+    }
+}
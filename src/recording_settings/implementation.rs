@@ -0,0 +1,213 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_pointer, get_function_result_pointer_vec,
+    get_function_result_unit,
+};
+use crate::{Progress, RecordingScreenSettings, RecordingSettings, VboxError};
+#[cfg(is_v_7_1)]
+use vbox_raw::sys_lib::IProgress;
+use vbox_raw::sys_lib::IRecordingScreenSettings;
+
+impl RecordingSettings {
+    /// This setting determines whether VirtualBox uses recording to record a VM session.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let enabled = recording_settings.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// This setting determines whether VirtualBox uses recording to record a VM session.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// recording_settings.set_enabled(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// This setting returns an array for recording settings of all configured virtual screens.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<RecordingScreenSettings>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screens = recording_settings.get_screens().unwrap();
+    pub fn get_screens(&self) -> Result<Vec<RecordingScreenSettings>, VboxError> {
+        let screens = get_function_result_pointer_vec!(
+            self.object,
+            GetScreens,
+            *mut IRecordingScreenSettings
+        )?;
+        Ok(screens
+            .into_iter()
+            .map(RecordingScreenSettings::new)
+            .collect())
+    }
+
+    /// Returns the recording settings for a particular screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen_id` - u32. Screen ID to retrieve recording screen settings for.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`RecordingScreenSettings`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let recording_settings = machine.get_recording_settings().unwrap();
+    /// let screen_settings = recording_settings.get_screen_settings(0).unwrap();
+    pub fn get_screen_settings(
+        &self,
+        screen_id: u32,
+    ) -> Result<RecordingScreenSettings, VboxError> {
+        let screen_settings = get_function_result_pointer!(
+            self.object,
+            GetScreenSettings,
+            *mut IRecordingScreenSettings,
+            screen_id
+        )?;
+        Ok(RecordingScreenSettings::new(screen_settings))
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Progress object to track the current recording operation.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let progress = recording_settings.get_progress().unwrap();
+    pub fn get_progress(&self) -> Result<Progress, VboxError> {
+        let progress = get_function_result_pointer!(self.object, GetProgress, *mut IProgress)?;
+        Ok(Progress::new(progress))
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Starts recording of the running virtual machine.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let recording_settings = machine_mut.get_recording_settings().unwrap();
+    /// let progress = recording_settings.start().unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn start(&self) -> Result<Progress, VboxError> {
+        let progress = get_function_result_pointer!(self.object, Start, *mut IProgress)?;
+        Ok(Progress::new(progress))
+    }
+}
+
+#[cfg(not(is_v_7_1))]
+impl RecordingSettings {
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_1
+    pub fn get_progress(&self) -> Result<Progress, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingSettings::get_progress",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method serves as a placeholder for versions of the API where the actual method is not available.
+    /// Attempting to call this method will result in an `UnsupportedInCurrentApiVersion` error.
+    /// This method is intended to ensure that the codebase can be compiled against multiple API versions
+    /// without modification.
+    ///
+    /// Supported from API version: v7_1
+    pub fn start(&self) -> Result<Progress, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "RecordingSettings::start",
+            "v7_1",
+        ))
+    }
+}
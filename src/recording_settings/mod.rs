@@ -1,9 +1,13 @@
+mod implementation;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IRecordingSettings;
 
-/// The [`RecordingSettings`] interface represents recording settings of the virtual machine. More...
+/// The [`RecordingSettings`] interface represents recording settings of the virtual machine.
+///
+/// This is used only in the [`crate::Machine::get_recording_settings`] attribute.
 ///
 /// **Reference to the official documentation:**
 ///
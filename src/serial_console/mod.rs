@@ -0,0 +1,542 @@
+use crate::VboxError;
+use log::{debug, error};
+use regex::bytes::Regex;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Result of a successful [`SerialConsole::expect`] call.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpectMatch {
+    /// Output received before the match.
+    pub before: String,
+    /// The text matched by the pattern.
+    pub matched: String,
+    /// Capture groups of the pattern, `None` for groups which did not participate in the match.
+    pub captures: Vec<Option<String>>,
+}
+
+/// Connection handle used to shut the socket down, which also stops the reader thread.
+enum Connection {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixStream),
+    #[cfg(not(unix))]
+    Pipe,
+}
+
+impl Connection {
+    fn shutdown(&self) -> std::io::Result<()> {
+        let result = match self {
+            Connection::Tcp(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(unix)]
+            Connection::Unix(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(not(unix))]
+            Connection::Pipe => Ok(()),
+        };
+        match result {
+            Err(err) if err.kind() == ErrorKind::NotConnected => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// Expect-style client for the serial port of a virtual machine.
+///
+/// The serial port has to be attached to the host with [`crate::enums::PortMode::TCP`] or
+/// [`crate::enums::PortMode::HostPipe`] in server mode. A background thread reads the output of
+/// the guest; [`SerialConsole::expect`] waits until the pending output matches a pattern.
+/// The connection is shut down by [`SerialConsole::close`] or when the console is dropped, so the
+/// serial port accepts the next client.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use virtualbox_rs::SerialConsole;
+///
+/// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+/// console.set_transcript_file("/tmp/freebsd_14_console.log").unwrap();
+/// console.expect("login: ", Duration::from_secs(120)).unwrap();
+/// console.send_line("root").unwrap();
+/// console.expect(r"Password:", Duration::from_secs(10)).unwrap();
+/// ```
+pub struct SerialConsole {
+    writer: Box<dyn Write + Send>,
+    receiver: Receiver<Vec<u8>>,
+    pending: Vec<u8>,
+    transcript: Vec<u8>,
+    transcript_file: Option<File>,
+    line_ending: String,
+    closed: bool,
+    connection: Connection,
+}
+
+impl SerialConsole {
+    /// Connects to a serial port attached to a TCP socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - &str. `host:port` the serial port listens on.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`SerialConsole`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    pub fn connect_tcp(address: &str) -> Result<Self, VboxError> {
+        let stream = TcpStream::connect(address)
+            .map_err(|err| VboxError::io_error("SerialConsole::connect_tcp", err))?;
+        stream
+            .set_nodelay(true)
+            .map_err(|err| VboxError::io_error("SerialConsole::connect_tcp", err))?;
+        let clone = || {
+            stream
+                .try_clone()
+                .map_err(|err| VboxError::io_error("SerialConsole::connect_tcp", err))
+        };
+        let (reader, connection) = (clone()?, Connection::Tcp(clone()?));
+        Ok(Self::new(Box::new(reader), Box::new(stream), connection))
+    }
+
+    /// Connects to a serial port attached to a host pipe.
+    ///
+    /// On Unix hosts VirtualBox creates a local socket at the given path, on Windows hosts a named
+    /// pipe such as `\\.\pipe\freebsd_14`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Path of the pipe.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`SerialConsole`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let console = SerialConsole::connect_pipe("/tmp/freebsd_14_serial").unwrap();
+    pub fn connect_pipe(path: &str) -> Result<Self, VboxError> {
+        #[cfg(unix)]
+        {
+            let stream = std::os::unix::net::UnixStream::connect(path)
+                .map_err(|err| VboxError::io_error("SerialConsole::connect_pipe", err))?;
+            let clone = || {
+                stream
+                    .try_clone()
+                    .map_err(|err| VboxError::io_error("SerialConsole::connect_pipe", err))
+            };
+            let (reader, connection) = (clone()?, Connection::Unix(clone()?));
+            Ok(Self::new(Box::new(reader), Box::new(stream), connection))
+        }
+        #[cfg(not(unix))]
+        {
+            let pipe = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(path)
+                .map_err(|err| VboxError::io_error("SerialConsole::connect_pipe", err))?;
+            let reader = pipe
+                .try_clone()
+                .map_err(|err| VboxError::io_error("SerialConsole::connect_pipe", err))?;
+            Ok(Self::new(
+                Box::new(reader),
+                Box::new(pipe),
+                Connection::Pipe,
+            ))
+        }
+    }
+
+    fn new(
+        mut reader: Box<dyn Read + Send>,
+        writer: Box<dyn Write + Send>,
+        connection: Connection,
+    ) -> Self {
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(count) => {
+                        if sender.send(buf[..count].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                    Err(err) => {
+                        debug!("SerialConsole reader stopped: {:?}", err);
+                        break;
+                    }
+                }
+            }
+        });
+        Self {
+            writer,
+            receiver,
+            pending: Vec::new(),
+            transcript: Vec::new(),
+            transcript_file: None,
+            line_ending: "\r".to_string(),
+            closed: false,
+            connection,
+        }
+    }
+
+    /// Sets the line ending appended by [`SerialConsole::send_line`]. The default is `"\r"`,
+    /// which is what a terminal sends for the Enter key.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_ending` - &str.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.set_line_ending("\r\n");
+    pub fn set_line_ending(&mut self, line_ending: &str) {
+        self.line_ending = line_ending.to_string();
+    }
+
+    /// Writes everything received from the serial port to a file, in addition to the in-memory
+    /// transcript. The output received so far is written first.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Path of the transcript file. An existing file is truncated.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.set_transcript_file("/tmp/freebsd_14_console.log").unwrap();
+    pub fn set_transcript_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), VboxError> {
+        let mut file = File::create(path)
+            .map_err(|err| VboxError::io_error("SerialConsole::set_transcript_file", err))?;
+        file.write_all(&self.transcript)
+            .map_err(|err| VboxError::io_error("SerialConsole::set_transcript_file", err))?;
+        self.transcript_file = Some(file);
+        Ok(())
+    }
+
+    /// Everything received from the serial port so far.
+    ///
+    /// # Returns
+    ///
+    /// Returns the transcript, with invalid UTF-8 sequences replaced.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// println!("{}", console.transcript());
+    pub fn transcript(&self) -> String {
+        String::from_utf8_lossy(&self.transcript).to_string()
+    }
+
+    /// Sends text to the serial port as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.send("\x03").unwrap();
+    pub fn send(&mut self, text: &str) -> Result<(), VboxError> {
+        self.writer
+            .write_all(text.as_bytes())
+            .and_then(|_| self.writer.flush())
+            .map_err(|err| VboxError::io_error("SerialConsole::send", err))
+    }
+
+    /// Sends a line of text followed by the line ending.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.send_line("uname -a").unwrap();
+    pub fn send_line(&mut self, line: &str) -> Result<(), VboxError> {
+        let text = format!("{}{}", line, self.line_ending);
+        self.send(&text)
+    }
+
+    /// Waits until the output received since the previous match matches a regular expression.
+    ///
+    /// The output up to the end of the match is consumed; the rest is kept for the next call.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - &str. Regular expression in the syntax of the `regex` crate.
+    /// * `timeout` - Duration. How long to wait for the match.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`ExpectMatch`] on success, or a [`VboxError`] on failure. The error type is
+    /// [`crate::VboxErrorType::VBOX_E_TIMEOUT`] if there was no match in time, which converts to
+    /// [`ErrorKind::TimedOut`], and [`crate::VboxErrorType::IoError`] if the serial port was closed.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use std::time::Duration;
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.send_line("uname -r").unwrap();
+    /// let found = console.expect(r"(\d+)\.(\d+)-RELEASE", Duration::from_secs(5)).unwrap();
+    /// println!("major version: {:?}", found.captures[1]);
+    pub fn expect(&mut self, pattern: &str, timeout: Duration) -> Result<ExpectMatch, VboxError> {
+        let regex = Regex::new(pattern)
+            .map_err(|err| VboxError::invalid_data("SerialConsole::expect", format!("{}", err)))?;
+        // A timeout too large for Instant waits without a deadline.
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            if let Some(found) = self.take_match(&regex) {
+                return Ok(found);
+            }
+            if self.closed {
+                return Err(VboxError::io_error(
+                    "SerialConsole::expect",
+                    std::io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        format!("Serial port closed while waiting for {:?}", pattern),
+                    ),
+                ));
+            }
+            let received = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(VboxError::timeout(
+                            "SerialConsole::expect",
+                            format!("Timed out waiting for {:?}", pattern),
+                        ));
+                    }
+                    self.receiver.recv_timeout(deadline - now)
+                }
+                None => self
+                    .receiver
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(data) => self.push(data),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => self.closed = true,
+            }
+        }
+    }
+
+    /// Shuts the connection down. Later calls of [`SerialConsole::expect`] fail once the pending
+    /// output is consumed.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::SerialConsole;
+    ///
+    /// let mut console = SerialConsole::connect_tcp("127.0.0.1:2023").unwrap();
+    /// console.send_line("poweroff").unwrap();
+    /// console.close().unwrap();
+    pub fn close(&mut self) -> Result<(), VboxError> {
+        let flushed = self.writer.flush();
+        self.connection
+            .shutdown()
+            .map_err(|err| VboxError::io_error("SerialConsole::close", err))?;
+        flushed.map_err(|err| VboxError::io_error("SerialConsole::close", err))
+    }
+
+    fn push(&mut self, data: Vec<u8>) {
+        if let Some(file) = &mut self.transcript_file {
+            if let Err(err) = file.write_all(&data) {
+                error!("Failed to write serial console transcript: {:?}", err);
+            }
+        }
+        self.transcript.extend_from_slice(&data);
+        self.pending.extend(data);
+    }
+
+    fn take_match(&mut self, regex: &Regex) -> Option<ExpectMatch> {
+        let captures = regex.captures(&self.pending)?;
+        let whole = captures.get(0)?;
+        let found = ExpectMatch {
+            before: String::from_utf8_lossy(&self.pending[..whole.start()]).to_string(),
+            matched: String::from_utf8_lossy(whole.as_bytes()).to_string(),
+            captures: captures
+                .iter()
+                .map(|group| {
+                    group.map(|group| String::from_utf8_lossy(group.as_bytes()).to_string())
+                })
+                .collect(),
+        };
+        let end = whole.end();
+        self.pending.drain(..end);
+        Some(found)
+    }
+}
+
+impl Drop for SerialConsole {
+    fn drop(&mut self) {
+        if let Err(err) = self.connection.shutdown() {
+            debug!("SerialConsole: shutdown failed: {:?}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SerialConsole;
+    use crate::VboxErrorType;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Stand-in for a guest: prints a login prompt, then echoes everything back.
+    fn echo_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream.write_all(b"FreeBSD/amd64\r\n\r\nlogin: ").unwrap();
+            let mut buf = [0u8; 256];
+            loop {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(count) => stream.write_all(&buf[..count]).unwrap(),
+                }
+            }
+        });
+        address
+    }
+
+    #[test]
+    fn expect_and_send_line() {
+        let mut console = SerialConsole::connect_tcp(&echo_server()).unwrap();
+        let found = console
+            .expect(r"(\w+)/(\w+)\r\n", Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(found.before, "");
+        assert_eq!(found.captures[1].as_deref(), Some("FreeBSD"));
+        assert_eq!(found.captures[2].as_deref(), Some("amd64"));
+
+        let found = console.expect("login: ", Duration::from_secs(5)).unwrap();
+        assert_eq!(found.before, "\r\n");
+
+        console.send_line("root").unwrap();
+        let found = console.expect("ro+t\r", Duration::from_secs(5)).unwrap();
+        assert_eq!(found.matched, "root\r");
+        assert_eq!(console.transcript(), "FreeBSD/amd64\r\n\r\nlogin: root\r");
+    }
+
+    #[test]
+    fn expect_times_out() {
+        let mut console = SerialConsole::connect_tcp(&echo_server()).unwrap();
+        console.expect("login: ", Duration::from_secs(5)).unwrap();
+        let err = console
+            .expect("Password:", Duration::from_millis(100))
+            .unwrap_err();
+        assert_eq!(err.error_type, VboxErrorType::VBOX_E_TIMEOUT);
+        assert_eq!(
+            std::io::Error::from(err).kind(),
+            std::io::ErrorKind::TimedOut
+        );
+    }
+
+    #[test]
+    fn drop_closes_the_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let console = SerialConsole::connect_tcp(&address).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+        drop(console);
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let mut buf = [0u8; 16];
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn close_ends_expect() {
+        let mut console = SerialConsole::connect_tcp(&echo_server()).unwrap();
+        console.expect("login: ", Duration::from_secs(5)).unwrap();
+        console.close().unwrap();
+        let err = console
+            .expect("Password:", Duration::from_secs(5))
+            .unwrap_err();
+        assert_eq!(err.error_type, VboxErrorType::IoError, "{}", err.msg);
+        let err = console.expect("Password:", Duration::MAX).unwrap_err();
+        assert_eq!(err.error_type, VboxErrorType::IoError, "{}", err.msg);
+    }
+
+    #[test]
+    fn transcript_file_receives_output() {
+        let path = std::env::temp_dir().join(format!(
+            "virtualbox_rs_serial_console_{}.log",
+            std::process::id()
+        ));
+        let mut console = SerialConsole::connect_tcp(&echo_server()).unwrap();
+        console.set_transcript_file(&path).unwrap();
+        console.expect("login: ", Duration::from_secs(5)).unwrap();
+        drop(console);
+        let transcript = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(transcript, "FreeBSD/amd64\r\n\r\nlogin: ");
+    }
+}
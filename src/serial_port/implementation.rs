@@ -0,0 +1,430 @@
+use crate::enums::{PortMode, UartType};
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{SerialPort, VboxError};
+
+impl SerialPort {
+    /// Slot number this serial port is plugged into.
+    ///
+    /// Corresponds to the value you pass to [`crate::Machine::get_serial_port`] to obtain this instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let slot = serial_port.get_slot().unwrap();
+    pub fn get_slot(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetSlot, u32)
+    }
+
+    /// Flag whether the serial port is enabled. If disabled, the serial port will not be reported to the guest OS.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let enabled = serial_port.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Flag whether the serial port is enabled. If disabled, the serial port will not be reported to the guest OS.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_enabled(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// Base I/O address of the serial port.
+    ///
+    /// The default value is 0x3f8 for the first port.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let io_base = serial_port.get_io_base().unwrap();
+    pub fn get_io_base(&self) -> Result<u32, VboxError> {
+        #[cfg(is_v_7_1)]
+        {
+            get_function_result_number!(self.object, GetIOAddress, u32)
+        }
+        #[cfg(not(is_v_7_1))]
+        {
+            get_function_result_number!(self.object, GetIOBase, u32)
+        }
+    }
+
+    /// Base I/O address of the serial port.
+    ///
+    /// The default value is 0x3f8 for the first port.
+    ///
+    /// # Arguments
+    ///
+    /// * `io_base` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_io_base(0x3f8).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_io_base(&self, io_base: u32) -> Result<(), VboxError> {
+        #[cfg(is_v_7_1)]
+        {
+            get_function_result_unit!(self.object, SetIOAddress, io_base)
+        }
+        #[cfg(not(is_v_7_1))]
+        {
+            get_function_result_unit!(self.object, SetIOBase, io_base)
+        }
+    }
+
+    /// IRQ number of the serial port.
+    ///
+    /// The default value is 4 for the first port.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let irq = serial_port.get_irq().unwrap();
+    pub fn get_irq(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetIRQ, u32)
+    }
+
+    /// IRQ number of the serial port.
+    ///
+    /// The default value is 4 for the first port.
+    ///
+    /// # Arguments
+    ///
+    /// * `irq` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_irq(4).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_irq(&self, irq: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetIRQ, irq)
+    }
+
+    /// How is this port connected to the host.
+    ///
+    /// Changing this attribute may fail if the conditions for [`SerialPort::get_path`] are not met.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`PortMode`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let host_mode = serial_port.get_host_mode().unwrap();
+    pub fn get_host_mode(&self) -> Result<PortMode, VboxError> {
+        let host_mode = get_function_result_number!(self.object, GetHostMode, u32)?;
+        Ok(PortMode::from(host_mode))
+    }
+
+    /// How is this port connected to the host.
+    ///
+    /// Changing this attribute may fail if the conditions for [`SerialPort::get_path`] are not met.
+    ///
+    /// # Arguments
+    ///
+    /// * `host_mode` - [`PortMode`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{PortMode, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_host_mode(PortMode::RawFile).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_host_mode(&self, host_mode: PortMode) -> Result<(), VboxError> {
+        let host_mode: u32 = host_mode.into();
+        get_function_result_unit!(self.object, SetHostMode, host_mode)
+    }
+
+    /// Flag whether this serial port acts as a server (creates a new pipe or TCP socket on the host), or as a client (uses the existing pipe or TCP socket).
+    ///
+    /// This attribute is used only when [`SerialPort::get_host_mode`] is [`PortMode::HostPipe`] or [`PortMode::TCP`].
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let server = serial_port.get_server().unwrap();
+    pub fn get_server(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetServer)
+    }
+
+    /// Flag whether this serial port acts as a server (creates a new pipe or TCP socket on the host), or as a client (uses the existing pipe or TCP socket).
+    ///
+    /// This attribute is used only when [`SerialPort::get_host_mode`] is [`PortMode::HostPipe`] or [`PortMode::TCP`].
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_server(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_server(&self, server: bool) -> Result<(), VboxError> {
+        let server = if server { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetServer, server)
+    }
+
+    /// Path to the serial port.
+    ///
+    /// The meaning depends on [`SerialPort::get_host_mode`]: the pipe name for [`PortMode::HostPipe`], the host device for [`PortMode::HostDevice`], the file for [`PortMode::RawFile`] and the `[host:]port` address for [`PortMode::TCP`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let path = serial_port.get_path().unwrap();
+    pub fn get_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPath)
+    }
+
+    /// Path to the serial port.
+    ///
+    /// The meaning depends on [`SerialPort::get_host_mode`]: the pipe name for [`PortMode::HostPipe`], the host device for [`PortMode::HostDevice`], the file for [`PortMode::RawFile`] and the `[host:]port` address for [`PortMode::TCP`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_path("/tmp/freebsd_14_console.log").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_path(&self, path: &str) -> Result<(), VboxError> {
+        let path = string_to_c_u64_str(path)?;
+        get_function_result_unit!(self.object, SetPath, path)
+    }
+
+    /// Selects the emulated UART implementation.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`UartType`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let serial_port = machine.get_serial_port(0).unwrap();
+    /// let uart_type = serial_port.get_uart_type().unwrap();
+    pub fn get_uart_type(&self) -> Result<UartType, VboxError> {
+        let uart_type = get_function_result_number!(self.object, GetUartType, u32)?;
+        Ok(UartType::from(uart_type))
+    }
+
+    /// Selects the emulated UART implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `uart_type` - [`UartType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{SessionType, UartType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let serial_port = machine_mut.get_serial_port(0).unwrap();
+    /// serial_port.set_uart_type(UartType::U16550A).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_uart_type(&self, uart_type: UartType) -> Result<(), VboxError> {
+        let uart_type: u32 = uart_type.into();
+        get_function_result_unit!(self.object, SetUartType, uart_type)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_str, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{SharedFolder, VboxError};
+
+impl SharedFolder {
+    /// Logical name of the shared folder.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let name = shared_folder.get_name().unwrap();
+    pub fn get_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetName)
+    }
+
+    /// Full path to the shared folder in the host file system.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let host_path = shared_folder.get_host_path().unwrap();
+    pub fn get_host_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetHostPath)
+    }
+
+    /// Whether the folder defined by the host path is currently accessible or not.
+    ///
+    /// For example, the folder can be inaccessible if it is placed on the network share that is not available by the time this property is read.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let accessible = shared_folder.get_accessible().unwrap();
+    pub fn get_accessible(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetAccessible)
+    }
+
+    /// Whether the folder defined by the host path is writable or not.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let writable = shared_folder.get_writable().unwrap();
+    pub fn get_writable(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetWritable)
+    }
+
+    /// Whether the folder defined by the host path is writable or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `writable` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// shared_folder.set_writable(false).unwrap();
+    pub fn set_writable(&self, writable: bool) -> Result<(), VboxError> {
+        let writable = if writable { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetWritable, writable)
+    }
+
+    /// Whether the folder gets automatically mounted by the guest or not.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let auto_mount = shared_folder.get_auto_mount().unwrap();
+    pub fn get_auto_mount(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetAutoMount)
+    }
+
+    /// Whether the folder gets automatically mounted by the guest or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_mount` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// shared_folder.set_auto_mount(true).unwrap();
+    pub fn set_auto_mount(&self, auto_mount: bool) -> Result<(), VboxError> {
+        let auto_mount = if auto_mount { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetAutoMount, auto_mount)
+    }
+
+    /// Desired mount point in the guest for automatically mounting the folder when [`SharedFolder::get_auto_mount`] is set.
+    ///
+    /// For Windows and OS/2 guests this should be a drive letter, while other guests it should be a absolute directory.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let auto_mount_point = shared_folder.get_auto_mount_point().unwrap();
+    pub fn get_auto_mount_point(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetAutoMountPoint)
+    }
+
+    /// Desired mount point in the guest for automatically mounting the folder when [`SharedFolder::get_auto_mount`] is set.
+    ///
+    /// For Windows and OS/2 guests this should be a drive letter, while other guests it should be a absolute directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_mount_point` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// shared_folder.set_auto_mount_point("/mnt/sf1").unwrap();
+    pub fn set_auto_mount_point(&self, auto_mount_point: &str) -> Result<(), VboxError> {
+        let auto_mount_point = string_to_c_u64_str(auto_mount_point)?;
+        get_function_result_unit!(self.object, SetAutoMountPoint, auto_mount_point)
+    }
+
+    /// Text message that represents the result of the last accessibility check.
+    ///
+    /// Accessibility checks are performed each time the [`SharedFolder::get_accessible`] attribute is read. An empty string is returned if the last accessibility check was successful.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let shared_folders = machine.get_shared_folders().unwrap();
+    /// let shared_folder = shared_folders.get(0).unwrap();
+    /// let last_access_error = shared_folder.get_last_access_error().unwrap();
+    pub fn get_last_access_error(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLastAccessError)
+    }
+}
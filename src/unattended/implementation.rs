@@ -0,0 +1,1982 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_pointer,
+    get_function_result_str, get_function_result_unit,
+};
+#[cfg(not(is_v_6_1))]
+use crate::utility::macros::macros::{
+    get_function_result_pointer_vec, get_function_result_str_vec,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{Machine, Unattended, VboxError};
+use vbox_raw::sys_lib::IMachine;
+
+impl Unattended {
+    /// Guest operating system ISO image.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let iso_path = unattended.get_iso_path().unwrap();
+    pub fn get_iso_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetIsoPath)
+    }
+
+    /// Guest operating system ISO image.
+    ///
+    /// # Arguments
+    ///
+    /// * `iso_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    pub fn set_iso_path(&self, iso_path: &str) -> Result<(), VboxError> {
+        let iso_path = string_to_c_u64_str(iso_path)?;
+        get_function_result_unit!(self.object, SetIsoPath, iso_path)
+    }
+
+    /// The machine object to configure for unattended installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Machine`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let machine = unattended.get_machine().unwrap();
+    pub fn get_machine(&self) -> Result<Machine, VboxError> {
+        let machine = get_function_result_pointer!(self.object, GetMachine, *mut IMachine)?;
+        Ok(Machine::new(machine))
+    }
+
+    /// The machine object to configure for unattended installation.
+    ///
+    /// The machine must not be locked by a session.
+    ///
+    /// # Arguments
+    ///
+    /// * `machine` - &[`Machine`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let machine = vbox.find_machines("Freebsd_14").unwrap();
+    /// unattended.set_machine(&machine).unwrap();
+    pub fn set_machine(&self, machine: &Machine) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetMachine, machine.object)
+    }
+
+    /// User name.
+    ///
+    /// Will be created on the guest.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let user = unattended.get_user().unwrap();
+    pub fn get_user(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUser)
+    }
+
+    /// User name.
+    ///
+    /// Will be created on the guest.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_user("vbox").unwrap();
+    pub fn set_user(&self, user: &str) -> Result<(), VboxError> {
+        let user = string_to_c_u64_str(user)?;
+        get_function_result_unit!(self.object, SetUser, user)
+    }
+
+    /// Password for the user.
+    ///
+    /// Before VirtualBox 7.1 this password is also used for the admin/root account.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let user_password = unattended.get_user_password().unwrap();
+    pub fn get_user_password(&self) -> Result<&'static str, VboxError> {
+        #[cfg(is_v_7_1)]
+        {
+            get_function_result_str!(self.object, GetUserPassword)
+        }
+        #[cfg(not(is_v_7_1))]
+        {
+            get_function_result_str!(self.object, GetPassword)
+        }
+    }
+
+    /// Password for the user.
+    ///
+    /// Before VirtualBox 7.1 this password is also used for the admin/root account.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_password` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_user_password("changeme").unwrap();
+    pub fn set_user_password(&self, user_password: &str) -> Result<(), VboxError> {
+        let user_password = string_to_c_u64_str(user_password)?;
+        #[cfg(is_v_7_1)]
+        {
+            get_function_result_unit!(self.object, SetUserPassword, user_password)
+        }
+        #[cfg(not(is_v_7_1))]
+        {
+            get_function_result_unit!(self.object, SetPassword, user_password)
+        }
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Password for the admin/root account.
+    ///
+    /// Defaults to [`Unattended::get_user_password`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let admin_password = unattended.get_admin_password().unwrap();
+    pub fn get_admin_password(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetAdminPassword)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Password for the admin/root account.
+    ///
+    /// Defaults to [`Unattended::get_user_password`].
+    ///
+    /// # Arguments
+    ///
+    /// * `admin_password` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_admin_password("changeme").unwrap();
+    pub fn set_admin_password(&self, admin_password: &str) -> Result<(), VboxError> {
+        let admin_password = string_to_c_u64_str(admin_password)?;
+        get_function_result_unit!(self.object, SetAdminPassword, admin_password)
+    }
+
+    /// The full name of the user.
+    ///
+    /// This is optional and defaults to [`Unattended::get_user`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let full_user_name = unattended.get_full_user_name().unwrap();
+    pub fn get_full_user_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetFullUserName)
+    }
+
+    /// The full name of the user.
+    ///
+    /// This is optional and defaults to [`Unattended::get_user`].
+    ///
+    /// # Arguments
+    ///
+    /// * `full_user_name` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_full_user_name("VirtualBox User").unwrap();
+    pub fn set_full_user_name(&self, full_user_name: &str) -> Result<(), VboxError> {
+        let full_user_name = string_to_c_u64_str(full_user_name)?;
+        get_function_result_unit!(self.object, SetFullUserName, full_user_name)
+    }
+
+    /// Any product key that may be required by the installer.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let product_key = unattended.get_product_key().unwrap();
+    pub fn get_product_key(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetProductKey)
+    }
+
+    /// Any product key that may be required by the installer.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_key` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_product_key("XXXXX-XXXXX-XXXXX-XXXXX-XXXXX").unwrap();
+    pub fn set_product_key(&self, product_key: &str) -> Result<(), VboxError> {
+        let product_key = string_to_c_u64_str(product_key)?;
+        get_function_result_unit!(self.object, SetProductKey, product_key)
+    }
+
+    /// Guest Additions ISO image path.
+    ///
+    /// This property is ignored unless [`Unattended::get_install_guest_additions`] is true. Defaults to the default Guest Additions ISO.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let additions_iso_path = unattended.get_additions_iso_path().unwrap();
+    pub fn get_additions_iso_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetAdditionsIsoPath)
+    }
+
+    /// Guest Additions ISO image path.
+    ///
+    /// This property is ignored unless [`Unattended::get_install_guest_additions`] is true. Defaults to the default Guest Additions ISO.
+    ///
+    /// # Arguments
+    ///
+    /// * `additions_iso_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_additions_iso_path("/usr/share/virtualbox/VBoxGuestAdditions.iso").unwrap();
+    pub fn set_additions_iso_path(&self, additions_iso_path: &str) -> Result<(), VboxError> {
+        let additions_iso_path = string_to_c_u64_str(additions_iso_path)?;
+        get_function_result_unit!(self.object, SetAdditionsIsoPath, additions_iso_path)
+    }
+
+    /// Indicates whether the guest additions should be installed or not.
+    ///
+    /// Setting this to false does not affect additions shipped with the linux distribution, only the installation of additions pointed to by [`Unattended::get_additions_iso_path`].
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let install_guest_additions = unattended.get_install_guest_additions().unwrap();
+    pub fn get_install_guest_additions(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetInstallGuestAdditions)
+    }
+
+    /// Indicates whether the guest additions should be installed or not.
+    ///
+    /// Setting this to false does not affect additions shipped with the linux distribution, only the installation of additions pointed to by [`Unattended::get_additions_iso_path`].
+    ///
+    /// # Arguments
+    ///
+    /// * `install_guest_additions` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_install_guest_additions(true).unwrap();
+    pub fn set_install_guest_additions(
+        &self,
+        install_guest_additions: bool,
+    ) -> Result<(), VboxError> {
+        let install_guest_additions = if install_guest_additions { 1 } else { 0 };
+        get_function_result_unit!(
+            self.object,
+            SetInstallGuestAdditions,
+            install_guest_additions
+        )
+    }
+
+    /// VirtualBox ValidationKit ISO image path.
+    ///
+    /// This is used when [`Unattended::get_install_test_exec_service`] is true.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let validation_kit_iso_path = unattended.get_validation_kit_iso_path().unwrap();
+    pub fn get_validation_kit_iso_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetValidationKitIsoPath)
+    }
+
+    /// VirtualBox ValidationKit ISO image path.
+    ///
+    /// This is used when [`Unattended::get_install_test_exec_service`] is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `validation_kit_iso_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_validation_kit_iso_path("/home/user/iso/ValidationKit.iso").unwrap();
+    pub fn set_validation_kit_iso_path(
+        &self,
+        validation_kit_iso_path: &str,
+    ) -> Result<(), VboxError> {
+        let validation_kit_iso_path = string_to_c_u64_str(validation_kit_iso_path)?;
+        get_function_result_unit!(
+            self.object,
+            SetValidationKitIsoPath,
+            validation_kit_iso_path
+        )
+    }
+
+    /// Indicates whether the test execution service (TXS) from the VBox ValidationKit should be installed.
+    ///
+    /// The TXS binary will be taken from [`Unattended::get_validation_kit_iso_path`].
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let install_test_exec_service = unattended.get_install_test_exec_service().unwrap();
+    pub fn get_install_test_exec_service(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetInstallTestExecService)
+    }
+
+    /// Indicates whether the test execution service (TXS) from the VBox ValidationKit should be installed.
+    ///
+    /// The TXS binary will be taken from [`Unattended::get_validation_kit_iso_path`].
+    ///
+    /// # Arguments
+    ///
+    /// * `install_test_exec_service` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_install_test_exec_service(false).unwrap();
+    pub fn set_install_test_exec_service(
+        &self,
+        install_test_exec_service: bool,
+    ) -> Result<(), VboxError> {
+        let install_test_exec_service = if install_test_exec_service { 1 } else { 0 };
+        get_function_result_unit!(
+            self.object,
+            SetInstallTestExecService,
+            install_test_exec_service
+        )
+    }
+
+    #[cfg(is_v_7_1)]
+    /// User Payload ISO image path.
+    ///
+    /// This is used when [`Unattended::get_install_user_payload`] is true.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let user_payload_iso_path = unattended.get_user_payload_iso_path().unwrap();
+    pub fn get_user_payload_iso_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUserPayloadIsoPath)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// User Payload ISO image path.
+    ///
+    /// This is used when [`Unattended::get_install_user_payload`] is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_payload_iso_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_user_payload_iso_path("/home/user/iso/payload.iso").unwrap();
+    pub fn set_user_payload_iso_path(&self, user_payload_iso_path: &str) -> Result<(), VboxError> {
+        let user_payload_iso_path = string_to_c_u64_str(user_payload_iso_path)?;
+        get_function_result_unit!(self.object, SetUserPayloadIsoPath, user_payload_iso_path)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Indicates whether the user payload should be installed.
+    ///
+    /// The payload is taken from [`Unattended::get_user_payload_iso_path`] and its `VBoxUserPayload` script is run at the end of the installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let install_user_payload = unattended.get_install_user_payload().unwrap();
+    pub fn get_install_user_payload(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetInstallUserPayload)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// Indicates whether the user payload should be installed.
+    ///
+    /// The payload is taken from [`Unattended::get_user_payload_iso_path`] and its `VBoxUserPayload` script is run at the end of the installation.
+    ///
+    /// # Arguments
+    ///
+    /// * `install_user_payload` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_install_user_payload(true).unwrap();
+    pub fn set_install_user_payload(&self, install_user_payload: bool) -> Result<(), VboxError> {
+        let install_user_payload = if install_user_payload { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetInstallUserPayload, install_user_payload)
+    }
+
+    /// The guest time zone specifier.
+    ///
+    /// This is unfortunately guest OS specific. Windows XP and earlier takes the index number from the Microsoft time zone index table, Windows Vista and later takes the time zone string from the same table. Linux usually takes the TZ string from the tz database. The default is currently UTC/GMT, but this may change to be same as the host later.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let time_zone = unattended.get_time_zone().unwrap();
+    pub fn get_time_zone(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetTimeZone)
+    }
+
+    /// The guest time zone specifier.
+    ///
+    /// This is unfortunately guest OS specific. Windows XP and earlier takes the index number from the Microsoft time zone index table, Windows Vista and later takes the time zone string from the same table. Linux usually takes the TZ string from the tz database. The default is currently UTC/GMT, but this may change to be same as the host later.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_zone` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_time_zone("Europe/Berlin").unwrap();
+    pub fn set_time_zone(&self, time_zone: &str) -> Result<(), VboxError> {
+        let time_zone = string_to_c_u64_str(time_zone)?;
+        get_function_result_unit!(self.object, SetTimeZone, time_zone)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// The keyboard layout (e.g. `us`, `de`, `fr`) to use.
+    ///
+    /// Defaults to a layout derived from [`Unattended::get_locale`].
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let keyboard_layout = unattended.get_keyboard_layout().unwrap();
+    pub fn get_keyboard_layout(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetKeyboardLayout)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// The keyboard layout (e.g. `us`, `de`, `fr`) to use.
+    ///
+    /// Defaults to a layout derived from [`Unattended::get_locale`].
+    ///
+    /// # Arguments
+    ///
+    /// * `keyboard_layout` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_keyboard_layout("de").unwrap();
+    pub fn set_keyboard_layout(&self, keyboard_layout: &str) -> Result<(), VboxError> {
+        let keyboard_layout = string_to_c_u64_str(keyboard_layout)?;
+        get_function_result_unit!(self.object, SetKeyboardLayout, keyboard_layout)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// The keyboard layout variant (e.g. `nodeadkeys`) to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let keyboard_variant = unattended.get_keyboard_variant().unwrap();
+    pub fn get_keyboard_variant(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetKeyboardVariant)
+    }
+
+    #[cfg(is_v_7_1)]
+    /// The keyboard layout variant (e.g. `nodeadkeys`) to use.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyboard_variant` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_keyboard_variant("nodeadkeys").unwrap();
+    pub fn set_keyboard_variant(&self, keyboard_variant: &str) -> Result<(), VboxError> {
+        let keyboard_variant = string_to_c_u64_str(keyboard_variant)?;
+        get_function_result_unit!(self.object, SetKeyboardVariant, keyboard_variant)
+    }
+
+    /// The 5 letter locale identifier, no codesets or such.
+    ///
+    /// The format is two lower case language letters (ISO 639-1), underscore ('_'), and two upper case country letters (ISO 3166-1 alpha-2). This will be used to guess the language and keyboard layout if they are not set. The default is taken from the host (user default locale, or en_US).
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let locale = unattended.get_locale().unwrap();
+    pub fn get_locale(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLocale)
+    }
+
+    /// The 5 letter locale identifier, no codesets or such.
+    ///
+    /// The format is two lower case language letters (ISO 639-1), underscore ('_'), and two upper case country letters (ISO 3166-1 alpha-2). This will be used to guess the language and keyboard layout if they are not set. The default is taken from the host (user default locale, or en_US).
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_locale("en_US").unwrap();
+    pub fn set_locale(&self, locale: &str) -> Result<(), VboxError> {
+        let locale = string_to_c_u64_str(locale)?;
+        get_function_result_unit!(self.object, SetLocale, locale)
+    }
+
+    /// This is more or less a Windows specific setting for choosing the UI language setting of the installed OS.
+    ///
+    /// The value should be from the list available via [`Unattended::get_detected_os_languages`]. The typical format is `{language-code}-{COUNTRY}` but windows may also use `{16-bit code}:{32-bit code}` or insert another component between the language and country codes. The default is currently `en-US`.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let language = unattended.get_language().unwrap();
+    pub fn get_language(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetLanguage)
+    }
+
+    /// This is more or less a Windows specific setting for choosing the UI language setting of the installed OS.
+    ///
+    /// The value should be from the list available via [`Unattended::get_detected_os_languages`]. The typical format is `{language-code}-{COUNTRY}` but windows may also use `{16-bit code}:{32-bit code}` or insert another component between the language and country codes. The default is currently `en-US`.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_language("en-US").unwrap();
+    pub fn set_language(&self, language: &str) -> Result<(), VboxError> {
+        let language = string_to_c_u64_str(language)?;
+        get_function_result_unit!(self.object, SetLanguage, language)
+    }
+
+    /// The 2 upper case letter country identifier, ISO 3166-1 alpha-2.
+    ///
+    /// This is used for mirrors and such. The default is taken from the host.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let country = unattended.get_country().unwrap();
+    pub fn get_country(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetCountry)
+    }
+
+    /// The 2 upper case letter country identifier, ISO 3166-1 alpha-2.
+    ///
+    /// This is used for mirrors and such. The default is taken from the host.
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_country("DE").unwrap();
+    pub fn set_country(&self, country: &str) -> Result<(), VboxError> {
+        let country = string_to_c_u64_str(country)?;
+        get_function_result_unit!(self.object, SetCountry, country)
+    }
+
+    /// Proxy incantation to pass on to the guest OS installer.
+    ///
+    /// This is important to get right if the guest OS installer is of the type that goes online to fetch the packages or to fetch updates. The empty string means no proxy and NULL string (the default) means let the guest OS installer decide. The format is not yet well defined, `http://proxy.example.org:3128` works for most installers.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let proxy = unattended.get_proxy().unwrap();
+    pub fn get_proxy(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetProxy)
+    }
+
+    /// Proxy incantation to pass on to the guest OS installer.
+    ///
+    /// This is important to get right if the guest OS installer is of the type that goes online to fetch the packages or to fetch updates. The empty string means no proxy and NULL string (the default) means let the guest OS installer decide. The format is not yet well defined, `http://proxy.example.org:3128` works for most installers.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_proxy("http://proxy.example.org:3128").unwrap();
+    pub fn set_proxy(&self, proxy: &str) -> Result<(), VboxError> {
+        let proxy = string_to_c_u64_str(proxy)?;
+        get_function_result_unit!(self.object, SetProxy, proxy)
+    }
+
+    /// Guest OS specific package selection adjustments.
+    ///
+    /// This is a list of keywords separated by semicolons. Currently the only supported keyword is `minimal`, which requests a minimal installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let package_selection_adjustments = unattended.get_package_selection_adjustments().unwrap();
+    pub fn get_package_selection_adjustments(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPackageSelectionAdjustments)
+    }
+
+    /// Guest OS specific package selection adjustments.
+    ///
+    /// This is a list of keywords separated by semicolons. Currently the only supported keyword is `minimal`, which requests a minimal installation.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_selection_adjustments` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_package_selection_adjustments("minimal").unwrap();
+    pub fn set_package_selection_adjustments(
+        &self,
+        package_selection_adjustments: &str,
+    ) -> Result<(), VboxError> {
+        let package_selection_adjustments = string_to_c_u64_str(package_selection_adjustments)?;
+        get_function_result_unit!(
+            self.object,
+            SetPackageSelectionAdjustments,
+            package_selection_adjustments
+        )
+    }
+
+    /// The fully qualified guest hostname.
+    ///
+    /// This defaults to machine-name + `.myguest.virtualbox.org`, though it may change to the host domain name later.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let hostname = unattended.get_hostname().unwrap();
+    pub fn get_hostname(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetHostname)
+    }
+
+    /// The fully qualified guest hostname.
+    ///
+    /// This defaults to machine-name + `.myguest.virtualbox.org`, though it may change to the host domain name later.
+    ///
+    /// # Arguments
+    ///
+    /// * `hostname` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_hostname("freebsd14.example.org").unwrap();
+    pub fn set_hostname(&self, hostname: &str) -> Result<(), VboxError> {
+        let hostname = string_to_c_u64_str(hostname)?;
+        get_function_result_unit!(self.object, SetHostname, hostname)
+    }
+
+    /// The path + basename for auxiliary files generated by the unattended installation.
+    ///
+    /// This defaults to the VM folder + Unattended + VM UUID. The files which gets generated depends on the OS being installed. When installing Windows there is currently only a `auto-unattend.xml` file that needs preparing, while Linux installs generally involve several files and sometimes a whole ISO.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let auxiliary_base_path = unattended.get_auxiliary_base_path().unwrap();
+    pub fn get_auxiliary_base_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetAuxiliaryBasePath)
+    }
+
+    /// The path + basename for auxiliary files generated by the unattended installation.
+    ///
+    /// This defaults to the VM folder + Unattended + VM UUID. The files which gets generated depends on the OS being installed. When installing Windows there is currently only a `auto-unattend.xml` file that needs preparing, while Linux installs generally involve several files and sometimes a whole ISO.
+    ///
+    /// # Arguments
+    ///
+    /// * `auxiliary_base_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_auxiliary_base_path("/home/user/VirtualBox VMs/Freebsd_14/Unattended-").unwrap();
+    pub fn set_auxiliary_base_path(&self, auxiliary_base_path: &str) -> Result<(), VboxError> {
+        let auxiliary_base_path = string_to_c_u64_str(auxiliary_base_path)?;
+        get_function_result_unit!(self.object, SetAuxiliaryBasePath, auxiliary_base_path)
+    }
+
+    /// The image index on installation CD/DVD used to install.
+    ///
+    /// Used only with Windows installation CD/DVD, see [`Unattended::get_detected_image_indices`].
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let image_index = unattended.get_image_index().unwrap();
+    pub fn get_image_index(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetImageIndex, u32)
+    }
+
+    /// The image index on installation CD/DVD used to install.
+    ///
+    /// Used only with Windows installation CD/DVD, see [`Unattended::get_detected_image_indices`].
+    ///
+    /// # Arguments
+    ///
+    /// * `image_index` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_image_index(1).unwrap();
+    pub fn set_image_index(&self, image_index: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetImageIndex, image_index)
+    }
+
+    /// The unattended installation script template file.
+    ///
+    /// The template default is based on the guest OS type and is determined by the internal installer when [`Unattended::prepare`] is invoked. Most users will want the defaults. After [`Unattended::prepare`] is called, it will reflect the value used.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let script_template_path = unattended.get_script_template_path().unwrap();
+    pub fn get_script_template_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetScriptTemplatePath)
+    }
+
+    /// The unattended installation script template file.
+    ///
+    /// The template default is based on the guest OS type and is determined by the internal installer when [`Unattended::prepare`] is invoked. Most users will want the defaults. After [`Unattended::prepare`] is called, it will reflect the value used.
+    ///
+    /// # Arguments
+    ///
+    /// * `script_template_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_script_template_path("/home/user/unattended/freebsd_installerconfig").unwrap();
+    pub fn set_script_template_path(&self, script_template_path: &str) -> Result<(), VboxError> {
+        let script_template_path = string_to_c_u64_str(script_template_path)?;
+        get_function_result_unit!(self.object, SetScriptTemplatePath, script_template_path)
+    }
+
+    /// The post installation (shell/batch) script template file.
+    ///
+    /// The template default is based on the guest OS type and is determined by the internal installer when [`Unattended::prepare`] is invoked. Most users will want the defaults. After [`Unattended::prepare`] is called, it will reflect the value used.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let post_install_script_template_path = unattended.get_post_install_script_template_path().unwrap();
+    pub fn get_post_install_script_template_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPostInstallScriptTemplatePath)
+    }
+
+    /// The post installation (shell/batch) script template file.
+    ///
+    /// The template default is based on the guest OS type and is determined by the internal installer when [`Unattended::prepare`] is invoked. Most users will want the defaults. After [`Unattended::prepare`] is called, it will reflect the value used.
+    ///
+    /// # Arguments
+    ///
+    /// * `post_install_script_template_path` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_post_install_script_template_path("/home/user/unattended/freebsd_postinstall.sh").unwrap();
+    pub fn set_post_install_script_template_path(
+        &self,
+        post_install_script_template_path: &str,
+    ) -> Result<(), VboxError> {
+        let post_install_script_template_path =
+            string_to_c_u64_str(post_install_script_template_path)?;
+        get_function_result_unit!(
+            self.object,
+            SetPostInstallScriptTemplatePath,
+            post_install_script_template_path
+        )
+    }
+
+    /// Custom post installation command.
+    ///
+    /// Exactly what is expected as input here depends on the guest OS flavor and how the guest OS installs stuff. For Linux the current expectation is that it's something that can be handed to `/bin/sh -c` without any further quoting or escaping. For Windows it should be a command line which can be given to `cmd.exe /c`.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let post_install_command = unattended.get_post_install_command().unwrap();
+    pub fn get_post_install_command(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPostInstallCommand)
+    }
+
+    /// Custom post installation command.
+    ///
+    /// Exactly what is expected as input here depends on the guest OS flavor and how the guest OS installs stuff. For Linux the current expectation is that it's something that can be handed to `/bin/sh -c` without any further quoting or escaping. For Windows it should be a command line which can be given to `cmd.exe /c`.
+    ///
+    /// # Arguments
+    ///
+    /// * `post_install_command` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_post_install_command("pkg install -y sudo").unwrap();
+    pub fn set_post_install_command(&self, post_install_command: &str) -> Result<(), VboxError> {
+        let post_install_command = string_to_c_u64_str(post_install_command)?;
+        get_function_result_unit!(self.object, SetPostInstallCommand, post_install_command)
+    }
+
+    /// Extra kernel arguments passed to the install kernel of some guests.
+    ///
+    /// This is currently only picked up by Debian and Ubuntu guests. The default is taken from the guest OS type and may also be used to force text mode installation.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let extra_install_kernel_parameters = unattended.get_extra_install_kernel_parameters().unwrap();
+    pub fn get_extra_install_kernel_parameters(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetExtraInstallKernelParameters)
+    }
+
+    /// Extra kernel arguments passed to the install kernel of some guests.
+    ///
+    /// This is currently only picked up by Debian and Ubuntu guests. The default is taken from the guest OS type and may also be used to force text mode installation.
+    ///
+    /// # Arguments
+    ///
+    /// * `extra_install_kernel_parameters` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_extra_install_kernel_parameters("console=ttyS0").unwrap();
+    pub fn set_extra_install_kernel_parameters(
+        &self,
+        extra_install_kernel_parameters: &str,
+    ) -> Result<(), VboxError> {
+        let extra_install_kernel_parameters = string_to_c_u64_str(extra_install_kernel_parameters)?;
+        get_function_result_unit!(
+            self.object,
+            SetExtraInstallKernelParameters,
+            extra_install_kernel_parameters
+        )
+    }
+
+    /// The detected OS type ID (guest OS type identifier).
+    ///
+    /// Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`]. Not yet implemented for all guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_os_type_id = unattended.get_detected_os_type_id().unwrap();
+    pub fn get_detected_os_type_id(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDetectedOSTypeId)
+    }
+
+    /// The detected OS version string.
+    ///
+    /// Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`]. Not yet implemented for all guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_os_version = unattended.get_detected_os_version().unwrap();
+    pub fn get_detected_os_version(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDetectedOSVersion)
+    }
+
+    /// The detected OS flavor (e.g. server, desktop, etc).
+    ///
+    /// Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`]. Not yet implemented for all guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_os_flavor = unattended.get_detected_os_flavor().unwrap();
+    pub fn get_detected_os_flavor(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDetectedOSFlavor)
+    }
+
+    /// The space separated list of (Windows) installation UI languages we detected (lang.ini).
+    ///
+    /// The language specifier format is specific to the guest OS. They are used to set [`Unattended::set_language`]. Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`]. Not yet implemented for all guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_os_languages = unattended.get_detected_os_languages().unwrap();
+    pub fn get_detected_os_languages(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDetectedOSLanguages)
+    }
+
+    /// Space separated list of other stuff detected about the OS and the installation ISO.
+    ///
+    /// Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`]. Not yet implemented for all guests.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_os_hints = unattended.get_detected_os_hints().unwrap();
+    pub fn get_detected_os_hints(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDetectedOSHints)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// A list of names of the images detected from `install.wim` file of a Windows Vista or later ISO.
+    ///
+    /// This array is parallel to [`Unattended::get_detected_image_indices`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_image_names = unattended.get_detected_image_names().unwrap();
+    pub fn get_detected_image_names(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetDetectedImageNames)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// A list of image indexes detected from `install.wim` file of a Windows Vista or later ISO.
+    ///
+    /// This array is parallel to [`Unattended::get_detected_image_names`]. Use one of these with [`Unattended::set_image_index`].
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<u32>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let detected_image_indices = unattended.get_detected_image_indices().unwrap();
+    pub fn get_detected_image_indices(&self) -> Result<Vec<u32>, VboxError> {
+        get_function_result_pointer_vec!(self.object, GetDetectedImageIndices, u32)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// A flag which indicates whether unattended installation is supported for the detected OS.
+    ///
+    /// Set by [`Unattended::detect_iso_os`] or [`Unattended::prepare`].
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let is_unattended_install_supported = unattended.get_is_unattended_install_supported().unwrap();
+    pub fn get_is_unattended_install_supported(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetIsUnattendedInstallSupported)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// A flag to enable/disable the network access during the unattended installation.
+    ///
+    /// When true, package updates are not fetched over the network, which speeds up installations on slow networks.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// let avoid_updates_over_network = unattended.get_avoid_updates_over_network().unwrap();
+    pub fn get_avoid_updates_over_network(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetAvoidUpdatesOverNetwork)
+    }
+
+    #[cfg(not(is_v_6_1))]
+    /// A flag to enable/disable the network access during the unattended installation.
+    ///
+    /// When true, package updates are not fetched over the network, which speeds up installations on slow networks.
+    ///
+    /// # Arguments
+    ///
+    /// * `avoid_updates_over_network` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_avoid_updates_over_network(true).unwrap();
+    pub fn set_avoid_updates_over_network(
+        &self,
+        avoid_updates_over_network: bool,
+    ) -> Result<(), VboxError> {
+        let avoid_updates_over_network = if avoid_updates_over_network { 1 } else { 0 };
+        get_function_result_unit!(
+            self.object,
+            SetAvoidUpdatesOverNetwork,
+            avoid_updates_over_network
+        )
+    }
+
+    /// Detects the OS on the ISO given by [`Unattended::set_iso_path`] and sets the detected-OS properties.
+    ///
+    /// Not yet implemented for all guest types. This is called by [`Unattended::prepare`] when it has not been called on the current ISO.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    /// unattended.detect_iso_os().unwrap();
+    pub fn detect_iso_os(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, DetectIsoOS)
+    }
+
+    /// Prepare for running the unattended process of installation.
+    ///
+    /// This will perform [`Unattended::detect_iso_os`] if not yet called on the current [`Unattended::get_iso_path`] value. It may then fail if the ISO is not supported. A machine must have been set with [`Unattended::set_machine`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    /// let machine = vbox.find_machines("Freebsd_14").unwrap();
+    /// unattended.set_machine(&machine).unwrap();
+    /// unattended.prepare().unwrap();
+    pub fn prepare(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Prepare)
+    }
+
+    /// Constructs the necessary ISO/VISO/Floppy images, with unattended scripts and all necessary bits on them.
+    ///
+    /// Must be called after [`Unattended::prepare`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    /// let machine = vbox.find_machines("Freebsd_14").unwrap();
+    /// unattended.set_machine(&machine).unwrap();
+    /// unattended.prepare().unwrap();
+    /// unattended.construct_media().unwrap();
+    pub fn construct_media(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, ConstructMedia)
+    }
+
+    /// Reconfigures the machine to start the installation.
+    ///
+    /// This involves mounting the ISOs and floppy images created by [`Unattended::construct_media`], attaching new DVD and floppy drives as necessary, and possibly modifying the boot order. The machine must not be locked by a session.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    /// let machine = vbox.find_machines("Freebsd_14").unwrap();
+    /// unattended.set_machine(&machine).unwrap();
+    /// unattended.prepare().unwrap();
+    /// unattended.construct_media().unwrap();
+    /// unattended.reconfigure_vm().unwrap();
+    pub fn reconfigure_vm(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, ReconfigureVM)
+    }
+
+    /// Done with the unattended installer, frees up resources.
+    ///
+    /// The generated media remain attached to the machine.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let unattended = vbox.create_unattended_installer().unwrap();
+    /// unattended.set_iso_path("/home/user/iso/FreeBSD-14.1-RELEASE-amd64-disc1.iso").unwrap();
+    /// let machine = vbox.find_machines("Freebsd_14").unwrap();
+    /// unattended.set_machine(&machine).unwrap();
+    /// unattended.prepare().unwrap();
+    /// unattended.construct_media().unwrap();
+    /// unattended.reconfigure_vm().unwrap();
+    /// unattended.done().unwrap();
+    pub fn done(&self) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, Done)
+    }
+}
+
+#[cfg(not(is_v_7_1))]
+impl Unattended {
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_admin_password(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_admin_password",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_admin_password(&self, _admin_password: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_admin_password",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_user_payload_iso_path(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_user_payload_iso_path",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_user_payload_iso_path(&self, _user_payload_iso_path: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_user_payload_iso_path",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_install_user_payload(&self) -> Result<bool, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_install_user_payload",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_install_user_payload(&self, _install_user_payload: bool) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_install_user_payload",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_keyboard_layout(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_keyboard_layout",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_keyboard_layout(&self, _keyboard_layout: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_keyboard_layout",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_keyboard_variant(&self) -> Result<&'static str, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_keyboard_variant",
+            "v7_1",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_keyboard_variant(&self, _keyboard_variant: &str) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_keyboard_variant",
+            "v7_1",
+        ))
+    }
+}
+
+#[cfg(is_v_6_1)]
+impl Unattended {
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_detected_image_names(&self) -> Result<Vec<&'static str>, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_detected_image_names",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_detected_image_indices(&self) -> Result<Vec<u32>, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_detected_image_indices",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_is_unattended_install_supported(&self) -> Result<bool, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_is_unattended_install_supported",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn get_avoid_updates_over_network(&self) -> Result<bool, VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::get_avoid_updates_over_network",
+            "v7_0",
+        ))
+    }
+
+    /// Placeholder Method
+    ///
+    /// This method is a placeholder. It is not supported in the current API version.
+    /// Calling this method will always return an error indicating that the method is unsupported.
+    ///
+    /// # Returns
+    ///
+    /// Always returns a [`VboxError`] indicating that the method is unsupported in the current API version.
+    ///
+    pub fn set_avoid_updates_over_network(
+        &self,
+        _avoid_updates_over_network: bool,
+    ) -> Result<(), VboxError> {
+        Err(VboxError::unsupported_in_current_api_version(
+            "Unattended::set_avoid_updates_over_network",
+            "v7_0",
+        ))
+    }
+}
@@ -1,9 +1,19 @@
+mod implementation;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IUnattended;
 
-/// Represents a platform-independent Unattended in OVF format.
+/// The IUnattended interface represents the pipeline for preparing the Guest OS for fully automated install.
+///
+/// The typical workflow is:
+/// 1. Call [`crate::VirtualBox::create_unattended_installer`] to create the object.
+/// 2. Set [`Unattended::set_iso_path`] and call [`Unattended::detect_iso_os`].
+/// 3. Create, configure and register a machine according to the detected OS type.
+/// 4. Set [`Unattended::set_machine`] and the other properties.
+/// 5. Call [`Unattended::prepare`], [`Unattended::construct_media`] and [`Unattended::reconfigure_vm`].
+/// 6. Call [`Unattended::done`] and start the machine.
 ///
 /// **Reference to the official documentation:**
 ///
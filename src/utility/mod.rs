@@ -97,3 +97,57 @@ pub(crate) fn str_vec_to_ptr(strs: Vec<&str>) -> Result<(u32, *mut *mut u16), Vb
     }
     Ok((strs_size, safearray as *mut *mut u16))
 }
+
+/// Copies an output array allocated by VirtualBox and frees it.
+pub(crate) fn array_out_to_vec(data_ptr: *mut u8, data_size: u32) -> Result<Vec<u8>, VboxError> {
+    if data_ptr.is_null() {
+        return Ok(Vec::new());
+    }
+    let data = unsafe { std::slice::from_raw_parts(data_ptr, data_size as usize) }.to_vec();
+    let api = g_pVBoxFuncs()?;
+    let free_fn =
+        unsafe { (*api).pfnArrayOutFree }.ok_or(VboxError::get_fn_error("pfnArrayOutFree"))?;
+    let result = unsafe { free_fn(data_ptr as *mut std::ffi::c_void) };
+    if result != NS_OK as i32 {
+        error!(
+            "array_out_to_vec: pfnArrayOutFree failed. Result: {}",
+            result
+        );
+    }
+    Ok(data)
+}
+
+pub(crate) fn random_bytes<const N: usize>() -> [u8; N] {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let mut bytes = [0u8; N];
+    for (i, chunk) in bytes.chunks_mut(8).enumerate() {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(i);
+        hasher.write_u128(nanos);
+        let value = hasher.finish().to_le_bytes();
+        chunk.copy_from_slice(&value[..chunk.len()]);
+    }
+    bytes
+}
+
+pub(crate) fn random_uuid() -> String {
+    let mut bytes: [u8; 16] = random_bytes();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
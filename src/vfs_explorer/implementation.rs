@@ -0,0 +1,278 @@
+use crate::enums::{FsObjType, VFSType};
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_pointer, get_function_result_str,
+    get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::{c_u64_str_to_string, str_vec_to_ptr, string_to_c_u64_str};
+use crate::vfs_explorer::VFSExplorerEntry;
+use crate::{Progress, VFSExplorer, VboxError};
+use vbox_raw::sys_lib::IProgress;
+
+impl VFSExplorer {
+    /// Returns the current path in the virtual file system.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let path = vfs_explorer.get_path().unwrap();
+    pub fn get_path(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetPath)
+    }
+
+    /// Returns the file system type which is currently in use.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`VFSType`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let vfs_type = vfs_explorer.get_type().unwrap();
+    pub fn get_type(&self) -> Result<VFSType, VboxError> {
+        let vfs_type = get_function_result_number!(self.object, GetType, u32)?;
+        Ok(VFSType::from(vfs_type))
+    }
+
+    /// Updates the internal list of files/directories from the current directory level.
+    ///
+    /// Use [`VFSExplorer::entry_list`] to get the full list after a call to this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let progress = vfs_explorer.update().unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn update(&self) -> Result<Progress, VboxError> {
+        let progress = get_function_result_pointer!(self.object, Update, *mut IProgress)?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Change the current directory level.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - &str. The name of the directory to go in.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let progress = vfs_explorer.cd("FreeBSD_14").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn cd(&self, dir: &str) -> Result<Progress, VboxError> {
+        let dir = string_to_c_u64_str(dir)?;
+        let progress = get_function_result_pointer!(self.object, Cd, *mut IProgress, dir)?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Go one directory upwards from the current directory level.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let progress = vfs_explorer.cd_up().unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn cd_up(&self) -> Result<Progress, VboxError> {
+        let progress = get_function_result_pointer!(self.object, CdUp, *mut IProgress)?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Returns a list of files/directories after a call to [`VFSExplorer::update`].
+    ///
+    /// The user is responsible for keeping this internal list up to date.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<VFSExplorerEntry>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// vfs_explorer.update().unwrap().wait_for_completion(-1).unwrap();
+    /// let entries = vfs_explorer.entry_list().unwrap();
+    pub fn entry_list(&self) -> Result<Vec<VFSExplorerEntry>, VboxError> {
+        let mut names_size = 0;
+        let mut names: *mut *mut u16 = std::ptr::null_mut();
+        let mut types_size = 0;
+        let mut types: *mut u32 = std::ptr::null_mut();
+        let mut sizes_size = 0;
+        let mut sizes: *mut i64 = std::ptr::null_mut();
+        let mut modes_size = 0;
+        let mut modes: *mut u32 = std::ptr::null_mut();
+        get_function_result_unit!(
+            self.object,
+            EntryList,
+            &mut names_size,
+            &mut names,
+            &mut types_size,
+            &mut types,
+            &mut sizes_size,
+            &mut sizes,
+            &mut modes_size,
+            &mut modes
+        )?;
+        if names_size == 0 {
+            return Ok(Vec::new());
+        }
+        if names.is_null() || types.is_null() || sizes.is_null() || modes.is_null() {
+            return Err(VboxError::null_pointer_error("VFSExplorer::entry_list"));
+        }
+        if [types_size, sizes_size, modes_size]
+            .iter()
+            .any(|size| *size != names_size)
+        {
+            return Err(VboxError::vectors_length_mismatch(
+                "VFSExplorer::entry_list",
+            ));
+        }
+        let count = names_size as usize;
+        let names = unsafe { Vec::from_raw_parts(names, count, count) };
+        let types = unsafe { Vec::from_raw_parts(types, count, count) };
+        let sizes = unsafe { Vec::from_raw_parts(sizes, count, count) };
+        let modes = unsafe { Vec::from_raw_parts(modes, count, count) };
+        let mut entries = Vec::with_capacity(count);
+        for i in 0..count {
+            entries.push(VFSExplorerEntry {
+                name: c_u64_str_to_string(names[i])?,
+                object_type: FsObjType::from(types[i]),
+                size: sizes[i],
+                mode: modes[i],
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Checks if the given file list exists in the current directory level.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - Vec<&str>. The names to check.
+    ///
+    /// # Returns
+    ///
+    /// Returns the names which exist, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// vfs_explorer.update().unwrap().wait_for_completion(-1).unwrap();
+    /// let exists = vfs_explorer.exists(vec!["FreeBSD_14.ova"]).unwrap();
+    pub fn exists(&self, names: Vec<&str>) -> Result<Vec<&'static str>, VboxError> {
+        let (names_size, names) = str_vec_to_ptr(names)?;
+        get_function_result_str_vec!(self.object, Exists, names_size, names)
+    }
+
+    /// Deletes the given files in the current directory level.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - Vec<&str>. The names to remove.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Progress`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/export/").unwrap();
+    /// let progress = vfs_explorer.remove(vec!["FreeBSD_14.ova"]).unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    pub fn remove(&self, names: Vec<&str>) -> Result<Progress, VboxError> {
+        let (names_size, names) = str_vec_to_ptr(names)?;
+        let progress =
+            get_function_result_pointer!(self.object, Remove, *mut IProgress, names_size, names)?;
+        Ok(Progress::new(progress))
+    }
+
+    /// Lists a directory, waiting for the asynchronous operations to finish.
+    ///
+    /// Changes the current directory level to `path` with [`VFSExplorer::cd`] when it is not empty,
+    /// otherwise refreshes the internal list with [`VFSExplorer::update`], and returns
+    /// [`VFSExplorer::entry_list`]. A relative `path` is joined onto [`VFSExplorer::get_path`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - &str. Directory to list, absolute or relative to the current directory level. An empty string lists the current directory.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<VFSExplorerEntry>`] on success, or a [`VboxError`] if one of the operations fails.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// use virtualbox_rs::enums::FsObjType;
+    /// let appliance = Appliance::init().unwrap();
+    /// let vfs_explorer = appliance.create_vfs_explorer("/home/user/").unwrap();
+    /// let exports = vfs_explorer.list_dir("export").unwrap();
+    /// let taken = exports
+    ///     .iter()
+    ///     .any(|entry| entry.object_type == FsObjType::File && entry.name == "FreeBSD_14.ova");
+    pub fn list_dir(&self, path: &str) -> Result<Vec<VFSExplorerEntry>, VboxError> {
+        let progress = if path.is_empty() {
+            self.update()?
+        } else if path.starts_with('/') || std::path::Path::new(path).is_absolute() {
+            self.cd(path)?
+        } else {
+            let current = self.get_path()?;
+            if current.is_empty() || current.ends_with('/') || current.ends_with('\\') {
+                self.cd(&format!("{}{}", current, path))?
+            } else {
+                self.cd(&format!("{}/{}", current, path))?
+            }
+        };
+        // IVFSExplorer::cd refreshes the list itself.
+        progress.wait_for_success("VFSExplorer::list_dir")?;
+        self.entry_list()
+    }
+}
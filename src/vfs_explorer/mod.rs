@@ -1,3 +1,6 @@
+mod implementation;
+
+use crate::enums::FsObjType;
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
@@ -13,6 +16,17 @@ pub struct VFSExplorer {
     object: *mut IVFSExplorer,
 }
 
+/// One entry of the current directory of a [`VFSExplorer`], see [`VFSExplorer::entry_list`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VFSExplorerEntry {
+    pub name: &'static str,
+    pub object_type: FsObjType,
+    /// Size in bytes.
+    pub size: i64,
+    /// Unix file mode bits.
+    pub mode: u32,
+}
+
 impl VFSExplorer {
     pub(crate) fn new(object: *mut IVFSExplorer) -> Self {
         Self { object }
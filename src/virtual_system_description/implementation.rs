@@ -0,0 +1,411 @@
+use crate::enums::{VirtualSystemDescriptionType, VirtualSystemDescriptionValueType};
+use crate::utility::macros::macros::{
+    get_function_result_number, get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::{c_u64_str_to_string, str_vec_to_ptr, string_to_c_u64_str};
+use crate::{VboxError, VirtualSystemDescription, VirtualSystemDescriptionEntry};
+
+impl VirtualSystemDescription {
+    /// Return the number of virtual system description entries.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// let count = virtual_system_description.get_count().unwrap();
+    pub fn get_count(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetCount, u32)
+    }
+
+    /// Returns information about the virtual system.
+    ///
+    /// On import the entries describe what was found in the OVF and what VirtualBox suggests, on export what will be written to the OVF. The order of the entries is the order expected by [`VirtualSystemDescription::set_final_values`].
+    ///
+    /// # Returns
+    ///
+    /// Returns Vec<[`VirtualSystemDescriptionEntry`]> on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// for entry in virtual_system_description.get_description().unwrap() {
+    ///     println!("{}: {}", entry.description_type, entry.vbox_value);
+    /// }
+    pub fn get_description(&self) -> Result<Vec<VirtualSystemDescriptionEntry>, VboxError> {
+        let mut types_size = 0;
+        let mut types: *mut u32 = std::ptr::null_mut();
+        let mut refs_size = 0;
+        let mut refs: *mut *mut u16 = std::ptr::null_mut();
+        let mut ovf_values_size = 0;
+        let mut ovf_values: *mut *mut u16 = std::ptr::null_mut();
+        let mut vbox_values_size = 0;
+        let mut vbox_values: *mut *mut u16 = std::ptr::null_mut();
+        let mut extra_config_values_size = 0;
+        let mut extra_config_values: *mut *mut u16 = std::ptr::null_mut();
+        get_function_result_unit!(
+            self.object,
+            GetDescription,
+            &mut types_size,
+            &mut types,
+            &mut refs_size,
+            &mut refs,
+            &mut ovf_values_size,
+            &mut ovf_values,
+            &mut vbox_values_size,
+            &mut vbox_values,
+            &mut extra_config_values_size,
+            &mut extra_config_values
+        )?;
+        description_entries(
+            "GetDescription",
+            (types_size, types),
+            (refs_size, refs),
+            (ovf_values_size, ovf_values),
+            (vbox_values_size, vbox_values),
+            (extra_config_values_size, extra_config_values),
+        )
+    }
+
+    /// This is the same as [`VirtualSystemDescription::get_description`] except that you can specify which types should be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `description_type` - [`VirtualSystemDescriptionType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns Vec<[`VirtualSystemDescriptionEntry`]> on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// use virtualbox_rs::enums::VirtualSystemDescriptionType;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// let disks = virtual_system_description
+    ///     .get_description_by_type(VirtualSystemDescriptionType::HardDiskImage)
+    ///     .unwrap();
+    pub fn get_description_by_type(
+        &self,
+        description_type: VirtualSystemDescriptionType,
+    ) -> Result<Vec<VirtualSystemDescriptionEntry>, VboxError> {
+        let description_type: u32 = description_type.into();
+        let mut types_size = 0;
+        let mut types: *mut u32 = std::ptr::null_mut();
+        let mut refs_size = 0;
+        let mut refs: *mut *mut u16 = std::ptr::null_mut();
+        let mut ovf_values_size = 0;
+        let mut ovf_values: *mut *mut u16 = std::ptr::null_mut();
+        let mut vbox_values_size = 0;
+        let mut vbox_values: *mut *mut u16 = std::ptr::null_mut();
+        let mut extra_config_values_size = 0;
+        let mut extra_config_values: *mut *mut u16 = std::ptr::null_mut();
+        get_function_result_unit!(
+            self.object,
+            GetDescriptionByType,
+            description_type,
+            &mut types_size,
+            &mut types,
+            &mut refs_size,
+            &mut refs,
+            &mut ovf_values_size,
+            &mut ovf_values,
+            &mut vbox_values_size,
+            &mut vbox_values,
+            &mut extra_config_values_size,
+            &mut extra_config_values
+        )?;
+        description_entries(
+            "GetDescriptionByType",
+            (types_size, types),
+            (refs_size, refs),
+            (ovf_values_size, ovf_values),
+            (vbox_values_size, vbox_values),
+            (extra_config_values_size, extra_config_values),
+        )
+    }
+
+    /// Delete all records which are equal to the passed type from the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `description_type` - [`VirtualSystemDescriptionType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// use virtualbox_rs::enums::VirtualSystemDescriptionType;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// virtual_system_description
+    ///     .remove_description_by_type(VirtualSystemDescriptionType::SoundCard)
+    ///     .unwrap();
+    pub fn remove_description_by_type(
+        &self,
+        description_type: VirtualSystemDescriptionType,
+    ) -> Result<(), VboxError> {
+        let description_type: u32 = description_type.into();
+        get_function_result_unit!(self.object, RemoveDescriptionByType, description_type)
+    }
+
+    /// This is the same as [`VirtualSystemDescription::get_description_by_type`] except that you can specify which value types should be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `description_type` - [`VirtualSystemDescriptionType`].
+    /// * `which` - [`VirtualSystemDescriptionValueType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns Vec<&str> on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// use virtualbox_rs::enums::{VirtualSystemDescriptionType, VirtualSystemDescriptionValueType};
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// let names = virtual_system_description
+    ///     .get_values_by_type(
+    ///         VirtualSystemDescriptionType::Name,
+    ///         VirtualSystemDescriptionValueType::Auto,
+    ///     )
+    ///     .unwrap();
+    pub fn get_values_by_type(
+        &self,
+        description_type: VirtualSystemDescriptionType,
+        which: VirtualSystemDescriptionValueType,
+    ) -> Result<Vec<&'static str>, VboxError> {
+        let description_type: u32 = description_type.into();
+        let which: u32 = which.into();
+        get_function_result_str_vec!(self.object, GetValuesByType, description_type, which)
+    }
+
+    /// This method allows the appliance's user to change the configuration for the virtual system descriptions.
+    ///
+    /// For each array item returned from [`VirtualSystemDescription::get_description`], you must pass in one boolean value and one configuration value.
+    ///
+    /// Each item in the boolean array determines whether the particular configuration item should be enabled. You can only disable things (i.e. set the array item to false) for a few selected types. For all other types, the boolean value is ignored. Disabling is possible for hard disk images, sound cards, USB controllers, network adapters, floppy and CD/DVD drives.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Vec<bool>.
+    /// * `vbox_values` - Vec<&str>.
+    /// * `extra_config_values` - Vec<&str>.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::Appliance;
+    /// use virtualbox_rs::enums::VirtualSystemDescriptionType;
+    /// let appliance = Appliance::init().unwrap();
+    /// let progress = appliance.read("/home/user/iso/FreeBSD_14.ova").unwrap();
+    /// progress.wait_for_completion(-1).unwrap();
+    /// appliance.interpret().unwrap();
+    /// let virtual_system_descriptions = appliance.get_virtual_system_descriptions().unwrap();
+    /// let virtual_system_description = virtual_system_descriptions.get(0).unwrap();
+    /// let entries = virtual_system_description.get_description().unwrap();
+    /// let mut enabled = Vec::new();
+    /// let mut vbox_values = Vec::new();
+    /// let mut extra_config_values = Vec::new();
+    /// for entry in &entries {
+    ///     enabled.push(entry.description_type != VirtualSystemDescriptionType::SoundCard);
+    ///     vbox_values.push(match entry.description_type {
+    ///         VirtualSystemDescriptionType::Name => "Freebsd_14_imported",
+    ///         VirtualSystemDescriptionType::Memory => "4096",
+    ///         _ => entry.vbox_value,
+    ///     });
+    ///     extra_config_values.push(entry.extra_config_value);
+    /// }
+    /// virtual_system_description
+    ///     .set_final_values(enabled, vbox_values, extra_config_values)
+    ///     .unwrap();
+    pub fn set_final_values(
+        &self,
+        enabled: Vec<bool>,
+        vbox_values: Vec<&str>,
+        extra_config_values: Vec<&str>,
+    ) -> Result<(), VboxError> {
+        if enabled.len() != vbox_values.len() || enabled.len() != extra_config_values.len() {
+            return Err(VboxError::vectors_length_mismatch(
+                "VirtualSystemDescription::set_final_values",
+            ));
+        }
+        let mut enabled: Vec<i32> = enabled
+            .into_iter()
+            .map(|enabled| if enabled { 1 } else { 0 })
+            .collect();
+        let enabled_size = enabled.len() as u32;
+        let enabled_ptr = enabled.as_mut_ptr();
+        let (vbox_values_size, vbox_values_ptr) = str_vec_to_ptr(vbox_values)?;
+        let (extra_config_values_size, extra_config_values_ptr) =
+            str_vec_to_ptr(extra_config_values)?;
+        get_function_result_unit!(
+            self.object,
+            SetFinalValues,
+            enabled_size,
+            enabled_ptr,
+            vbox_values_size,
+            vbox_values_ptr,
+            extra_config_values_size,
+            extra_config_values_ptr
+        )
+    }
+
+    /// This method adds an additional description entry to the stack of already available descriptions for this virtual system.
+    ///
+    /// This is handy for writing values which aren't directly supported by VirtualBox. One example would be the License type of [`VirtualSystemDescriptionType`].
+    ///
+    /// # Arguments
+    ///
+    /// * `description_type` - [`VirtualSystemDescriptionType`].
+    /// * `vbox_value` - &str.
+    /// * `extra_config_value` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Appliance, VirtualBox};
+    /// use virtualbox_rs::enums::VirtualSystemDescriptionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let appliance = Appliance::init().unwrap();
+    /// let description = machine.export_to(&appliance, "").unwrap();
+    /// description
+    ///     .add_description(VirtualSystemDescriptionType::Product, "FreeBSD appliance", "")
+    ///     .unwrap();
+    /// description
+    ///     .add_description(VirtualSystemDescriptionType::Vendor, "Example Inc.", "")
+    ///     .unwrap();
+    /// description
+    ///     .add_description(VirtualSystemDescriptionType::Version, "14.1", "")
+    ///     .unwrap();
+    /// description
+    ///     .add_description(VirtualSystemDescriptionType::License, "BSD-2-Clause", "")
+    ///     .unwrap();
+    pub fn add_description(
+        &self,
+        description_type: VirtualSystemDescriptionType,
+        vbox_value: &str,
+        extra_config_value: &str,
+    ) -> Result<(), VboxError> {
+        let description_type: u32 = description_type.into();
+        let vbox_value = string_to_c_u64_str(vbox_value)?;
+        let extra_config_value = string_to_c_u64_str(extra_config_value)?;
+        get_function_result_unit!(
+            self.object,
+            AddDescription,
+            description_type,
+            vbox_value,
+            extra_config_value
+        )
+    }
+}
+
+fn description_entries(
+    fn_name: &str,
+    types: (u32, *mut u32),
+    refs: (u32, *mut *mut u16),
+    ovf_values: (u32, *mut *mut u16),
+    vbox_values: (u32, *mut *mut u16),
+    extra_config_values: (u32, *mut *mut u16),
+) -> Result<Vec<VirtualSystemDescriptionEntry>, VboxError> {
+    let (types_size, types) = types;
+    if types_size == 0 {
+        return Ok(Vec::new());
+    }
+    if types.is_null()
+        || refs.1.is_null()
+        || ovf_values.1.is_null()
+        || vbox_values.1.is_null()
+        || extra_config_values.1.is_null()
+    {
+        return Err(VboxError::null_pointer_error(fn_name));
+    }
+    let sizes = [refs.0, ovf_values.0, vbox_values.0, extra_config_values.0];
+    if sizes.iter().any(|size| *size != types_size) {
+        return Err(VboxError::vectors_length_mismatch(fn_name));
+    }
+    let types = unsafe { Vec::from_raw_parts(types, types_size as usize, types_size as usize) };
+    let refs = unsafe { Vec::from_raw_parts(refs.1, refs.0 as usize, refs.0 as usize) };
+    let ovf_values =
+        unsafe { Vec::from_raw_parts(ovf_values.1, ovf_values.0 as usize, ovf_values.0 as usize) };
+    let vbox_values = unsafe {
+        Vec::from_raw_parts(
+            vbox_values.1,
+            vbox_values.0 as usize,
+            vbox_values.0 as usize,
+        )
+    };
+    let extra_config_values = unsafe {
+        Vec::from_raw_parts(
+            extra_config_values.1,
+            extra_config_values.0 as usize,
+            extra_config_values.0 as usize,
+        )
+    };
+    let mut entries = Vec::with_capacity(types.len());
+    for (i, description_type) in types.into_iter().enumerate() {
+        entries.push(VirtualSystemDescriptionEntry {
+            description_type: VirtualSystemDescriptionType::from(description_type),
+            reference: c_u64_str_to_string(refs[i])?,
+            ovf_value: c_u64_str_to_string(ovf_values[i])?,
+            vbox_value: c_u64_str_to_string(vbox_values[i])?,
+            extra_config_value: c_u64_str_to_string(extra_config_values[i])?,
+        });
+    }
+    Ok(entries)
+}
@@ -1,3 +1,6 @@
+mod implementation;
+
+use crate::enums::VirtualSystemDescriptionType;
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
@@ -13,6 +16,23 @@ pub struct VirtualSystemDescription {
     object: *mut IVirtualSystemDescription,
 }
 
+/// One configuration value of a [`VirtualSystemDescription`], as returned by
+/// [`VirtualSystemDescription::get_description`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualSystemDescriptionEntry {
+    /// Type of the configuration value.
+    pub description_type: VirtualSystemDescriptionType,
+    /// Reference to the OVF element the value was read from. Hard disk images and controllers
+    /// use it to refer to each other.
+    pub reference: &'static str,
+    /// Original value as found in the OVF file.
+    pub ovf_value: &'static str,
+    /// Value suggested by VirtualBox.
+    pub vbox_value: &'static str,
+    /// Additional configuration, for example the controller and port of a hard disk image.
+    pub extra_config_value: &'static str,
+}
+
 impl VirtualSystemDescription {
     pub(crate) fn new(object: *mut IVirtualSystemDescription) -> Self {
         Self { object }
@@ -0,0 +1,479 @@
+use crate::enums::AuthType;
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+    get_function_result_str_vec, get_function_result_unit,
+};
+use crate::utility::string_to_c_u64_str;
+use crate::{VRDEServer, VboxError};
+
+impl VRDEServer {
+    /// Flag if VRDE server is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let enabled = vrde_server.get_enabled().unwrap();
+    pub fn get_enabled(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetEnabled)
+    }
+
+    /// Flag if VRDE server is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_enabled(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), VboxError> {
+        let enabled = if enabled { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetEnabled, enabled)
+    }
+
+    /// VRDE authentication method.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`AuthType`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let auth_type = vrde_server.get_auth_type().unwrap();
+    pub fn get_auth_type(&self) -> Result<AuthType, VboxError> {
+        let auth_type = get_function_result_number!(self.object, GetAuthType, u32)?;
+        Ok(AuthType::from(auth_type))
+    }
+
+    /// VRDE authentication method.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_type` - [`AuthType`].
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::{AuthType, SessionType};
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_auth_type(AuthType::External).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_auth_type(&self, auth_type: AuthType) -> Result<(), VboxError> {
+        let auth_type: u32 = auth_type.into();
+        get_function_result_unit!(self.object, SetAuthType, auth_type)
+    }
+
+    /// Timeout for guest authentication. Milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let auth_timeout = vrde_server.get_auth_timeout().unwrap();
+    pub fn get_auth_timeout(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetAuthTimeout, u32)
+    }
+
+    /// Timeout for guest authentication. Milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_timeout` - u32.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_auth_timeout(5000).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_auth_timeout(&self, auth_timeout: u32) -> Result<(), VboxError> {
+        get_function_result_unit!(self.object, SetAuthTimeout, auth_timeout)
+    }
+
+    /// Flag whether multiple simultaneous connections to the VM are permitted.
+    ///
+    /// Note that this will be replaced by a more powerful mechanism in the future.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let allow_multi_connection = vrde_server.get_allow_multi_connection().unwrap();
+    pub fn get_allow_multi_connection(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetAllowMultiConnection)
+    }
+
+    /// Flag whether multiple simultaneous connections to the VM are permitted.
+    ///
+    /// Note that this will be replaced by a more powerful mechanism in the future.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_multi_connection` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_allow_multi_connection(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_allow_multi_connection(
+        &self,
+        allow_multi_connection: bool,
+    ) -> Result<(), VboxError> {
+        let allow_multi_connection = if allow_multi_connection { 1 } else { 0 };
+        get_function_result_unit!(self.object, SetAllowMultiConnection, allow_multi_connection)
+    }
+
+    /// Flag whether the existing connection must be dropped and a new connection must be established by the VRDE server, when a new client connects in single connection mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let reuse_single_connection = vrde_server.get_reuse_single_connection().unwrap();
+    pub fn get_reuse_single_connection(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetReuseSingleConnection)
+    }
+
+    /// Flag whether the existing connection must be dropped and a new connection must be established by the VRDE server, when a new client connects in single connection mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `reuse_single_connection` - bool.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_reuse_single_connection(true).unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_reuse_single_connection(
+        &self,
+        reuse_single_connection: bool,
+    ) -> Result<(), VboxError> {
+        let reuse_single_connection = if reuse_single_connection { 1 } else { 0 };
+        get_function_result_unit!(
+            self.object,
+            SetReuseSingleConnection,
+            reuse_single_connection
+        )
+    }
+
+    /// The name of Extension Pack providing VRDE for this VM.
+    ///
+    /// Overrides the default VRDE extension pack of the system properties.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let vrde_ext_pack = vrde_server.get_vrde_ext_pack().unwrap();
+    pub fn get_vrde_ext_pack(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetVRDEExtPack)
+    }
+
+    /// The name of Extension Pack providing VRDE for this VM.
+    ///
+    /// Overrides the default VRDE extension pack of the system properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `vrde_ext_pack` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_vrde_ext_pack("Oracle VirtualBox Extension Pack").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_vrde_ext_pack(&self, vrde_ext_pack: &str) -> Result<(), VboxError> {
+        let vrde_ext_pack = string_to_c_u64_str(vrde_ext_pack)?;
+        get_function_result_unit!(self.object, SetVRDEExtPack, vrde_ext_pack)
+    }
+
+    /// Library used for authentication of RDP clients by this VM.
+    ///
+    /// Overrides the VRDE authentication library of the system properties.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let auth_library = vrde_server.get_auth_library().unwrap();
+    pub fn get_auth_library(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetAuthLibrary)
+    }
+
+    /// Library used for authentication of RDP clients by this VM.
+    ///
+    /// Overrides the VRDE authentication library of the system properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_library` - &str.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_auth_library("VBoxAuth").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_auth_library(&self, auth_library: &str) -> Result<(), VboxError> {
+        let auth_library = string_to_c_u64_str(auth_library)?;
+        get_function_result_unit!(self.object, SetAuthLibrary, auth_library)
+    }
+
+    /// Array of names of properties, which are supported by this VRDE server.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Vec<&str>`] on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let vrde_properties = vrde_server.get_vrde_properties().unwrap();
+    pub fn get_vrde_properties(&self) -> Result<Vec<&'static str>, VboxError> {
+        get_function_result_str_vec!(self.object, GetVRDEProperties)
+    }
+
+    /// Sets a VRDE specific property string.
+    ///
+    /// If you pass an empty string as a key value, the given key will be deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str. Name of the key to set, for example `TCP/Ports`, `TCP/Address` or `Security/Method`.
+    /// * `value` - &str. Value to assign to the key.
+    ///
+    /// # Returns
+    ///
+    /// Returns () on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let machine_mut = session.get_machine().unwrap();
+    /// let vrde_server = machine_mut.get_vrde_server().unwrap();
+    /// vrde_server.set_vrde_property("TCP/Ports", "3389-3399").unwrap();
+    /// machine_mut.save_settings().unwrap();
+    pub fn set_vrde_property(&self, key: &str, value: &str) -> Result<(), VboxError> {
+        let key = string_to_c_u64_str(key)?;
+        let value = string_to_c_u64_str(value)?;
+        get_function_result_unit!(self.object, SetVRDEProperty, key, value)
+    }
+
+    /// Returns a VRDE specific property string.
+    ///
+    /// If the requested data key does not exist, this function will succeed and return an empty string in the value argument.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - &str. Name of the key to get, for example `TCP/Ports`, `TCP/Address` or `Security/Method`.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::VirtualBox;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// let vrde_server = machine.get_vrde_server().unwrap();
+    /// let ports = vrde_server.get_vrde_property("TCP/Ports").unwrap();
+    pub fn get_vrde_property(&self, key: &str) -> Result<&'static str, VboxError> {
+        let key = string_to_c_u64_str(key)?;
+        get_function_result_str!(self.object, GetVRDEProperty, key)
+    }
+}
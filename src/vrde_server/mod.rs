@@ -1,9 +1,11 @@
+mod implementation;
+
 use crate::utility::macros::macros::call_function;
 use crate::VboxError;
 use log::{debug, error};
 use vbox_raw::sys_lib::IVRDEServer;
 
-///
+/// Remote Desktop Extension (VRDE) server settings of a virtual machine.
 ///
 /// **Reference to the official documentation:**
 ///
@@ -0,0 +1,385 @@
+use crate::utility::macros::macros::{
+    get_function_result_bool, get_function_result_number, get_function_result_str,
+};
+use crate::{VRDEServerInfo, VboxError};
+
+impl VRDEServerInfo {
+    /// Whether the remote desktop connection is active.
+    ///
+    /// # Returns
+    ///
+    /// Returns bool on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let active = vrde_server_info.get_active().unwrap();
+    pub fn get_active(&self) -> Result<bool, VboxError> {
+        get_function_result_bool!(self.object, GetActive)
+    }
+
+    /// VRDE server port number.
+    ///
+    /// If this property is equal to 0, then the VRDE server failed to start, usually because there are no free IP ports to bind to. If this property is equal to -1, then the VRDE server has not yet been started.
+    ///
+    /// # Returns
+    ///
+    /// Returns i32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let port = vrde_server_info.get_port().unwrap();
+    pub fn get_port(&self) -> Result<i32, VboxError> {
+        get_function_result_number!(self.object, GetPort, i32)
+    }
+
+    /// How many times a client connected.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let number_of_clients = vrde_server_info.get_number_of_clients().unwrap();
+    pub fn get_number_of_clients(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetNumberOfClients, u32)
+    }
+
+    /// When the last connection was established, in milliseconds since 1970-01-01 UTC.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let begin_time = vrde_server_info.get_begin_time().unwrap();
+    pub fn get_begin_time(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetBeginTime, i64)
+    }
+
+    /// When the last connection was terminated or the current time, if connection is still active, in milliseconds since 1970-01-01 UTC.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let end_time = vrde_server_info.get_end_time().unwrap();
+    pub fn get_end_time(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetEndTime, i64)
+    }
+
+    /// How many bytes were sent in last or current, if still active, connection.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let bytes_sent = vrde_server_info.get_bytes_sent().unwrap();
+    pub fn get_bytes_sent(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetBytesSent, i64)
+    }
+
+    /// How many bytes were sent in all connections.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let bytes_sent_total = vrde_server_info.get_bytes_sent_total().unwrap();
+    pub fn get_bytes_sent_total(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetBytesSentTotal, i64)
+    }
+
+    /// How many bytes were received in last or current, if still active, connection.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let bytes_received = vrde_server_info.get_bytes_received().unwrap();
+    pub fn get_bytes_received(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetBytesReceived, i64)
+    }
+
+    /// How many bytes were received in all connections.
+    ///
+    /// # Returns
+    ///
+    /// Returns i64 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let bytes_received_total = vrde_server_info.get_bytes_received_total().unwrap();
+    pub fn get_bytes_received_total(&self) -> Result<i64, VboxError> {
+        get_function_result_number!(self.object, GetBytesReceivedTotal, i64)
+    }
+
+    /// Login user name supplied by the client.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let user = vrde_server_info.get_user().unwrap();
+    pub fn get_user(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetUser)
+    }
+
+    /// Login domain name supplied by the client.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let domain = vrde_server_info.get_domain().unwrap();
+    pub fn get_domain(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetDomain)
+    }
+
+    /// The client name supplied by the client.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let client_name = vrde_server_info.get_client_name().unwrap();
+    pub fn get_client_name(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetClientName)
+    }
+
+    /// The IP address of the client.
+    ///
+    /// # Returns
+    ///
+    /// Returns &str on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let client_ip = vrde_server_info.get_client_ip().unwrap();
+    pub fn get_client_ip(&self) -> Result<&'static str, VboxError> {
+        get_function_result_str!(self.object, GetClientIP)
+    }
+
+    /// The client software version number.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let client_version = vrde_server_info.get_client_version().unwrap();
+    pub fn get_client_version(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetClientVersion, u32)
+    }
+
+    /// Public key exchange method used when connection was established.
+    ///
+    /// Values: 0 - RDP4 public key exchange scheme. 1 - X509 certificates exchange scheme.
+    ///
+    /// # Returns
+    ///
+    /// Returns u32 on success, or a [`VboxError`] on failure.
+    ///
+    ///  # Example
+    ///
+    /// ```no_run
+    ///
+    /// use virtualbox_rs::{Session, VirtualBox};
+    /// use virtualbox_rs::enums::SessionType;
+    ///
+    /// let vbox = VirtualBox::init().unwrap();
+    /// let mut session = Session::init().unwrap();
+    /// let machine = vbox.
+    ///         find_machines("Freebsd_14").unwrap();
+    /// machine.lock_machine(&mut session, SessionType::Shared).unwrap();
+    /// let console = session.get_console().unwrap();
+    /// let vrde_server_info = console.get_vrde_server_info().unwrap();
+    /// let encryption_style = vrde_server_info.get_encryption_style().unwrap();
+    pub fn get_encryption_style(&self) -> Result<u32, VboxError> {
+        get_function_result_number!(self.object, GetEncryptionStyle, u32)
+    }
+}
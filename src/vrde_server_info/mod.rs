@@ -0,0 +1,41 @@
+mod implementation;
+
+use crate::utility::macros::macros::call_function;
+use crate::VboxError;
+use log::{debug, error};
+use vbox_raw::sys_lib::IVRDEServerInfo;
+
+/// Contains information about the remote desktop (VRDE) server capabilities and status.
+///
+/// This is used in the [`crate::Console::get_vrde_server_info`] attribute.
+///
+/// **Reference to the official documentation:**
+///
+/// [https://www.virtualbox.org/sdkref/interface_i_v_r_d_e_server_info.html](https://www.virtualbox.org/sdkref/interface_i_v_r_d_e_server_info.html)
+#[derive(Debug)]
+pub struct VRDEServerInfo {
+    object: *mut IVRDEServerInfo,
+}
+
+impl VRDEServerInfo {
+    pub(crate) fn new(object: *mut IVRDEServerInfo) -> Self {
+        Self { object }
+    }
+
+    fn release(&self) -> Result<i32, VboxError> {
+        call_function!(self.object, Release)
+    }
+}
+
+impl Drop for VRDEServerInfo {
+    fn drop(&mut self) {
+        match self.release() {
+            Ok(count) => {
+                debug!("VRDEServerInfo refcount: {}", count)
+            }
+            Err(err) => {
+                error!("Failed drop VRDEServerInfo. Error: {:?}", err)
+            }
+        }
+    }
+}